@@ -1,3 +1,47 @@
+/// App commands guarded by the capability files: each window only gets the
+/// `allow-*` permissions (grouped in `permissions/windows.toml`) it needs.
+#[cfg(feature = "desktop")]
+const COMMANDS: &[&str] = &[
+    "doc_list",
+    "doc_read",
+    "doc_write",
+    "doc_append",
+    "doc_backup",
+    "doc_is_writable",
+    "migrate_documents",
+    "list_backups",
+    "diff_backup",
+    "restore_backup",
+    "project_list",
+    "project_get",
+    "project_create",
+    "project_update",
+    "project_delete",
+    "field_get_global",
+    "field_get_for_project",
+    "field_get_by_field",
+    "field_add_option",
+    "field_remove_option",
+    "git_status",
+    "search",
+    "search_rebuild",
+    "config_get",
+    "config_update",
+    "local_api_token_get",
+    "local_api_token_regenerate",
+    "capture_show",
+    "capture_target",
+    "capture_hide",
+    "capture_submit",
+    "window_open",
+];
+
 fn main() {
-    tauri_build::build()
+    // The headless CLI has no webview or bundle to configure
+    #[cfg(feature = "desktop")]
+    tauri_build::try_build(
+        tauri_build::Attributes::new()
+            .app_manifest(tauri_build::AppManifest::new().commands(COMMANDS)),
+    )
+    .expect("failed to run tauri build script");
 }
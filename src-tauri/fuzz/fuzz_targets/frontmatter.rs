@@ -0,0 +1,24 @@
+//! Fuzz target: frontmatter parsing
+//!
+//! Wraps the input in `---` delimiters so every run reaches the YAML
+//! parser and required-field extraction. Parsing must never panic, and an
+//! accepted document must carry the invariants the stores rely on.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use meatycapture_lib::serializer;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(yaml) = std::str::from_utf8(data) else {
+        return;
+    };
+    let content = format!("---\n{yaml}\n---\n");
+
+    if let Ok(doc) = serializer::parse(&content) {
+        assert!(!doc.doc_id.is_empty());
+        assert!(!doc.title.is_empty());
+        assert!(!doc.project_id.is_empty());
+        assert!(doc.tags.iter().all(|tag| !tag.is_empty() && tag.trim() == tag));
+    }
+});
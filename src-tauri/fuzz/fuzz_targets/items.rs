@@ -0,0 +1,59 @@
+//! Fuzz target: item section parsing
+//!
+//! Feeds the input as the body of a document with valid frontmatter.
+//! Parsing must never panic, only `## REQ-...` headers may yield items, and
+//! every parsed item must survive serialize -> parse unchanged.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use meatycapture_lib::models::{RequestLogDoc, RequestLogItem};
+use meatycapture_lib::serializer;
+
+const FRONTMATTER: &str = "---
+type: request-log
+doc_id: REQ-20251203-fuzz
+title: Fuzz
+project_id: fuzz
+item_count: 0
+tags: []
+items_index:
+created_at: 2025-12-03T10:00:00.000Z
+updated_at: 2025-12-03T10:00:00.000Z
+---
+";
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(body) = std::str::from_utf8(data) else {
+        return;
+    };
+    let doc = serializer::parse(&format!("{FRONTMATTER}{body}"))
+        .expect("valid frontmatter must parse");
+
+    let headers = body.lines().filter(|line| line.starts_with("## REQ-")).count();
+    assert!(doc.items.len() <= headers);
+
+    for item in &doc.items {
+        assert!(item.id.starts_with("REQ-"));
+        assert!(!item.id.contains(char::is_whitespace));
+        assert!(!item.title.is_empty());
+        assert!(item.tags.iter().all(|tag| !tag.is_empty() && tag.trim() == tag));
+
+        let single = RequestLogDoc {
+            items: vec![item.clone()],
+            ..doc.clone()
+        };
+        let reparsed = serializer::parse(&serializer::serialize(&single))
+            .expect("serialized document must parse");
+        let [again] = reparsed.items.as_slice() else {
+            panic!("item lost on round trip: {item:?}");
+        };
+        assert_eq!(
+            RequestLogItem {
+                created_at: item.created_at,
+                ..again.clone()
+            },
+            *item
+        );
+    }
+});
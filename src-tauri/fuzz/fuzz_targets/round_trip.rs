@@ -0,0 +1,23 @@
+//! Fuzz target: serialize -> parse round trip
+//!
+//! Any input the parser accepts is normalized by one serialize pass; after
+//! that, parse -> serialize must be a fixed point and keep every item.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use meatycapture_lib::serializer;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(content) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(doc) = serializer::parse(content) else {
+        return;
+    };
+
+    let first = serializer::serialize(&doc);
+    let reparsed = serializer::parse(&first).expect("serialized document must parse");
+    assert_eq!(reparsed.items.len(), doc.items.len());
+    assert_eq!(serializer::serialize(&reparsed), first);
+});
@@ -0,0 +1,462 @@
+//! Desktop Application
+//!
+//! Tauri wiring for the desktop app:
+//! - Plugins, managed stores and IPC command registration
+//! - Project scopes: the data dir and enabled project folders, the only
+//!   paths native commands touch for the webview
+//! - Single instance: later launches forward their arguments (desktop only)
+//! - `meatycapture://` deep links from launch arguments or macOS open events
+//! - Tray, quick-capture shortcut and window events (desktop only)
+//! - Window geometry restored on open and saved on close/exit (desktop only)
+//! - Background search reconcile and document watcher
+//! - Git auto-commit reports forwarded as `git-commit` events (`git` feature)
+//! - Embedded local API server (`server` feature, when configured)
+
+use std::path::Path;
+use std::sync::Arc;
+
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::backups::BackupHistory;
+use crate::config_store::{
+    config_dir, LocalConfigStore, LocalFieldCatalogStore, LocalProjectStore,
+};
+use crate::doc_index::{default_index_file, DocIndex};
+use crate::doc_store::{enabled_project_dirs, FsDocStore};
+use crate::ports::DocStore;
+use crate::scope::ProjectScopes;
+use crate::search::{default_search_dir, SearchIndex};
+use crate::watcher::{DocEvent, DocWatcher, DEFAULT_DEBOUNCE};
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+use crate::{
+    capture, deep_link::DeepLink, launch::LaunchArgs, models, navigation, shortcut, tray,
+    window_state::WindowStateStore, windows,
+};
+use crate::{commands, lock};
+
+/// Builds and runs the Tauri application.
+pub(crate) fn run() {
+    let doc_index = Arc::new(DocIndex::open(default_index_file()));
+    let history = Arc::new(BackupHistory::open(&config_dir()));
+    let mut doc_store = FsDocStore::with_lock_timeout(lock::lock_timeout_from_env())
+        .with_index(doc_index)
+        .with_history(history);
+    #[cfg(feature = "git")]
+    let git = Arc::new(crate::git::GitAutoCommit::new(LocalProjectStore::new(None)));
+    #[cfg(feature = "git")]
+    {
+        doc_store = doc_store.with_git(git.clone());
+    }
+
+    // Search is optional: only one process can hold the index writer
+    let search = match SearchIndex::open(&default_search_dir()) {
+        Ok(search) => Some(Arc::new(search)),
+        Err(error) => {
+            log::error!("Search disabled: {error}");
+            None
+        }
+    };
+    if let Some(search) = &search {
+        doc_store = doc_store.with_search(search.clone());
+    }
+
+    let mut builder = tauri::Builder::default();
+
+    // Must be the first plugin so a second launch exits before touching stores
+    #[cfg(not(any(target_os = "android", target_os = "ios")))]
+    {
+        builder = builder.plugin(tauri_plugin_single_instance::init(|app, argv, cwd| {
+            let args = LaunchArgs::parse(argv.into_iter().skip(1), Path::new(&cwd));
+            handle_launch(app, args);
+        }));
+    }
+
+    builder = builder
+        .manage(doc_store.clone())
+        .manage(LocalProjectStore::new(None))
+        .manage(LocalFieldCatalogStore::new(None))
+        .manage(LocalConfigStore::new(None))
+        .manage(ProjectScopes::new(config_dir()))
+        .setup(move |app| {
+            sync_project_scopes(app.handle());
+            if let Some(search) = &search {
+                app.manage(search.clone());
+            }
+            #[cfg(feature = "git")]
+            {
+                let emitter = app.handle().clone();
+                git.on_commit(move |report| {
+                    if let Err(error) = emitter.emit("git-commit", report) {
+                        log::warn!("Failed to emit git-commit: {error}");
+                    }
+                });
+            }
+            #[cfg(not(any(target_os = "android", target_os = "ios")))]
+            setup_desktop(app.handle());
+            #[cfg(feature = "server")]
+            start_local_api(app.handle());
+            start_background_services(app.handle().clone(), doc_store, search);
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![
+            commands::docs::doc_list,
+            commands::docs::doc_read,
+            commands::docs::doc_write,
+            commands::docs::doc_append,
+            commands::docs::doc_backup,
+            commands::docs::doc_is_writable,
+            commands::docs::migrate_documents,
+            commands::docs::list_backups,
+            commands::docs::diff_backup,
+            commands::docs::restore_backup,
+            commands::projects::project_list,
+            commands::projects::project_get,
+            commands::projects::project_create,
+            commands::projects::project_update,
+            commands::projects::project_delete,
+            commands::fields::field_get_global,
+            commands::fields::field_get_for_project,
+            commands::fields::field_get_by_field,
+            commands::fields::field_add_option,
+            commands::fields::field_remove_option,
+            #[cfg(feature = "git")]
+            commands::git::git_status,
+            commands::search::search,
+            commands::search::search_rebuild,
+            commands::config::config_get,
+            commands::config::config_update,
+            commands::config::local_api_token_get,
+            commands::config::local_api_token_regenerate,
+            #[cfg(not(any(target_os = "android", target_os = "ios")))]
+            commands::capture::capture_show,
+            #[cfg(not(any(target_os = "android", target_os = "ios")))]
+            commands::capture::capture_target,
+            #[cfg(not(any(target_os = "android", target_os = "ios")))]
+            commands::capture::capture_hide,
+            #[cfg(not(any(target_os = "android", target_os = "ios")))]
+            commands::capture::capture_submit,
+            #[cfg(not(any(target_os = "android", target_os = "ios")))]
+            commands::windows::window_open,
+        ]);
+
+    #[cfg(not(any(target_os = "android", target_os = "ios")))]
+    {
+        builder = builder
+            .manage(capture::PendingCapture::default())
+            .manage(WindowStateStore::open(&config_dir()))
+            .plugin(tauri_plugin_shell::init())
+            .plugin(shortcut::plugin())
+            .on_window_event(|window, event| {
+                if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+                    tray::handle_close_requested(window, api);
+                }
+                capture::handle_window_event(window, event);
+                windows::handle_window_event(window, event);
+            });
+    }
+
+    builder
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|_app, _event| {
+            // macOS delivers URL-scheme launches as events, not arguments
+            #[cfg(target_os = "macos")]
+            {
+                if let tauri::RunEvent::Opened { urls } = &_event {
+                    for url in urls {
+                        handle_deep_link(_app, url.as_str());
+                    }
+                }
+            }
+            // Windows still open at quit never saw a close request
+            #[cfg(not(any(target_os = "android", target_os = "ios")))]
+            {
+                if let tauri::RunEvent::Exit = &_event {
+                    windows::save(_app);
+                }
+            }
+        });
+}
+
+/// Re-reads `projects.json` into `ProjectScopes`, the only gate between
+/// the webview and the filesystem.
+///
+/// Called on startup, after `project_*` commands and when `projects.json`
+/// changes on disk; native commands honour the new roots immediately.
+pub(crate) fn sync_project_scopes(app: &AppHandle) {
+    let projects = app.state::<LocalProjectStore>();
+    match app.state::<ProjectScopes>().sync(projects.inner()) {
+        Ok(change) => {
+            for root in &change.granted {
+                log::info!("Project folder allowed: {}", root.display());
+            }
+            for root in &change.revoked {
+                log::info!("Project folder no longer allowed: {}", root.display());
+            }
+        }
+        Err(error) => log::error!("Failed to sync project scopes: {error}"),
+    }
+}
+
+/// Restores the main window's geometry, creates the tray icon and registers
+/// the quick-capture shortcut.
+///
+/// None of these is essential, so failures are logged rather than aborting
+/// startup.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn setup_desktop(app: &AppHandle) {
+    use crate::ports::ConfigStore;
+
+    if let Some(main) = app.get_webview_window(navigation::MAIN_WINDOW) {
+        windows::restore(&main.as_ref().window());
+    }
+
+    if let Err(error) = tray::create(app) {
+        log::error!("System tray disabled: {error}");
+    }
+
+    let binding = app
+        .state::<LocalConfigStore>()
+        .get()
+        .map(|config| config.capture_shortcut().to_string())
+        .unwrap_or_else(|_| models::DEFAULT_CAPTURE_SHORTCUT.to_string());
+    if let Err(error) = shortcut::register(app, &binding) {
+        log::error!("Quick-capture shortcut disabled: {error}");
+    }
+
+    // The first launch acts on its own arguments too; the main webview may
+    // not be listening yet, so a document path is best effort
+    let cwd = std::env::current_dir().unwrap_or_default();
+    let args = LaunchArgs::parse(std::env::args().skip(1), &cwd);
+    if !args.is_empty() {
+        handle_launch(app, args);
+    }
+}
+
+/// Focuses the app and acts on launch arguments (forwarded from a second
+/// launch, or the first launch's own).
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn handle_launch(app: &AppHandle, args: LaunchArgs) {
+    log::info!("Launch request: {args:?}");
+    if let Some(link) = &args.deep_link {
+        handle_deep_link(app, link);
+    }
+    if args.capture {
+        capture::show_with(
+            app,
+            capture::CaptureTarget {
+                prefill: crate::deep_link::CapturePrefill {
+                    title: args.title,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+    }
+    match args.doc_path {
+        Some(doc_path) => navigation::navigate(
+            app,
+            navigation::Navigate {
+                doc_path: Some(doc_path),
+                ..navigation::Navigate::to(navigation::View::Viewer)
+            },
+        ),
+        // A bare relaunch means "show me the app"
+        None if !args.capture && args.deep_link.is_none() => {
+            if let Err(error) = navigation::show_main_window(app) {
+                log::warn!("Failed to show main window: {error}");
+            }
+        }
+        None => {}
+    }
+}
+
+/// Acts on a `meatycapture://` link: prefills the wizard, saves
+/// (`submit=1`) or shows a document. A `submit=1` link opens the prefilled
+/// capture window for confirmation unless `deep_link_auto_submit` is set. A
+/// rejected link only raises an error toast; nothing is written.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn handle_deep_link(app: &AppHandle, link: &str) {
+    use crate::deep_link::find_document;
+    use crate::ports::{ConfigStore, SystemClock};
+    use navigation::{Navigate, NoticeKind, View};
+
+    let projects = app.state::<LocalProjectStore>();
+    let docs = app.state::<FsDocStore>();
+    let config = app.state::<LocalConfigStore>();
+    let handled = DeepLink::parse(link).and_then(|parsed| match parsed {
+        DeepLink::Open { doc_id } => {
+            let meta = find_document(&doc_id, projects.inner(), docs.inner())?;
+            let request = Navigate {
+                doc_path: Some(meta.path),
+                ..Navigate::to(View::Viewer)
+            };
+            Ok(Some((request, None)))
+        }
+        DeepLink::Capture(capture) => {
+            let plan = capture.resolve(
+                projects.inner(),
+                app.state::<LocalFieldCatalogStore>().inner(),
+                docs.inner(),
+                config.inner(),
+            )?;
+            if !plan.submit {
+                let request = Navigate {
+                    view: View::Wizard,
+                    project_id: Some(plan.project_id),
+                    doc_path: plan.doc_path,
+                    draft: Some(plan.prefill),
+                };
+                return Ok(Some((request, None)));
+            }
+            if !config.get()?.deep_link_auto_submit() {
+                // Nothing is written until the user confirms
+                capture::show_with(
+                    app,
+                    capture::CaptureTarget {
+                        project_id: Some(plan.project_id),
+                        today: plan.doc_path.is_none(),
+                        doc_path: plan.doc_path,
+                        prefill: plan.prefill,
+                    },
+                );
+                return Ok(None);
+            }
+            let saved = plan.submit(projects.inner(), docs.inner(), &SystemClock)?;
+            let message = format!(
+                "Captured \"{}\" to {}",
+                plan.prefill.title.unwrap_or_default(),
+                saved.doc.doc_id
+            );
+            let request = Navigate {
+                doc_path: Some(saved.path),
+                ..Navigate::to(View::Viewer)
+            };
+            Ok(Some((request, Some(message))))
+        }
+    });
+
+    match handled {
+        Ok(None) => {}
+        Ok(Some((request, message))) => {
+            navigation::navigate(app, request);
+            if let Some(message) = message {
+                navigation::notify(app, NoticeKind::Success, message);
+            }
+        }
+        Err(error) => {
+            log::warn!("Rejected deep link {link}: {error}");
+            if let Err(error) = navigation::show_main_window(app) {
+                log::warn!("Failed to show main window: {error}");
+            }
+            navigation::notify(app, NoticeKind::Error, error.to_string());
+        }
+    }
+}
+
+/// Starts the embedded API server when `local_api_port` is configured.
+///
+/// Requests go through the managed stores, so they share locks and search
+/// updates with IPC. A failure (e.g. port in use) only disables the server.
+#[cfg(feature = "server")]
+fn start_local_api(app: &AppHandle) {
+    use crate::ports::ConfigStore;
+
+    let config = match app.state::<LocalConfigStore>().get() {
+        Ok(config) => config,
+        Err(error) => {
+            log::error!("Local API server disabled: {error}");
+            return;
+        }
+    };
+    let Some(settings) = crate::server::ServerConfig::resolve(&config) else {
+        return;
+    };
+    if let Err(error) = crate::server::start(settings, Arc::new(app.clone())) {
+        log::error!("Local API server disabled: {error}");
+    }
+}
+
+#[cfg(feature = "server")]
+impl crate::server::Stores for AppHandle {
+    fn docs(&self) -> &dyn DocStore {
+        self.state::<FsDocStore>().inner()
+    }
+
+    fn projects(&self) -> &dyn crate::ports::ProjectStore {
+        self.state::<LocalProjectStore>().inner()
+    }
+
+    fn fields(&self) -> &dyn crate::ports::FieldCatalogStore {
+        self.state::<LocalFieldCatalogStore>().inner()
+    }
+
+    fn scopes(&self) -> &ProjectScopes {
+        self.state::<ProjectScopes>().inner()
+    }
+
+    fn projects_changed(&self) {
+        sync_project_scopes(self);
+    }
+}
+
+/// Reconciles the search index with disk, then starts the document watcher.
+///
+/// Runs on its own thread: both steps revalidate every enabled project,
+/// which is slow on a cold cache.
+fn start_background_services(
+    handle: AppHandle,
+    docs: FsDocStore,
+    search: Option<Arc<SearchIndex>>,
+) {
+    std::thread::spawn(move || {
+        let projects = LocalProjectStore::new(None);
+        if let Some(search) = &search {
+            let synced = enabled_project_dirs(&projects)
+                .and_then(|dirs| search.sync_dirs(&dirs, &docs, false));
+            if let Err(error) = synced {
+                log::warn!("Search index sync failed: {error}");
+            }
+        }
+
+        let emitter = handle.clone();
+        let reader = docs.clone();
+        let started = DocWatcher::start(projects, docs, DEFAULT_DEBOUNCE, move |event| {
+            // Keep search current for changes made outside the app
+            if let Some(search) = &search {
+                let path = Path::new(&event.meta().path);
+                let updated = match &event {
+                    DocEvent::Deleted(_) => search.remove_document(path),
+                    _ => reader
+                        .read(&event.meta().path)
+                        .and_then(|doc| search.index_document(path, &doc)),
+                };
+                if let Err(error) = updated {
+                    log::warn!("Failed to update search index - {error}");
+                }
+            }
+            if let Err(error) = emitter.emit(event.name(), event.meta()) {
+                log::warn!("Failed to emit {}: {error}", event.name());
+            }
+        });
+
+        // The viewer still works without live updates (manual refresh)
+        match started {
+            Ok(watcher) => {
+                // Also picks up projects.json edits made outside the app
+                let projects_handle = handle.clone();
+                watcher.on_projects_changed(move || {
+                    sync_project_scopes(&projects_handle);
+                    #[cfg(not(any(target_os = "android", target_os = "ios")))]
+                    {
+                        if let Err(error) = tray::refresh(&projects_handle) {
+                            log::warn!("Failed to refresh tray menu: {error}");
+                        }
+                    }
+                });
+                handle.manage(watcher);
+            }
+            Err(error) => log::error!("Document watcher disabled: {error}"),
+        }
+    });
+}
@@ -0,0 +1,166 @@
+//! Atomic File Writes
+//!
+//! Crash-safe replacement of files on disk:
+//! 1. Write the new contents to a temp file in the target's directory
+//! 2. fsync the temp file
+//! 3. Rename it over the target (atomic on the same filesystem)
+//! 4. fsync the directory so the rename itself is durable
+//!
+//! A crash at any point leaves either the old file or the new file in
+//! place, never a truncated mix. Stray temp files are hidden dotfiles with a
+//! `.tmp` suffix and are ignored by document listing.
+
+use std::ffi::OsString;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Atomically replaces `path` with `contents`.
+pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    write_atomic_with(path, |file| file.write_all(contents.as_ref()))
+}
+
+/// Atomically replaces `path` with whatever `write` produces.
+///
+/// If `write` fails, the temp file is removed and the target is untouched.
+/// Existing file permissions are carried over to the replacement.
+pub fn write_atomic_with<F>(path: &Path, write: F) -> io::Result<()>
+where
+    F: FnOnce(&mut File) -> io::Result<()>,
+{
+    let dir = parent_dir(path);
+    let temp_path = temp_path_for(path);
+
+    let result = (|| {
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&temp_path)?;
+
+        write(&mut file)?;
+        file.flush()?;
+
+        if let Ok(metadata) = fs::metadata(path) {
+            file.set_permissions(metadata.permissions())?;
+        }
+        file.sync_all()?;
+        drop(file);
+
+        fs::rename(&temp_path, path)
+    })();
+
+    if let Err(error) = result {
+        let _ = fs::remove_file(&temp_path);
+        return Err(error);
+    }
+
+    sync_dir(&dir)
+}
+
+/// Returns true for temp files created by [`write_atomic`].
+pub fn is_temp_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with('.') && name.ends_with(".tmp"))
+}
+
+fn parent_dir(path: &Path) -> PathBuf {
+    match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+        _ => PathBuf::from("."),
+    }
+}
+
+/// Builds `.{file_name}.{pid}.{counter}.tmp` next to the target.
+fn temp_path_for(path: &Path) -> PathBuf {
+    let mut name = OsString::from(".");
+    name.push(path.file_name().unwrap_or_default());
+    name.push(format!(
+        ".{}.{}.tmp",
+        std::process::id(),
+        TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    parent_dir(path).join(name)
+}
+
+/// Flushes directory metadata so a completed rename survives power loss.
+#[cfg(unix)]
+fn sync_dir(dir: &Path) -> io::Result<()> {
+    File::open(dir)?.sync_all()
+}
+
+/// Windows has no directory handles to fsync; `MoveFileEx` is durable enough.
+#[cfg(not(unix))]
+fn sync_dir(_dir: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leftover_temp_files(dir: &Path) -> Vec<PathBuf> {
+        fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| is_temp_file(path))
+            .collect()
+    }
+
+    #[test]
+    fn replaces_existing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("log.md");
+        fs::write(&path, "old").unwrap();
+
+        write_atomic(&path, "new contents").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new contents");
+        assert!(leftover_temp_files(dir.path()).is_empty());
+    }
+
+    #[test]
+    fn interrupted_write_leaves_original_intact() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("log.md");
+        let original = "---\ndoc_id: REQ-20251203-app\n---\n".repeat(100);
+        fs::write(&path, &original).unwrap();
+
+        let result = write_atomic_with(&path, |file| {
+            file.write_all(b"---\ndoc_id: REQ-2025")?;
+            Err(io::Error::other("simulated crash mid-write"))
+        });
+
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), original);
+        assert!(leftover_temp_files(dir.path()).is_empty());
+    }
+
+    #[test]
+    fn stale_temp_file_from_crash_does_not_affect_target() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("log.md");
+        fs::write(&path, "original").unwrap();
+
+        // A process killed between write and rename leaves only a temp file
+        let stale = dir.path().join(".log.md.99999.0.tmp");
+        fs::write(&stale, "partial").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "original");
+        write_atomic(&path, "updated").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "updated");
+    }
+
+    #[test]
+    fn creates_new_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("new.md");
+
+        write_atomic(&path, "fresh").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fresh");
+    }
+}
@@ -0,0 +1,461 @@
+//! Versioned Backup History
+//!
+//! Timestamped snapshots of request-log files, taken before each overwrite:
+//! - Stored under `~/.meatycapture/backups/<project>/<doc_id>/`
+//!   (the config directory, see [`BackupHistory::open`])
+//! - Deduplicated by SHA-256: identical content is stored once
+//! - Pruned by count and age ([`Retention`], from `config.json`)
+//! - Listed, diffed and restored through `FsDocStore`
+//!
+//! A snapshot is named `<timestamp>-<hash>.md` and its file stem is the
+//! backup ID. A `source` file next to the snapshots records the document
+//! path, so a file's history is found even when it no longer parses.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::atomic_write::write_atomic;
+use crate::config_store::LocalConfigStore;
+use crate::error::{Error, Result};
+use crate::models::{iso8601, AppConfig};
+use crate::ports::ConfigStore;
+use crate::serializer::parse;
+
+/// Snapshots kept per document when `backup_keep` is not set.
+pub const DEFAULT_KEEP: u32 = 20;
+
+/// Snapshot lifetime in days when `backup_max_age_days` is not set.
+pub const DEFAULT_MAX_AGE_DAYS: u32 = 90;
+
+/// File recording the document path inside each history directory.
+const SOURCE_FILE: &str = "source";
+
+/// Timestamp part of a snapshot name (sorts chronologically).
+const TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%S%3fZ";
+
+/// Hex digits of the content hash kept in a snapshot name.
+const HASH_LEN: usize = 16;
+
+/// How many snapshots survive pruning; `0` disables a limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Retention {
+    /// Snapshots kept per document
+    pub keep: u32,
+    /// Age in days after which a snapshot is removed
+    pub max_age_days: u32,
+}
+
+impl Default for Retention {
+    fn default() -> Self {
+        Self {
+            keep: DEFAULT_KEEP,
+            max_age_days: DEFAULT_MAX_AGE_DAYS,
+        }
+    }
+}
+
+impl Retention {
+    /// Retention configured in `config.json`, with defaults for unset keys.
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self {
+            keep: config.backup_keep.unwrap_or(DEFAULT_KEEP),
+            max_age_days: config.backup_max_age_days.unwrap_or(DEFAULT_MAX_AGE_DAYS),
+        }
+    }
+}
+
+/// One snapshot of a document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BackupEntry {
+    /// Backup ID (`<timestamp>-<hash>`), passed to diff and restore
+    pub id: String,
+    /// Snapshot file
+    pub path: String,
+    /// SHA-256 of the content (hex, truncated)
+    pub hash: String,
+    /// Content size in bytes
+    pub size: u64,
+    /// When the snapshot was taken
+    #[serde(with = "iso8601")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// Snapshot store rooted at a backups directory.
+#[derive(Debug)]
+pub struct BackupHistory {
+    root: PathBuf,
+    retention: Mutex<Retention>,
+}
+
+impl BackupHistory {
+    /// Opens the history in `root` with the default [`Retention`].
+    pub fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            retention: Mutex::new(Retention::default()),
+        }
+    }
+
+    /// Opens `<config_dir>/backups` with the retention from the directory's
+    /// `config.json` (defaults when it is missing or unreadable).
+    pub fn open(config_dir: &Path) -> Self {
+        let retention = LocalConfigStore::new(Some(config_dir.to_path_buf()))
+            .get()
+            .map(|config| Retention::from_config(&config))
+            .unwrap_or_default();
+        Self::new(config_dir.join("backups")).with_retention(retention)
+    }
+
+    /// Sets the retention applied after each snapshot.
+    pub fn with_retention(self, retention: Retention) -> Self {
+        self.set_retention(retention);
+        self
+    }
+
+    /// Replaces the retention (e.g. after `config_update`).
+    pub fn set_retention(&self, retention: Retention) {
+        *self.retention.lock().unwrap_or_else(|e| e.into_inner()) = retention;
+    }
+
+    fn retention(&self) -> Retention {
+        *self.retention.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Records `content` as the newest snapshot of the document at `source`.
+    ///
+    /// Content identical to an existing snapshot is not stored again; that
+    /// snapshot is renamed to `now` instead, so the list stays in the order
+    /// the versions were last seen. Older snapshots are then pruned.
+    pub fn snapshot(
+        &self,
+        source: &Path,
+        content: &[u8],
+        now: DateTime<Utc>,
+    ) -> Result<BackupEntry> {
+        let dir = match history_key(content) {
+            Some(key) => self.root.join(key),
+            None => self.source_dir(source)?.unwrap_or_else(|| {
+                let stem = source.file_stem().unwrap_or_default().to_string_lossy();
+                self.root.join("unknown").join(path_component(&stem))
+            }),
+        };
+        fs::create_dir_all(&dir).map_err(Error::io(format!(
+            "Failed to create backup directory {}",
+            dir.display()
+        )))?;
+        write_atomic(&dir.join(SOURCE_FILE), source.display().to_string()).map_err(Error::io(
+            format!("Failed to record backup source in {}", dir.display()),
+        ))?;
+
+        let hash = content_hash(content);
+        let id = format!("{}-{hash}", now.format(TIMESTAMP_FORMAT));
+        let path = dir.join(format!("{id}.md"));
+        let context = format!("Failed to create backup of {}", source.display());
+        match entries_in(&dir)?
+            .into_iter()
+            .find(|entry| entry.hash == hash)
+        {
+            Some(existing) => fs::rename(&existing.path, &path).map_err(Error::io(context))?,
+            None => write_atomic(&path, content).map_err(Error::io(context))?,
+        }
+
+        self.prune(&dir, now)?;
+        log::debug!("Backup {id} of {}", source.display());
+        Ok(BackupEntry {
+            id,
+            path: path.display().to_string(),
+            hash,
+            size: content.len() as u64,
+            created_at: now,
+        })
+    }
+
+    /// Snapshots of the document at `source`, newest first.
+    pub fn list(&self, source: &Path) -> Result<Vec<BackupEntry>> {
+        match self.history_dir(source)? {
+            Some(dir) => entries_in(&dir),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// A snapshot of `source` with its content.
+    ///
+    /// Fails with [`Error::NotFound`] unless `id` is one of [`Self::list`]'s.
+    pub fn read(&self, source: &Path, id: &str) -> Result<(BackupEntry, String)> {
+        let entry = self
+            .list(source)?
+            .into_iter()
+            .find(|entry| entry.id == id)
+            .ok_or_else(|| {
+                Error::NotFound(format!("Backup {id} not found for {}", source.display()))
+            })?;
+        let content = fs::read_to_string(&entry.path)
+            .map_err(Error::io(format!("Failed to read backup {}", entry.path)))?;
+        Ok((entry, content))
+    }
+
+    /// Directory holding the history of `source`.
+    ///
+    /// The document's own IDs locate it; a file that is missing or no longer
+    /// parses is matched against the recorded `source` paths instead.
+    fn history_dir(&self, source: &Path) -> Result<Option<PathBuf>> {
+        let key = fs::read(source)
+            .ok()
+            .and_then(|content| history_key(&content));
+        match key.map(|key| self.root.join(key)) {
+            Some(dir) if dir.is_dir() => Ok(Some(dir)),
+            _ => self.source_dir(source),
+        }
+    }
+
+    /// History directory whose `source` file records `source`.
+    fn source_dir(&self, source: &Path) -> Result<Option<PathBuf>> {
+        let projects = match fs::read_dir(&self.root) {
+            Ok(projects) => projects,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => {
+                return Err(Error::io(format!(
+                    "Failed to list backups in {}",
+                    self.root.display()
+                ))(error))
+            }
+        };
+        let source = source.display().to_string();
+        Ok(projects
+            .flatten()
+            .filter_map(|project| fs::read_dir(project.path()).ok())
+            .flat_map(|docs| docs.flatten().map(|doc| doc.path()))
+            .find(|dir| {
+                fs::read_to_string(dir.join(SOURCE_FILE)).is_ok_and(|recorded| recorded == source)
+            }))
+    }
+
+    /// Removes snapshots beyond the retention, always keeping the newest.
+    fn prune(&self, dir: &Path, now: DateTime<Utc>) -> Result<()> {
+        let retention = self.retention();
+        let cutoff = now - Duration::days(i64::from(retention.max_age_days));
+        for (index, entry) in entries_in(dir)?.iter().enumerate().skip(1) {
+            let over_count = retention.keep > 0 && index >= retention.keep as usize;
+            let expired = retention.max_age_days > 0 && entry.created_at < cutoff;
+            if over_count || expired {
+                fs::remove_file(&entry.path)
+                    .map_err(Error::io(format!("Failed to prune backup {}", entry.path)))?;
+                log::debug!("Pruned backup {}", entry.path);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `<project>/<doc_id>` for request-log content, `None` when it does not
+/// parse.
+fn history_key(content: &[u8]) -> Option<PathBuf> {
+    let doc = parse(&String::from_utf8_lossy(content)).ok()?;
+    Some(Path::new(&path_component(&doc.project_id)).join(path_component(&doc.doc_id)))
+}
+
+/// Hand-edited frontmatter must not escape the backups directory.
+fn path_component(value: &str) -> String {
+    let component: String = value
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' => c,
+            _ => '_',
+        })
+        .collect();
+    if component.chars().all(|c| c == '.') {
+        format!("_{component}")
+    } else {
+        component
+    }
+}
+
+fn content_hash(content: &[u8]) -> String {
+    let mut hash = format!("{:x}", Sha256::digest(content));
+    hash.truncate(HASH_LEN);
+    hash
+}
+
+/// Snapshots in a history directory, newest first.
+fn entries_in(dir: &Path) -> Result<Vec<BackupEntry>> {
+    let files = match fs::read_dir(dir) {
+        Ok(files) => files,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => {
+            return Err(Error::io(format!(
+                "Failed to list backups in {}",
+                dir.display()
+            ))(error))
+        }
+    };
+
+    let mut entries: Vec<BackupEntry> = files
+        .flatten()
+        .filter_map(|file| {
+            let path = file.path();
+            let id = path.file_stem()?.to_str()?.to_string();
+            if path.extension()? != "md" {
+                return None;
+            }
+            let (timestamp, hash) = id.split_once('-')?;
+            let created_at = NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT)
+                .ok()?
+                .and_utc();
+            Some(BackupEntry {
+                hash: hash.to_string(),
+                size: file.metadata().ok()?.len(),
+                path: path.display().to_string(),
+                id,
+                created_at,
+            })
+        })
+        .collect();
+    entries.sort_by(|a, b| b.id.cmp(&a.id));
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn doc(project: &str, title: &str) -> String {
+        format!(
+            "---
+type: request-log
+schema_version: 2
+doc_id: REQ-20251203-{project}
+title: {title}
+project_id: {project}
+item_count: 0
+tags: []
+items_index:
+created_at: 2025-12-03T10:00:00.000Z
+updated_at: 2025-12-03T10:00:00.000Z
+---
+
+"
+        )
+    }
+
+    fn at(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 12, day, 10, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn snapshots_are_stored_per_project_and_document() {
+        let root = tempfile::tempdir().unwrap();
+        let history = BackupHistory::new(root.path().to_path_buf());
+        let source = root.path().join("docs/REQ-20251203-app.md");
+
+        let entry = history
+            .snapshot(&source, doc("app", "One").as_bytes(), at(3))
+            .unwrap();
+
+        assert_eq!(entry.id, format!("20251203T100000000Z-{}", entry.hash));
+        assert!(
+            Path::new(&entry.path).starts_with(root.path().join("app").join("REQ-20251203-app"))
+        );
+        assert_eq!(history.list(&source).unwrap(), vec![entry.clone()]);
+        let (read, content) = history.read(&source, &entry.id).unwrap();
+        assert_eq!((read, content), (entry, doc("app", "One")));
+        assert!(matches!(
+            history.read(&source, "../../etc/passwd"),
+            Err(Error::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn identical_content_is_stored_once() {
+        let root = tempfile::tempdir().unwrap();
+        let history = BackupHistory::new(root.path().to_path_buf());
+        let source = root.path().join("doc.md");
+
+        history
+            .snapshot(&source, doc("app", "A").as_bytes(), at(1))
+            .unwrap();
+        history
+            .snapshot(&source, doc("app", "B").as_bytes(), at(2))
+            .unwrap();
+        let again = history
+            .snapshot(&source, doc("app", "A").as_bytes(), at(3))
+            .unwrap();
+
+        let entries = history.list(&source).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0], again);
+        assert_eq!(entries[1].created_at, at(2));
+    }
+
+    #[test]
+    fn retention_limits_count_and_age_but_keeps_the_newest() {
+        let root = tempfile::tempdir().unwrap();
+        let history = BackupHistory::new(root.path().to_path_buf()).with_retention(Retention {
+            keep: 3,
+            max_age_days: 0,
+        });
+        let source = root.path().join("doc.md");
+
+        for day in 1..=5 {
+            let content = doc("app", &format!("v{day}"));
+            history
+                .snapshot(&source, content.as_bytes(), at(day))
+                .unwrap();
+        }
+        let days: Vec<_> = history
+            .list(&source)
+            .unwrap()
+            .iter()
+            .map(|entry| entry.created_at)
+            .collect();
+        assert_eq!(days, vec![at(5), at(4), at(3)]);
+
+        history.set_retention(Retention {
+            keep: 0,
+            max_age_days: 1,
+        });
+        history
+            .snapshot(&source, doc("app", "v5").as_bytes(), at(20))
+            .unwrap();
+        assert_eq!(history.list(&source).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn unparseable_documents_are_found_by_source_path() {
+        let root = tempfile::tempdir().unwrap();
+        let history = BackupHistory::new(root.path().join("backups"));
+        let source = root.path().join("REQ-20251203-app.md");
+        history
+            .snapshot(&source, doc("app", "A").as_bytes(), at(3))
+            .unwrap();
+        fs::write(&source, "corrupted").unwrap();
+
+        assert_eq!(history.list(&source).unwrap().len(), 1);
+        assert!(history
+            .list(&root.path().join("other.md"))
+            .unwrap()
+            .is_empty());
+
+        // Kept with the document's history, not under `unknown/`
+        history.snapshot(&source, b"corrupted", at(4)).unwrap();
+        assert_eq!(history.list(&source).unwrap().len(), 2);
+
+        let new = root.path().join("REQ-20251204-new.md");
+        let entry = history.snapshot(&new, b"corrupted", at(4)).unwrap();
+        assert!(Path::new(&entry.path)
+            .starts_with(root.path().join("backups/unknown/REQ-20251204-new")));
+    }
+
+    #[test]
+    fn path_components_cannot_escape_the_backups_directory() {
+        assert_eq!(path_component("../../etc"), ".._.._etc");
+        assert_eq!(path_component(".."), "_..");
+        assert_eq!(path_component(""), "_");
+        assert_eq!(path_component("my-app_2.0"), "my-app_2.0");
+    }
+}
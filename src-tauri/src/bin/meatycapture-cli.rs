@@ -0,0 +1,6 @@
+// Headless command-line interface; build with
+// `cargo build --no-default-features --features cli --bin meatycapture-cli`
+
+fn main() -> std::process::ExitCode {
+    meatycapture_lib::cli::main()
+}
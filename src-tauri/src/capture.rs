@@ -0,0 +1,157 @@
+//! Quick Capture Window
+//!
+//! Small frameless, always-on-top window for capturing an item without the
+//! four-step wizard:
+//! - Created on first use, then hidden instead of closed so it reopens instantly
+//! - Reopens at its last position (see `windows`)
+//! - Every open emits `capture-open` with the default project and last-used document
+//! - A title forwarded by `--capture "title"`, or the fields of a
+//!   `meatycapture://capture?...&submit=1` link awaiting confirmation, are
+//!   prefilled; a freshly created webview fetches them with `capture_target`
+//!   once it is listening
+//! - The webview hides it on submit or Escape (`capture_hide`)
+
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::{
+    AppHandle, Emitter, Manager, Runtime, WebviewUrl, WebviewWindowBuilder, Window, WindowEvent,
+};
+
+use crate::config_store::LocalConfigStore;
+use crate::deep_link::CapturePrefill;
+use crate::navigation::MAIN_WINDOW;
+use crate::ports::ConfigStore;
+use crate::windows::{self, AppWindow};
+
+/// Label of the quick-capture window.
+pub const CAPTURE_WINDOW: &str = "capture";
+
+/// Event telling the capture webview to reset its form.
+pub const CAPTURE_OPEN_EVENT: &str = "capture-open";
+
+/// Payload of the `capture-open` event.
+///
+/// When opening, an unset project or document falls back to
+/// `default_project` / `last_document`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CaptureTarget {
+    /// Project to preselect
+    pub project_id: Option<String>,
+    /// Document to preselect if it belongs to the project
+    pub doc_path: Option<String>,
+    /// Preselect today's document instead (deep links without `doc`)
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub today: bool,
+    /// Item fields to prefill (launch arguments, deep links)
+    #[serde(flatten)]
+    pub prefill: CapturePrefill,
+}
+
+/// Target waiting for a capture webview that was still loading.
+#[derive(Default)]
+pub struct PendingCapture(Mutex<Option<CaptureTarget>>);
+
+impl PendingCapture {
+    fn set(&self, target: CaptureTarget) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = Some(target);
+    }
+
+    fn take(&self) -> Option<CaptureTarget> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).take()
+    }
+}
+
+/// Opens (or focuses) the quick-capture window.
+pub fn show<R: Runtime>(app: &AppHandle<R>) {
+    show_with(app, CaptureTarget::default());
+}
+
+/// Opens (or focuses) the quick-capture window preselecting and
+/// prefilling `request`.
+pub fn show_with<R: Runtime>(app: &AppHandle<R>, request: CaptureTarget) {
+    if let Err(error) = try_show(app, request) {
+        log::warn!("Failed to open capture window: {error}");
+    }
+}
+
+/// Target for a capture webview that just mounted, including any request
+/// forwarded before it was listening.
+pub fn initial_target<R: Runtime>(app: &AppHandle<R>) -> CaptureTarget {
+    let request = app.try_state::<PendingCapture>().and_then(|p| p.take());
+    target(app, request.unwrap_or_default())
+}
+
+/// Hides the quick-capture window, keeping its webview alive.
+pub fn hide<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(window) = app.get_webview_window(CAPTURE_WINDOW) {
+        if let Err(error) = window.hide() {
+            log::warn!("Failed to hide capture window: {error}");
+        }
+    }
+}
+
+/// Keeps the capture window alive across closes and quits with the main window.
+///
+/// The hidden capture window would otherwise keep the app running after the
+/// main window is closed.
+pub fn handle_window_event<R: Runtime>(window: &Window<R>, event: &WindowEvent) {
+    match event {
+        WindowEvent::CloseRequested { api, .. } if window.label() == CAPTURE_WINDOW => {
+            api.prevent_close();
+            hide(window.app_handle());
+        }
+        WindowEvent::Destroyed if window.label() == MAIN_WINDOW => window.app_handle().exit(0),
+        _ => {}
+    }
+}
+
+fn try_show<R: Runtime>(app: &AppHandle<R>, request: CaptureTarget) -> tauri::Result<()> {
+    let window = match app.get_webview_window(CAPTURE_WINDOW) {
+        Some(window) => window,
+        None => {
+            if let Some(pending) = app.try_state::<PendingCapture>() {
+                pending.set(request.clone());
+            }
+            let (width, height) = AppWindow::Capture.default_size();
+            let created = WebviewWindowBuilder::new(
+                app,
+                CAPTURE_WINDOW,
+                WebviewUrl::App("index.html".into()),
+            )
+            .title(AppWindow::Capture.title())
+            .inner_size(width, height)
+            .resizable(false)
+            .decorations(false)
+            .always_on_top(true)
+            .skip_taskbar(true)
+            .center()
+            .visible(false)
+            .build()?;
+            // Back where the user last dragged it
+            windows::restore(&created.as_ref().window());
+            created
+        }
+    };
+
+    window.show()?;
+    window.unminimize()?;
+    window.set_focus()?;
+
+    // A freshly created webview loads the same target on mount (`capture_target`)
+    app.emit_to(CAPTURE_WINDOW, CAPTURE_OPEN_EVENT, target(app, request))
+}
+
+fn target<R: Runtime>(app: &AppHandle<R>, request: CaptureTarget) -> CaptureTarget {
+    match app.state::<LocalConfigStore>().get() {
+        Ok(config) => CaptureTarget {
+            project_id: request.project_id.or(config.default_project),
+            doc_path: request.doc_path.or(config.last_document),
+            ..request
+        },
+        Err(error) => {
+            log::warn!("Failed to read config for capture window: {error}");
+            request
+        }
+    }
+}
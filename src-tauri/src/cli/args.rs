@@ -0,0 +1,648 @@
+//! Argument Parsing
+//!
+//! clap definitions mirroring the TypeScript CLI's commands and flags, and
+//! the dispatch from parsed arguments to the handler modules.
+
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand};
+
+use crate::cli::exit::{CliError, CliResult};
+use crate::cli::output::{Format, Output};
+use crate::cli::search::MatchMode;
+use crate::cli::{config, docs, fields, projects, Context};
+use crate::mcp::{self, McpServer};
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "meatycapture",
+    version,
+    about = "Capture and manage request-log documents from the command line"
+)]
+pub struct Cli {
+    /// Suppress non-error output
+    #[arg(short, long, global = true)]
+    quiet: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Manage request-log documents
+    #[command(subcommand)]
+    Log(LogCommand),
+    /// Manage projects
+    #[command(subcommand)]
+    Project(ProjectCommand),
+    /// Manage field catalog options
+    #[command(subcommand)]
+    Field(FieldCommand),
+    /// Manage CLI configuration
+    #[command(subcommand)]
+    Config(ConfigCommand),
+    /// Create a new request-log document (alias of `log create`)
+    Create(CreateArgs),
+    /// Append items to an existing document (alias of `log append`)
+    Append(AppendArgs),
+    /// List request-log documents (alias of `log list`)
+    List(LogListArgs),
+    /// Serve the Model Context Protocol over stdin/stdout (for AI agents)
+    Mcp,
+}
+
+/// `--json/--yaml/--csv/--table`
+#[derive(Debug, Args)]
+struct FormatArgs {
+    /// Output as JSON
+    #[arg(long)]
+    json: bool,
+    /// Output as YAML
+    #[arg(long)]
+    yaml: bool,
+    /// Output as CSV
+    #[arg(long)]
+    csv: bool,
+    /// Output as ASCII table
+    #[arg(long)]
+    table: bool,
+}
+
+impl FormatArgs {
+    fn format(&self) -> Format {
+        Format::from_flags(self.json, self.yaml, self.csv, self.table)
+    }
+}
+
+/// `--json/--yaml`
+#[derive(Debug, Args)]
+struct StructuredArgs {
+    /// Output as JSON
+    #[arg(long)]
+    json: bool,
+    /// Output as YAML
+    #[arg(long)]
+    yaml: bool,
+}
+
+impl StructuredArgs {
+    fn format(&self) -> Format {
+        Format::from_flags(self.json, self.yaml, false, false)
+    }
+}
+
+// ============================================================================
+// log
+// ============================================================================
+
+#[derive(Debug, Subcommand)]
+enum LogCommand {
+    /// Create a new request-log document from JSON input
+    Create(CreateArgs),
+    /// Append items to an existing document from JSON input
+    Append(AppendArgs),
+    /// List request-log documents
+    List(LogListArgs),
+    /// View a request-log document
+    View(ViewArgs),
+    /// Search items across documents
+    Search(SearchArgs),
+    /// Delete a request-log document
+    Delete(DeleteArgs),
+    /// Upgrade documents to the current schema version
+    Migrate(MigrateArgs),
+}
+
+#[derive(Debug, Args)]
+struct CreateArgs {
+    /// Path to JSON input file, or "-" for stdin
+    json_file: String,
+    /// Output path for the document (default: auto-generated)
+    #[arg(short, long)]
+    output: Option<String>,
+    #[command(flatten)]
+    format: FormatArgs,
+    /// Skip backup creation
+    #[arg(long)]
+    no_backup: bool,
+}
+
+#[derive(Debug, Args)]
+struct AppendArgs {
+    /// Path to existing document
+    doc_path: String,
+    /// Path to JSON input file, or "-" for stdin
+    json_file: String,
+    #[command(flatten)]
+    format: FormatArgs,
+    /// Skip backup creation before modification
+    #[arg(long)]
+    no_backup: bool,
+}
+
+#[derive(Debug, Args)]
+struct LogListArgs {
+    /// Project identifier
+    project: Option<String>,
+    /// Custom path to search for documents
+    #[arg(short, long)]
+    path: Option<String>,
+    #[command(flatten)]
+    format: FormatArgs,
+    /// Sort by field
+    #[arg(long, default_value = "date", value_parser = ["name", "date", "items"])]
+    sort: String,
+    /// Reverse sort order
+    #[arg(long)]
+    reverse: bool,
+    /// Limit number of results
+    #[arg(long)]
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Args)]
+struct ViewArgs {
+    /// Path to the request-log document
+    doc_path: String,
+    /// Output as JSON
+    #[arg(long)]
+    json: bool,
+    /// Output as YAML
+    #[arg(long)]
+    yaml: bool,
+    /// Output original markdown
+    #[arg(long)]
+    markdown: bool,
+    /// Show only items, no frontmatter/metadata
+    #[arg(long)]
+    items_only: bool,
+    /// Show only items of this type
+    #[arg(long)]
+    filter_type: Option<String>,
+    /// Show only items with this status
+    #[arg(long)]
+    filter_status: Option<String>,
+    /// Show only items with this tag
+    #[arg(long)]
+    filter_tag: Option<String>,
+}
+
+#[derive(Debug, Args)]
+struct SearchArgs {
+    /// Search query (supports tag:, type:, status: prefixes)
+    query: String,
+    /// Project identifier
+    project: Option<String>,
+    /// Custom path to search for documents
+    #[arg(short, long)]
+    path: Option<String>,
+    #[command(flatten)]
+    format: FormatArgs,
+    /// Match mode
+    #[arg(long = "match", default_value = "contains", value_parser = ["full", "starts", "contains"])]
+    match_mode: String,
+    /// Limit number of results (0 = unlimited)
+    #[arg(long, default_value_t = 0)]
+    limit: usize,
+}
+
+#[derive(Debug, Args)]
+struct DeleteArgs {
+    /// Path to the document to delete
+    doc_path: String,
+    /// Skip confirmation prompt
+    #[arg(short, long)]
+    force: bool,
+    /// Don't create backup before deletion
+    #[arg(long)]
+    no_backup: bool,
+}
+
+#[derive(Debug, Args)]
+struct MigrateArgs {
+    /// Project identifier
+    project: Option<String>,
+    /// Custom path to the documents to migrate
+    #[arg(short, long)]
+    path: Option<String>,
+    /// Show the changes without writing
+    #[arg(long)]
+    dry_run: bool,
+    #[command(flatten)]
+    format: StructuredArgs,
+}
+
+// ============================================================================
+// project
+// ============================================================================
+
+#[derive(Debug, Subcommand)]
+enum ProjectCommand {
+    /// Register a new project
+    Add(ProjectAddArgs),
+    /// List registered projects
+    List(ProjectListArgs),
+    /// Update a project's name, path or repository URL
+    Update(ProjectUpdateArgs),
+    /// Enable a project
+    Enable(ProjectIdArgs),
+    /// Disable a project
+    Disable(ProjectIdArgs),
+    /// Set the default project
+    SetDefault(ProjectIdArgs),
+}
+
+#[derive(Debug, Args)]
+struct ProjectAddArgs {
+    /// Project name (used to generate the ID if --id is not provided)
+    name: String,
+    /// Default path for request-log documents
+    path: String,
+    /// Custom project ID (kebab-case)
+    #[arg(long)]
+    id: Option<String>,
+    /// Repository URL for context
+    #[arg(long)]
+    repo_url: Option<String>,
+    /// Commit each document write to the enclosing git repository
+    #[arg(long)]
+    auto_commit: bool,
+    #[command(flatten)]
+    format: StructuredArgs,
+}
+
+#[derive(Debug, Args)]
+struct ProjectListArgs {
+    #[command(flatten)]
+    format: FormatArgs,
+    /// Sort by field
+    #[arg(long, default_value = "name", value_parser = ["id", "name", "created"])]
+    sort: String,
+    /// Show only enabled projects
+    #[arg(long)]
+    enabled_only: bool,
+    /// Show only disabled projects
+    #[arg(long)]
+    disabled_only: bool,
+}
+
+#[derive(Debug, Args)]
+struct ProjectUpdateArgs {
+    /// Project ID to update
+    id: String,
+    /// New project name
+    #[arg(long)]
+    name: Option<String>,
+    /// New default document path
+    #[arg(long)]
+    path: Option<String>,
+    /// New repository URL
+    #[arg(long)]
+    repo_url: Option<String>,
+    /// Turn git auto-commit on or off
+    #[arg(long, value_name = "BOOL")]
+    auto_commit: Option<bool>,
+    #[command(flatten)]
+    format: StructuredArgs,
+}
+
+#[derive(Debug, Args)]
+struct ProjectIdArgs {
+    /// Project ID
+    id: String,
+    #[command(flatten)]
+    format: StructuredArgs,
+}
+
+// ============================================================================
+// field / config
+// ============================================================================
+
+#[derive(Debug, Subcommand)]
+enum FieldCommand {
+    /// Add a field option
+    Add(FieldAddArgs),
+    /// List field options
+    List(FieldListArgs),
+    /// Remove a field option
+    Remove(FieldRemoveArgs),
+    /// Import field options from a JSON or YAML file
+    Import(FieldImportArgs),
+}
+
+#[derive(Debug, Args)]
+struct FieldAddArgs {
+    /// Field name (type|domain|context|priority|status|tags)
+    field: String,
+    /// Option value to add
+    value: String,
+    /// Add as project-specific option (default: global)
+    #[arg(long)]
+    project: Option<String>,
+    #[command(flatten)]
+    format: StructuredArgs,
+}
+
+#[derive(Debug, Args)]
+struct FieldListArgs {
+    #[command(flatten)]
+    format: FormatArgs,
+    /// Filter by field name
+    #[arg(long)]
+    field: Option<String>,
+    /// Show effective options for a project (global + project-specific)
+    #[arg(long)]
+    project: Option<String>,
+    /// Show only global options
+    #[arg(long)]
+    global_only: bool,
+}
+
+#[derive(Debug, Args)]
+struct FieldRemoveArgs {
+    /// The ID of the option to remove
+    option_id: String,
+    /// Skip confirmation prompt
+    #[arg(short, long)]
+    force: bool,
+}
+
+#[derive(Debug, Args)]
+struct FieldImportArgs {
+    /// Path to import file (.json, .yaml, .yml)
+    file: String,
+    /// Import as project-specific options
+    #[arg(long)]
+    project: Option<String>,
+    /// Skip existing values instead of failing on duplicates
+    #[arg(long)]
+    merge: bool,
+    #[command(flatten)]
+    format: StructuredArgs,
+}
+
+#[derive(Debug, Subcommand)]
+enum ConfigCommand {
+    /// Initialize the default configuration
+    Init {
+        /// Use a custom config directory
+        #[arg(long)]
+        config_dir: Option<PathBuf>,
+        /// Overwrite existing configuration
+        #[arg(long)]
+        force: bool,
+    },
+    /// Show the current configuration
+    Show {
+        #[command(flatten)]
+        format: StructuredArgs,
+        /// Show only the config directory path
+        #[arg(long)]
+        config_dir: bool,
+    },
+    /// Set a configuration value (default_project, api_url)
+    Set {
+        /// Configuration key
+        key: String,
+        /// Configuration value
+        value: String,
+    },
+}
+
+// ============================================================================
+// Dispatch
+// ============================================================================
+
+/// Parses the process arguments.
+pub fn parse() -> Result<Cli, clap::Error> {
+    Cli::try_parse()
+}
+
+/// Runs the parsed command, writing results to `writer`.
+pub fn dispatch(cli: Cli, writer: &mut dyn Write) -> CliResult {
+    let quiet = cli.quiet;
+    let ctx = match &cli.command {
+        Command::Config(ConfigCommand::Init {
+            config_dir: Some(dir),
+            ..
+        }) => Context::new(Some(dir.clone())),
+        _ => Context::new(None),
+    };
+    let mut out = Sink { quiet, writer };
+
+    match cli.command {
+        Command::Log(command) => log(&ctx, command, &mut out),
+        Command::Create(args) => log(&ctx, LogCommand::Create(args), &mut out),
+        Command::Append(args) => log(&ctx, LogCommand::Append(args), &mut out),
+        Command::List(args) => log(&ctx, LogCommand::List(args), &mut out),
+        Command::Project(command) => project(&ctx, command, &mut out),
+        Command::Field(command) => field(&ctx, command, &mut out),
+        Command::Config(command) => config_command(&ctx, command, &mut out),
+        Command::Mcp => {
+            let server = McpServer::new(Some(ctx.config_dir.clone()));
+            mcp::serve(&server, io::stdin().lock(), out.writer)
+                .map_err(|error| CliError::io(format!("MCP server stopped: {error}")))
+        }
+    }
+}
+
+/// Builds each command's [`Output`] once its format flags are known.
+struct Sink<'w> {
+    quiet: bool,
+    writer: &'w mut dyn Write,
+}
+
+impl Sink<'_> {
+    fn out(&mut self, format: Format) -> Output<'_> {
+        Output::new(format, self.quiet, &mut *self.writer)
+    }
+}
+
+fn log(ctx: &Context, command: LogCommand, out: &mut Sink) -> CliResult {
+    match command {
+        LogCommand::Create(args) => docs::create(
+            ctx,
+            &mut out.out(args.format.format()),
+            docs::CreateOptions {
+                input: args.json_file,
+                output: args.output,
+                backup: !args.no_backup,
+            },
+        ),
+        LogCommand::Append(args) => docs::append(
+            ctx,
+            &mut out.out(args.format.format()),
+            docs::AppendOptions {
+                doc_path: args.doc_path,
+                input: args.json_file,
+                backup: !args.no_backup,
+            },
+        ),
+        LogCommand::List(args) => docs::list(
+            ctx,
+            &mut out.out(args.format.format()),
+            docs::ListOptions {
+                project: args.project,
+                path: args.path,
+                sort: docs::DocSort::parse(&args.sort).unwrap_or_default(),
+                reverse: args.reverse,
+                limit: args.limit,
+            },
+        ),
+        LogCommand::View(args) => docs::view(
+            ctx,
+            // --yaml wins over --json for view, as in the TS CLI
+            &mut out.out(Format::from_flags(
+                args.json && !args.yaml,
+                args.yaml,
+                false,
+                false,
+            )),
+            docs::ViewOptions {
+                doc_path: args.doc_path,
+                markdown: args.markdown,
+                items_only: args.items_only,
+                filter_type: args.filter_type,
+                filter_status: args.filter_status,
+                filter_tag: args.filter_tag,
+            },
+        ),
+        LogCommand::Search(args) => docs::search(
+            ctx,
+            &mut out.out(args.format.format()),
+            docs::SearchOptions {
+                query: args.query,
+                project: args.project,
+                path: args.path,
+                mode: MatchMode::parse(&args.match_mode).unwrap_or_default(),
+                limit: args.limit,
+            },
+        ),
+        LogCommand::Delete(args) => docs::delete(
+            ctx,
+            &mut out.out(Format::Human),
+            docs::DeleteOptions {
+                doc_path: args.doc_path,
+                force: args.force,
+                backup: !args.no_backup,
+            },
+        ),
+        LogCommand::Migrate(args) => docs::migrate(
+            ctx,
+            &mut out.out(args.format.format()),
+            docs::MigrateOptions {
+                project: args.project,
+                path: args.path,
+                dry_run: args.dry_run,
+            },
+        ),
+    }
+}
+
+fn project(ctx: &Context, command: ProjectCommand, out: &mut Sink) -> CliResult {
+    match command {
+        ProjectCommand::Add(args) => projects::add(
+            ctx,
+            &mut out.out(args.format.format()),
+            projects::AddOptions {
+                name: args.name,
+                path: args.path,
+                id: args.id,
+                repo_url: args.repo_url,
+                auto_commit: args.auto_commit,
+            },
+        ),
+        ProjectCommand::List(args) => projects::list(
+            ctx,
+            &mut out.out(args.format.format()),
+            projects::ListOptions {
+                sort: projects::ProjectSort::parse(&args.sort).unwrap_or_default(),
+                enabled_only: args.enabled_only,
+                disabled_only: args.disabled_only,
+            },
+        ),
+        ProjectCommand::Update(args) => projects::update(
+            ctx,
+            &mut out.out(args.format.format()),
+            projects::UpdateOptions {
+                id: args.id,
+                name: args.name,
+                path: args.path,
+                repo_url: args.repo_url,
+                auto_commit: args.auto_commit,
+            },
+        ),
+        ProjectCommand::Enable(args) => {
+            projects::set_enabled(ctx, &mut out.out(args.format.format()), &args.id, true)
+        }
+        ProjectCommand::Disable(args) => {
+            projects::set_enabled(ctx, &mut out.out(args.format.format()), &args.id, false)
+        }
+        ProjectCommand::SetDefault(args) => {
+            projects::set_default(ctx, &mut out.out(args.format.format()), &args.id)
+        }
+    }
+}
+
+fn field(ctx: &Context, command: FieldCommand, out: &mut Sink) -> CliResult {
+    match command {
+        FieldCommand::Add(args) => fields::add(
+            ctx,
+            &mut out.out(args.format.format()),
+            fields::AddOptions {
+                field: args.field,
+                value: args.value,
+                project: args.project,
+            },
+        ),
+        FieldCommand::List(args) => fields::list(
+            ctx,
+            &mut out.out(args.format.format()),
+            fields::ListOptions {
+                field: args.field,
+                project: args.project,
+                global_only: args.global_only,
+            },
+        ),
+        FieldCommand::Remove(args) => fields::remove(
+            ctx,
+            &mut out.out(Format::Human),
+            fields::RemoveOptions {
+                id: args.option_id,
+                force: args.force,
+            },
+        ),
+        FieldCommand::Import(args) => fields::import(
+            ctx,
+            &mut out.out(args.format.format()),
+            fields::ImportOptions {
+                file: args.file,
+                project: args.project,
+                merge: args.merge,
+            },
+        ),
+    }
+}
+
+fn config_command(ctx: &Context, command: ConfigCommand, out: &mut Sink) -> CliResult {
+    match command {
+        ConfigCommand::Init { force, .. } => config::init(
+            ctx,
+            &mut out.out(Format::Human),
+            config::InitOptions { force },
+        ),
+        ConfigCommand::Show { format, config_dir } => config::show(
+            ctx,
+            &mut out.out(format.format()),
+            config::ShowOptions { config_dir },
+        ),
+        ConfigCommand::Set { key, value } => {
+            if key.trim().is_empty() {
+                return Err(CliError::usage("Configuration key is required"));
+            }
+            config::set(ctx, &mut out.out(Format::Human), &key, &value)
+        }
+    }
+}
@@ -0,0 +1,297 @@
+//! Config Commands
+//!
+//! Configuration commands (`meatycapture config ...`):
+//! - init: Create `config.json`, `projects.json` and `fields.json`
+//! - show: Resolved paths, defaults and environment overrides
+//! - set: Update `default_project` or `api_url`
+
+use std::fs;
+
+use serde::Serialize;
+
+use crate::cli::exit::{CliError, CliResult};
+use crate::cli::output::{Format, Output};
+use crate::cli::projects::require_project;
+use crate::cli::{confirm, Context};
+use crate::models::{AppConfigUpdate, NewProject};
+use crate::ports::{ConfigStore, FieldCatalogStore, ProjectStore};
+
+/// Environment variables reported by `config show`.
+const ENV_VARS: [&str; 4] = [
+    "MEATYCAPTURE_CONFIG_DIR",
+    "MEATYCAPTURE_DEFAULT_PROJECT",
+    "MEATYCAPTURE_DEFAULT_PROJECT_PATH",
+    "MEATYCAPTURE_API_URL",
+];
+
+/// Files written by `config init`.
+const CONFIG_FILES: [&str; 3] = ["config.json", "projects.json", "fields.json"];
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|value| !value.is_empty())
+}
+
+/// Options for `config init`.
+#[derive(Debug, Clone, Default)]
+pub struct InitOptions {
+    /// Overwrite an existing configuration without asking
+    pub force: bool,
+}
+
+/// Creates the default configuration in `ctx.config_dir`.
+pub fn init(ctx: &Context, out: &mut Output, opts: InitOptions) -> CliResult {
+    let dir = &ctx.config_dir;
+    let exists = ctx.config.exists();
+
+    if exists && !opts.force {
+        let prompt = format!(
+            "Configuration already exists at {}. Do you want to overwrite? (y/N) ",
+            dir.display()
+        );
+        if !confirm(&prompt, &["y", "yes"])? {
+            return Err(CliError::interrupted("Initialization cancelled."));
+        }
+    }
+
+    out.line("Initializing MeatyCapture configuration...")?;
+    if exists {
+        for file in CONFIG_FILES {
+            // Missing files are fine; they are recreated below
+            let _ = fs::remove_file(dir.join(file));
+        }
+    }
+
+    let mut created = Vec::new();
+    if !dir.exists() {
+        fs::create_dir_all(dir).map_err(|error| {
+            CliError::io(format!(
+                "Permission denied: cannot write {}: {error}",
+                dir.display()
+            ))
+        })?;
+        created.push(format!("{}/", dir.display()));
+    }
+
+    ctx.config.update(AppConfigUpdate::default())?;
+    ctx.projects.create(NewProject {
+        id: Some("meatycapture".to_string()),
+        name: "MeatyCapture".to_string(),
+        default_path: dir.join("docs").join("meatycapture").display().to_string(),
+        repo_url: None,
+        enabled: true,
+        auto_commit: None,
+    })?;
+    // The field store writes its defaults on first read
+    ctx.fields.get_global()?;
+    created.extend(
+        CONFIG_FILES
+            .iter()
+            .map(|file| dir.join(file).display().to_string()),
+    );
+
+    for path in created {
+        out.line(format!("Created: {path}"))?;
+    }
+    out.line("✓ Configuration initialized successfully")
+}
+
+/// Resolved configuration printed by `config show`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigDisplay {
+    pub config_dir: String,
+    pub projects_file: String,
+    pub fields_file: String,
+    pub default_project: Option<String>,
+    pub api_url: Option<String>,
+    /// `api` when an API URL is configured, else `local`
+    pub adapter_mode: &'static str,
+    pub environment: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Collects the resolved configuration.
+///
+/// `default_project` resolves from `MEATYCAPTURE_DEFAULT_PROJECT`, then
+/// `config.json`, then the first enabled project.
+pub fn resolve_config(ctx: &Context) -> CliResult<ConfigDisplay> {
+    let config = ctx.config.get()?;
+    let default_project = match env_var("MEATYCAPTURE_DEFAULT_PROJECT") {
+        Some(project) => Some(project),
+        None => config.default_project.clone().or_else(|| {
+            ctx.projects
+                .list()
+                .ok()?
+                .into_iter()
+                .find(|project| project.enabled)
+                .map(|project| project.id)
+        }),
+    };
+    let api_url = env_var("MEATYCAPTURE_API_URL").or(config.api_url);
+
+    Ok(ConfigDisplay {
+        config_dir: ctx.config_dir.display().to_string(),
+        projects_file: ctx.config_dir.join("projects.json").display().to_string(),
+        fields_file: ctx.config_dir.join("fields.json").display().to_string(),
+        default_project,
+        adapter_mode: if api_url.is_some() { "api" } else { "local" },
+        api_url,
+        environment: ENV_VARS
+            .iter()
+            .map(|name| (name.to_string(), env_var(name).into()))
+            .collect(),
+    })
+}
+
+/// Options for `config show`.
+#[derive(Debug, Clone, Default)]
+pub struct ShowOptions {
+    /// Print only the config directory
+    pub config_dir: bool,
+}
+
+/// Prints the resolved configuration.
+pub fn show(ctx: &Context, out: &mut Output, opts: ShowOptions) -> CliResult {
+    if opts.config_dir {
+        return out.line(ctx.config_dir.display().to_string());
+    }
+
+    let display = resolve_config(ctx)?;
+    if matches!(out.format(), Format::Json | Format::Yaml) {
+        return out.structured(&display);
+    }
+
+    let or_unset = |value: Option<&str>| value.unwrap_or("(not set)").to_string();
+    let mut lines = vec![
+        "Configuration:".to_string(),
+        format!("  Config Directory: {}", display.config_dir),
+        format!("  Projects File:    {}", display.projects_file),
+        format!("  Fields File:      {}", display.fields_file),
+        format!(
+            "  Default Project:  {}",
+            or_unset(display.default_project.as_deref())
+        ),
+        format!(
+            "  API URL:          {}",
+            or_unset(display.api_url.as_deref())
+        ),
+        format!("  Adapter Mode:     {}", display.adapter_mode),
+        String::new(),
+        "Environment Variables:".to_string(),
+    ];
+    for name in ENV_VARS {
+        let label = format!("{name}:");
+        lines.push(format!(
+            "  {label:<36}{}",
+            or_unset(display.environment[name].as_str())
+        ));
+    }
+    out.line(lines.join("\n"))
+}
+
+/// Sets `default_project` (must be registered) or `api_url` (http(s), or
+/// `''`/`null`/`none` to clear).
+pub fn set(ctx: &Context, out: &mut Output, key: &str, value: &str) -> CliResult {
+    let update = match key {
+        "default_project" => {
+            require_project(ctx, value)?;
+            AppConfigUpdate {
+                default_project: Some(value.to_string()),
+                ..AppConfigUpdate::default()
+            }
+        }
+        "api_url" => {
+            let cleared = value.is_empty()
+                || value.eq_ignore_ascii_case("null")
+                || value.eq_ignore_ascii_case("none");
+            if !cleared {
+                validate_url(value)?;
+            }
+            AppConfigUpdate {
+                api_url: Some(if cleared {
+                    String::new()
+                } else {
+                    value.to_string()
+                }),
+                ..AppConfigUpdate::default()
+            }
+        }
+        _ => {
+            return Err(
+                CliError::validation(format!("Unknown configuration key: {key}"))
+                    .with_suggestion("Valid keys: default_project, api_url"),
+            )
+        }
+    };
+
+    ctx.config.update(update)?;
+    out.line(format!("Set {key} = {value}"))
+}
+
+/// Accepts `http://host...` and `https://host...` URLs.
+fn validate_url(value: &str) -> CliResult {
+    let Some((scheme, rest)) = value.split_once("://") else {
+        return Err(CliError::validation(format!("Invalid URL format: {value}"))
+            .with_suggestion("Use a valid URL like http://localhost:3737, or '' to clear"));
+    };
+    if !matches!(scheme.to_lowercase().as_str(), "http" | "https") {
+        return Err(
+            CliError::validation(format!("Invalid URL protocol: {scheme}:"))
+                .with_suggestion("Use http:// or https:// protocol"),
+        );
+    }
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    if host.is_empty() || host.contains(char::is_whitespace) {
+        return Err(CliError::validation(format!("Invalid URL format: {value}"))
+            .with_suggestion("Use a valid URL like http://localhost:3737, or '' to clear"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::exit::{RESOURCE_ERROR, VALIDATION_ERROR};
+    use tempfile::TempDir;
+
+    #[test]
+    fn init_creates_files_and_set_validates() {
+        let temp = TempDir::new().unwrap();
+        let ctx = Context::new(Some(temp.path().join("config")));
+        let mut buffer = Vec::new();
+        let mut out = Output::new(Format::Human, false, &mut buffer);
+
+        init(&ctx, &mut out, InitOptions { force: true }).unwrap();
+        for file in CONFIG_FILES {
+            assert!(ctx.config_dir.join(file).exists(), "{file}");
+        }
+        assert!(ctx.projects.get("meatycapture").unwrap().is_some());
+
+        set(&ctx, &mut out, "default_project", "meatycapture").unwrap();
+        set(&ctx, &mut out, "api_url", "http://localhost:3737").unwrap();
+        assert_eq!(
+            set(&ctx, &mut out, "api_url", "ftp://host")
+                .unwrap_err()
+                .code,
+            VALIDATION_ERROR
+        );
+        assert_eq!(
+            set(&ctx, &mut out, "default_project", "nope")
+                .unwrap_err()
+                .code,
+            RESOURCE_ERROR
+        );
+        assert_eq!(
+            set(&ctx, &mut out, "colour", "red").unwrap_err().code,
+            VALIDATION_ERROR
+        );
+        let config = ctx.config.get().unwrap();
+        assert_eq!(config.default_project.as_deref(), Some("meatycapture"));
+        assert_eq!(config.api_url.as_deref(), Some("http://localhost:3737"));
+
+        set(&ctx, &mut out, "api_url", "none").unwrap();
+        assert_eq!(ctx.config.get().unwrap().api_url, None);
+
+        let text = String::from_utf8(buffer).unwrap();
+        assert!(text.starts_with("Initializing MeatyCapture configuration...\nCreated: "));
+        assert!(text.contains("✓ Configuration initialized successfully\n"));
+    }
+}
@@ -0,0 +1,768 @@
+//! Log Commands
+//!
+//! Request-log document commands (`meatycapture log ...`):
+//! - create: New document from a JSON item list
+//! - append: Add JSON items to an existing document
+//! - list: Document metadata for a project or directory
+//! - view: One document, optionally filtered by type/status/tag
+//! - search: Items matching a query across documents
+//! - delete: Remove a document (with confirmation and backup)
+//! - migrate: Upgrade documents to the current schema version
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::Local;
+use serde::Deserialize;
+
+use crate::cli::exit::{CliError, CliResult};
+use crate::cli::output::{Format, Output};
+use crate::cli::search::{search_documents, MatchMode};
+use crate::cli::{confirm, default_docs_dir, input_label, read_input, resolve, Context};
+use crate::doc_store::expand_path;
+use crate::error::Error;
+use crate::ids::{generate_doc_id, generate_item_id};
+use crate::lock::{lock_timeout_from_env, DocLock};
+use crate::migrate::migrate_documents;
+use crate::models::{ItemDraft, RequestLogDoc, RequestLogItem, SCHEMA_VERSION};
+use crate::ports::{DocStore, ProjectStore};
+use crate::serializer::{aggregate_tags, serialize, update_items_index};
+
+/// Example input shown when JSON input is malformed.
+const CREATE_FORMAT_HINT: &str = r#"{
+  "project": "project-slug",
+  "title": "Optional doc title",
+  "items": [{
+    "title": "Item title",
+    "type": "enhancement",
+    "domain": "web",
+    "context": "Context",
+    "priority": "medium",
+    "status": "triage",
+    "tags": ["tag1"],
+    "notes": "Description"
+  }]
+}"#;
+
+/// JSON input for `log create`.
+#[derive(Debug, Deserialize)]
+struct CreateInput {
+    project: String,
+    #[serde(default)]
+    title: Option<String>,
+    items: Vec<ItemDraft>,
+}
+
+/// JSON input for `log append`.
+#[derive(Debug, Deserialize)]
+struct AppendInput {
+    items: Vec<ItemDraft>,
+}
+
+/// Parses JSON input, mapping syntax and shape errors to validation errors.
+fn parse_input<T: serde::de::DeserializeOwned>(
+    content: &str,
+    source: &str,
+    has_items: impl Fn(&T) -> bool,
+) -> CliResult<T> {
+    let value: serde_json::Value = serde_json::from_str(content).map_err(|error| {
+        CliError::validation(format!("Invalid JSON in {source}: {error}")).with_suggestion(format!(
+            "Check for missing commas, quotes, or brackets. Expected format:\n{CREATE_FORMAT_HINT}"
+        ))
+    })?;
+
+    serde_json::from_value(value)
+        .ok()
+        .filter(has_items)
+        .ok_or_else(|| {
+            CliError::validation(format!(
+                "Invalid JSON structure in {source}. Missing required fields or incorrect types."
+            ))
+            .with_suggestion(format!("Expected format:\n{CREATE_FORMAT_HINT}"))
+        })
+}
+
+/// Default directory for a project's documents.
+///
+/// Resolution order:
+/// 1. The registered project's `default_path`
+/// 2. `$MEATYCAPTURE_DEFAULT_PROJECT_PATH/<project-id>`
+/// 3. `~/.meatycapture/docs/<project-id>`
+fn project_docs_dir(ctx: &Context, project_id: &str) -> CliResult<PathBuf> {
+    if let Some(project) = ctx.projects.get(project_id)? {
+        return Ok(expand_path(&project.default_path));
+    }
+
+    Ok(std::env::var_os("MEATYCAPTURE_DEFAULT_PROJECT_PATH")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(default_docs_dir)
+        .join(project_id))
+}
+
+/// Directory searched by `list` and `search`: `--path`, then the project
+/// directory, then `~/.meatycapture/docs`.
+fn search_dir(ctx: &Context, project: Option<&str>, path: Option<&str>) -> CliResult<PathBuf> {
+    match (path, project) {
+        (Some(path), _) => Ok(resolve(path)),
+        (None, Some(project)) => project_docs_dir(ctx, project),
+        (None, None) => Ok(default_docs_dir()),
+    }
+}
+
+/// [`search_dir`], failing if `project` names an unregistered project.
+fn registered_dir(ctx: &Context, project: Option<&str>, path: Option<&str>) -> CliResult<PathBuf> {
+    if let (None, Some(project)) = (path, project) {
+        if ctx.projects.get(project)?.is_none() {
+            return Err(CliError::resource(format!("Project not found: {project}"))
+                .with_suggestion("Run 'meatycapture project list' to see available projects"));
+        }
+    }
+    search_dir(ctx, project, path)
+}
+
+// ============================================================================
+// create / append
+// ============================================================================
+
+/// Options for `log create`.
+#[derive(Debug, Clone)]
+pub struct CreateOptions {
+    /// JSON input file, or `-` for stdin
+    pub input: String,
+    /// Document path (default: `<project dir>/<doc_id>.md`)
+    pub output: Option<String>,
+    /// Back up an existing file at the output path before overwriting
+    pub backup: bool,
+}
+
+/// Creates a document from `{project, title?, items}` JSON input.
+pub fn create(ctx: &Context, out: &mut Output, opts: CreateOptions) -> CliResult {
+    let input: CreateInput = parse_input(
+        &read_input(&opts.input)?,
+        &input_label(&opts.input),
+        |input: &CreateInput| !input.project.is_empty() && !input.items.is_empty(),
+    )?;
+
+    let now = ctx.clock.now();
+    let doc_id = generate_doc_id(&input.project, now.with_timezone(&Local).date_naive())
+        .map_err(Error::from)?;
+    let path = match &opts.output {
+        Some(output) => resolve(output),
+        None => project_docs_dir(ctx, &input.project)?.join(format!("{doc_id}.md")),
+    };
+
+    let items = input
+        .items
+        .into_iter()
+        .zip(1..)
+        .map(|(draft, number)| {
+            let id = generate_item_id(&doc_id, number).map_err(Error::from)?;
+            Ok(RequestLogItem::from_draft(draft, id, now))
+        })
+        .collect::<CliResult<Vec<_>>>()?;
+    let doc = RequestLogDoc {
+        schema_version: SCHEMA_VERSION,
+        title: input
+            .title
+            .filter(|title| !title.is_empty())
+            .unwrap_or_else(|| format!("Request Log - {}", input.project)),
+        doc_id,
+        project_id: input.project,
+        items_index: update_items_index(&items),
+        tags: aggregate_tags(&items),
+        item_count: items.len() as u64,
+        items,
+        created_at: now,
+        updated_at: now,
+        extra_frontmatter: vec![],
+        preamble: String::new(),
+    };
+
+    let path_str = path.display().to_string();
+    ctx.docs(opts.backup).write(&path_str, &doc)?;
+
+    out.record(&doc)?;
+    if out.format() == Format::Human {
+        out.line(format!("\nCreated: {path_str}"))?;
+    }
+    Ok(())
+}
+
+/// Options for `log append`.
+#[derive(Debug, Clone)]
+pub struct AppendOptions {
+    /// Existing document
+    pub doc_path: String,
+    /// JSON input file, or `-` for stdin
+    pub input: String,
+    /// Back up the document before modifying it
+    pub backup: bool,
+}
+
+/// Appends `{items}` JSON input to an existing document.
+pub fn append(ctx: &Context, out: &mut Output, opts: AppendOptions) -> CliResult {
+    let input: AppendInput = parse_input(
+        &read_input(&opts.input)?,
+        &input_label(&opts.input),
+        |input: &AppendInput| !input.items.is_empty(),
+    )?;
+    let count = input.items.len();
+
+    let path = resolve(&opts.doc_path).display().to_string();
+    let doc = ctx
+        .docs(opts.backup)
+        .append_all(&path, input.items, ctx.clock.as_ref())?;
+
+    if out.format() != Format::Human {
+        return out.record(&doc);
+    }
+    out.line(
+        [
+            format!("Appended {count} item(s) to: {path}"),
+            format!("  Doc ID: {}", doc.doc_id),
+            format!("  Total Items: {}", doc.item_count),
+            format!(
+                "  Tags: {}",
+                if doc.tags.is_empty() {
+                    "(none)".to_string()
+                } else {
+                    doc.tags.join(", ")
+                }
+            ),
+        ]
+        .join("\n"),
+    )
+}
+
+// ============================================================================
+// list / view
+// ============================================================================
+
+/// Sort order for `log list`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DocSort {
+    /// Document ID, ascending
+    Name,
+    /// Last update, newest first
+    #[default]
+    Date,
+    /// Item count, ascending
+    Items,
+}
+
+impl DocSort {
+    /// Parses `name`, `date` or `items`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "name" => Some(DocSort::Name),
+            "date" => Some(DocSort::Date),
+            "items" => Some(DocSort::Items),
+            _ => None,
+        }
+    }
+}
+
+/// Options for `log list`.
+#[derive(Debug, Clone, Default)]
+pub struct ListOptions {
+    /// Registered project whose directory is listed
+    pub project: Option<String>,
+    /// Directory to list instead of the project's
+    pub path: Option<String>,
+    pub sort: DocSort,
+    pub reverse: bool,
+    /// Maximum number of documents (must be positive)
+    pub limit: Option<usize>,
+}
+
+/// Lists document metadata.
+pub fn list(ctx: &Context, out: &mut Output, opts: ListOptions) -> CliResult {
+    if opts.limit == Some(0) {
+        return Err(CliError::validation("Limit must be a positive number"));
+    }
+    let dir = registered_dir(ctx, opts.project.as_deref(), opts.path.as_deref())?;
+    let mut metas = ctx.docs(true).list(&dir.display().to_string())?;
+
+    match opts.sort {
+        DocSort::Name => metas.sort_by(|a, b| a.doc_id.cmp(&b.doc_id)),
+        DocSort::Date => metas.sort_by_key(|meta| std::cmp::Reverse(meta.updated_at)),
+        DocSort::Items => metas.sort_by_key(|meta| meta.item_count),
+    }
+    if opts.reverse {
+        metas.reverse();
+    }
+    if let Some(limit) = opts.limit {
+        metas.truncate(limit);
+    }
+
+    out.records(&metas, &format!("No documents found in: {}", dir.display()))
+}
+
+/// Options for `log view`.
+#[derive(Debug, Clone, Default)]
+pub struct ViewOptions {
+    pub doc_path: String,
+    /// Print markdown instead of the selected format
+    pub markdown: bool,
+    /// Print only the (filtered) items
+    pub items_only: bool,
+    pub filter_type: Option<String>,
+    pub filter_status: Option<String>,
+    pub filter_tag: Option<String>,
+}
+
+impl ViewOptions {
+    fn has_filters(&self) -> bool {
+        self.filter_type.is_some() || self.filter_status.is_some() || self.filter_tag.is_some()
+    }
+
+    /// Case-insensitive AND of the active filters.
+    fn keeps(&self, item: &RequestLogItem) -> bool {
+        let eq = |filter: &Option<String>, value: &str| {
+            filter
+                .as_ref()
+                .is_none_or(|filter| filter.to_lowercase() == value.to_lowercase())
+        };
+        eq(&self.filter_type, &item.item_type)
+            && eq(&self.filter_status, &item.status)
+            && self.filter_tag.as_ref().is_none_or(|tag| {
+                let tag = tag.to_lowercase();
+                item.tags.iter().any(|t| t.to_lowercase() == tag)
+            })
+    }
+}
+
+/// Prints one document, optionally filtered.
+pub fn view(ctx: &Context, out: &mut Output, opts: ViewOptions) -> CliResult {
+    let mut doc = ctx
+        .docs(true)
+        .read(&resolve(&opts.doc_path).display().to_string())?;
+
+    if opts.has_filters() {
+        doc.items.retain(|item| opts.keeps(item));
+        doc.item_count = doc.items.len() as u64;
+        doc.tags = doc
+            .items
+            .iter()
+            .flat_map(|item| item.tags.iter().cloned())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        doc.items_index = update_items_index(&doc.items);
+    }
+
+    match (opts.items_only, opts.markdown) {
+        (true, true) => out.line(
+            doc.items
+                .iter()
+                .map(item_markdown)
+                .collect::<Vec<_>>()
+                .join("\n\n---\n\n"),
+        ),
+        (true, false) => out.records(&doc.items, "No items found."),
+        (false, true) => out.line(serialize(&doc)),
+        (false, false) => out.record(&doc),
+    }
+}
+
+/// The `## id - title` section of an item, as in the document body.
+fn item_markdown(item: &RequestLogItem) -> String {
+    [
+        format!("## {} - {}", item.id, item.title),
+        String::new(),
+        format!(
+            "**Type:** {} | **Domain:** {} | **Priority:** {} | **Status:** {}",
+            item.item_type, item.domain, item.priority, item.status
+        ),
+        format!("**Tags:** {}", item.tags.join(", ")),
+        format!("**Context:** {}", item.context),
+        String::new(),
+        "### Problem/Goal".to_string(),
+        item.notes.clone(),
+    ]
+    .join("\n")
+}
+
+// ============================================================================
+// search / delete
+// ============================================================================
+
+/// Options for `log search`.
+#[derive(Debug, Clone, Default)]
+pub struct SearchOptions {
+    pub query: String,
+    /// Project whose directory is searched
+    pub project: Option<String>,
+    /// Directory to search instead of the project's
+    pub path: Option<String>,
+    pub mode: MatchMode,
+    /// Maximum matches; 0 means unlimited
+    pub limit: usize,
+}
+
+/// Searches items across the documents in a directory.
+pub fn search(ctx: &Context, out: &mut Output, opts: SearchOptions) -> CliResult {
+    if opts.query.trim().is_empty() {
+        return Err(CliError::validation("Search query is required")
+            .with_suggestion("Provide a search query, e.g., meatycapture log search \"bug\""));
+    }
+
+    let dir = search_dir(ctx, opts.project.as_deref(), opts.path.as_deref())?;
+    let store = ctx.docs(true);
+    let docs: Vec<(String, RequestLogDoc)> = store
+        .list(&dir.display().to_string())?
+        .into_iter()
+        .filter_map(|meta| {
+            // Documents that fail to read are skipped, as in `list`
+            let doc = store.read(&meta.path).ok()?;
+            Some((meta.path, doc))
+        })
+        .collect();
+
+    let matches = search_documents(&docs, &opts.query, opts.mode, opts.limit);
+    out.records(&matches, "No matches found.")
+}
+
+/// Options for `log delete`.
+#[derive(Debug, Clone)]
+pub struct DeleteOptions {
+    pub doc_path: String,
+    /// Skip the confirmation prompt
+    pub force: bool,
+    /// Copy the document to `.bak` before deleting it
+    pub backup: bool,
+}
+
+/// Deletes a document after confirmation.
+pub fn delete(ctx: &Context, out: &mut Output, opts: DeleteOptions) -> CliResult {
+    let path = resolve(&opts.doc_path);
+    let path_str = path.display().to_string();
+    let store = ctx.docs(true);
+    let doc = store.read(&path_str)?;
+
+    if !opts.force {
+        let prompt = format!(
+            "Are you sure you want to delete {}?\nThis document contains {} item(s).{}\nType 'yes' to confirm: ",
+            doc.doc_id,
+            doc.item_count,
+            if opts.backup {
+                " A backup will be created."
+            } else {
+                ""
+            }
+        );
+        if !confirm(&prompt, &["yes"])? {
+            return Err(CliError::interrupted("Deletion cancelled."));
+        }
+    }
+
+    // Locked so a concurrent append cannot recreate the file mid-delete
+    let _lock = DocLock::acquire(&path, lock_timeout_from_env())?;
+    let backup = opts.backup.then(|| store.backup(&path_str)).transpose()?;
+    fs::remove_file(&path).map_err(Error::io(format!("Failed to delete {path_str}")))?;
+
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    out.line(format!("Deleted: {file_name}"))?;
+    if let Some(backup) = backup {
+        out.line(format!("  Backup: {backup}"))?;
+    }
+    Ok(())
+}
+
+// ============================================================================
+// migrate
+// ============================================================================
+
+/// Options for `log migrate`.
+#[derive(Debug, Clone, Default)]
+pub struct MigrateOptions {
+    /// Registered project whose directory is migrated
+    pub project: Option<String>,
+    /// Directory to migrate instead of the project's
+    pub path: Option<String>,
+    /// Print the changes without writing
+    pub dry_run: bool,
+}
+
+/// Upgrades every document in a directory to the current schema version.
+///
+/// Each rewritten file is backed up first. Fails after reporting if any
+/// document could not be migrated.
+pub fn migrate(ctx: &Context, out: &mut Output, opts: MigrateOptions) -> CliResult {
+    let dir = registered_dir(ctx, opts.project.as_deref(), opts.path.as_deref())?;
+    let report = migrate_documents(&ctx.docs(true), &dir.display().to_string(), opts.dry_run)?;
+
+    if out.format() != Format::Human {
+        out.structured(&report)?;
+    } else {
+        let verb = if opts.dry_run {
+            "Would migrate"
+        } else {
+            "Migrated"
+        };
+        let mut lines = vec![format!(
+            "{verb} {} document(s) in: {}",
+            report.migrated.len(),
+            report.directory
+        )];
+        for migration in &report.migrated {
+            lines.push(format!(
+                "  {} (v{} -> v{})",
+                migration.path, migration.from_version, migration.to_version
+            ));
+            if let Some(backup) = &migration.backup {
+                lines.push(format!("    Backup: {backup}"));
+            }
+            if opts.dry_run {
+                lines.push(String::new());
+                lines.push(migration.diff.trim_end().to_string());
+                lines.push(String::new());
+            }
+        }
+        lines.push(format!(
+            "{} document(s) already current",
+            report.current.len()
+        ));
+        for failure in &report.failed {
+            lines.push(format!("Failed: {} - {}", failure.path, failure.error));
+        }
+        out.line(lines.join("\n"))?;
+    }
+
+    if report.failed.is_empty() {
+        Ok(())
+    } else {
+        Err(CliError::validation(format!(
+            "{} document(s) could not be migrated",
+            report.failed.len()
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::NewProject;
+    use crate::ports::Clock;
+    use chrono::{DateTime, TimeZone, Utc};
+    use tempfile::TempDir;
+
+    struct FixedClock;
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            Utc.with_ymd_and_hms(2025, 12, 3, 12, 0, 0).unwrap()
+        }
+    }
+
+    fn draft(title: &str, status: &str, tags: &[&str]) -> serde_json::Value {
+        serde_json::json!({
+            "title": title,
+            "type": "bug",
+            "domain": "web",
+            "context": "",
+            "priority": "high",
+            "status": status,
+            "tags": tags,
+            "notes": "",
+        })
+    }
+
+    fn run(f: impl FnOnce(&mut Output) -> CliResult, format: Format) -> (CliResult, String) {
+        let mut buffer = Vec::new();
+        let result = f(&mut Output::new(format, false, &mut buffer));
+        (result, String::from_utf8(buffer).unwrap())
+    }
+
+    #[test]
+    fn create_append_view_and_list() {
+        let temp = TempDir::new().unwrap();
+        let ctx = Context::new(Some(temp.path().join("config"))).with_clock(FixedClock);
+        let docs_dir = temp.path().join("docs");
+        ctx.projects
+            .create(NewProject {
+                id: None,
+                name: "App".to_string(),
+                default_path: docs_dir.display().to_string(),
+                repo_url: None,
+                enabled: true,
+                auto_commit: None,
+            })
+            .unwrap();
+
+        let create_input = temp.path().join("create.json");
+        let items = vec![draft("First", "triage", &["ux"])];
+        fs::write(
+            &create_input,
+            serde_json::json!({"project": "app", "items": items}).to_string(),
+        )
+        .unwrap();
+        let (result, _) = run(
+            |out| {
+                create(
+                    &ctx,
+                    out,
+                    CreateOptions {
+                        input: create_input.display().to_string(),
+                        output: None,
+                        backup: true,
+                    },
+                )
+            },
+            Format::Json,
+        );
+        result.unwrap();
+
+        let doc_path = docs_dir.join("REQ-20251203-app.md");
+        let append_input = temp.path().join("append.json");
+        let items = vec![draft("Second", "done", &["api"])];
+        fs::write(
+            &append_input,
+            serde_json::json!({"items": items}).to_string(),
+        )
+        .unwrap();
+        let (result, human) = run(
+            |out| {
+                append(
+                    &ctx,
+                    out,
+                    AppendOptions {
+                        doc_path: doc_path.display().to_string(),
+                        input: append_input.display().to_string(),
+                        backup: false,
+                    },
+                )
+            },
+            Format::Human,
+        );
+        result.unwrap();
+        assert!(human.contains("Appended 1 item(s) to:"));
+        assert!(human.contains("  Total Items: 2\n  Tags: api, ux"));
+        assert!(!docs_dir.join("REQ-20251203-app.md.bak").exists());
+
+        let (result, viewed) = run(
+            |out| {
+                view(
+                    &ctx,
+                    out,
+                    ViewOptions {
+                        doc_path: doc_path.display().to_string(),
+                        filter_status: Some("DONE".to_string()),
+                        ..ViewOptions::default()
+                    },
+                )
+            },
+            Format::Json,
+        );
+        result.unwrap();
+        let viewed: RequestLogDoc = serde_json::from_str(&viewed).unwrap();
+        assert_eq!(viewed.item_count, 1);
+        assert_eq!(viewed.items[0].id, "REQ-20251203-app-02");
+        assert_eq!(viewed.tags, vec!["api".to_string()]);
+
+        let (result, listed) = run(
+            |out| {
+                list(
+                    &ctx,
+                    out,
+                    ListOptions {
+                        project: Some("app".to_string()),
+                        ..ListOptions::default()
+                    },
+                )
+            },
+            Format::Csv,
+        );
+        result.unwrap();
+        assert_eq!(listed.lines().count(), 2);
+        assert!(listed.contains(",REQ-20251203-app,Request Log - app,2,"));
+    }
+
+    #[test]
+    fn rejects_bad_input_and_unknown_projects() {
+        let temp = TempDir::new().unwrap();
+        let ctx = Context::new(Some(temp.path().to_path_buf()));
+        let input = temp.path().join("bad.json");
+
+        fs::write(&input, "{\"project\": \"app\", \"items\": [").unwrap();
+        let opts = CreateOptions {
+            input: input.display().to_string(),
+            output: None,
+            backup: true,
+        };
+        let (result, _) = run(|out| create(&ctx, out, opts.clone()), Format::Human);
+        assert!(result.unwrap_err().message.starts_with("Invalid JSON in "));
+
+        fs::write(&input, r#"{"project": "app", "items": []}"#).unwrap();
+        let (result, _) = run(|out| create(&ctx, out, opts), Format::Human);
+        assert!(result
+            .unwrap_err()
+            .message
+            .starts_with("Invalid JSON structure in "));
+
+        let (result, _) = run(
+            |out| {
+                list(
+                    &ctx,
+                    out,
+                    ListOptions {
+                        project: Some("missing".to_string()),
+                        ..ListOptions::default()
+                    },
+                )
+            },
+            Format::Human,
+        );
+        assert_eq!(result.unwrap_err().code, crate::cli::exit::RESOURCE_ERROR);
+    }
+
+    #[test]
+    fn migrate_previews_then_upgrades_documents() {
+        let temp = TempDir::new().unwrap();
+        let ctx = Context::new(Some(temp.path().join("config")));
+        let doc_path = temp.path().join("REQ-20251203-app.md");
+        let legacy = "---
+type: request-log
+doc_id: REQ-20251203-app
+title: App
+project_id: app
+item_count: 0
+tags: []
+items_index:
+created_at: 2025-12-03T10:00:00.000Z
+updated_at: 2025-12-03T10:00:00.000Z
+---
+
+";
+        fs::write(&doc_path, legacy).unwrap();
+        let opts = MigrateOptions {
+            path: Some(temp.path().display().to_string()),
+            dry_run: true,
+            ..MigrateOptions::default()
+        };
+
+        let (result, human) = run(|out| migrate(&ctx, out, opts.clone()), Format::Human);
+        result.unwrap();
+        assert!(human.starts_with("Would migrate 1 document(s) in: "));
+        assert!(human.contains("+schema_version: 2"));
+        assert_eq!(fs::read_to_string(&doc_path).unwrap(), legacy);
+
+        let opts = MigrateOptions {
+            dry_run: false,
+            ..opts
+        };
+        let (result, json) = run(|out| migrate(&ctx, out, opts.clone()), Format::Json);
+        result.unwrap();
+        let report: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(report["migrated"][0]["to_version"], 2);
+        assert!(fs::read_to_string(&doc_path)
+            .unwrap()
+            .contains("schema_version: 2"));
+
+        let (result, human) = run(|out| migrate(&ctx, out, opts), Format::Human);
+        result.unwrap();
+        assert!(human.contains("1 document(s) already current"));
+    }
+}
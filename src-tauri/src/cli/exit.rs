@@ -0,0 +1,163 @@
+//! Exit Codes
+//!
+//! Process exit codes and the CLI error type, as documented in
+//! `docs/user/cli/exit-codes.md`:
+//! - 0: Success (including empty list/search results)
+//! - 1: Validation error (bad JSON, missing fields, invalid values)
+//! - 2: I/O error (file not found, permission denied)
+//! - 3: Resource error (project not found, unparseable document, locked)
+//! - 64: Usage error (unknown command or flag)
+//! - 130: Interrupted (Ctrl+C or a declined confirmation)
+
+use std::fmt;
+
+use crate::error::Error;
+
+/// Command completed successfully.
+pub const SUCCESS: u8 = 0;
+/// Invalid input, format, or validation failure.
+pub const VALIDATION_ERROR: u8 = 1;
+/// File system error, permission denied.
+pub const IO_ERROR: u8 = 2;
+/// Resource not found or unavailable.
+pub const RESOURCE_ERROR: u8 = 3;
+/// Invalid command or flag combination (BSD `EX_USAGE`).
+pub const USAGE_ERROR: u8 = 64;
+/// User interrupted or cancelled (128 + SIGINT).
+pub const INTERRUPTED: u8 = 130;
+
+/// Result alias for CLI command handlers.
+pub type CliResult<T = ()> = std::result::Result<T, CliError>;
+
+/// Error reported on stderr as `Error: <message>` with an optional
+/// `  -> <suggestion>` line, exiting with `code`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliError {
+    /// Process exit code
+    pub code: u8,
+    /// Human-readable message
+    pub message: String,
+    /// Optional hint on how to fix the problem
+    pub suggestion: Option<String>,
+}
+
+impl CliError {
+    /// Creates an error with an explicit exit code.
+    pub fn new(code: u8, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            suggestion: None,
+        }
+    }
+
+    /// Invalid input (exit 1).
+    pub fn validation(message: impl Into<String>) -> Self {
+        Self::new(VALIDATION_ERROR, message)
+    }
+
+    /// File system failure (exit 2).
+    pub fn io(message: impl Into<String>) -> Self {
+        Self::new(IO_ERROR, message)
+    }
+
+    /// Missing or unavailable resource (exit 3).
+    pub fn resource(message: impl Into<String>) -> Self {
+        Self::new(RESOURCE_ERROR, message)
+    }
+
+    /// Invalid command line (exit 64).
+    pub fn usage(message: impl Into<String>) -> Self {
+        Self::new(USAGE_ERROR, message)
+    }
+
+    /// Cancelled by the user (exit 130).
+    pub fn interrupted(message: impl Into<String>) -> Self {
+        Self::new(INTERRUPTED, message)
+    }
+
+    /// Adds a `  -> suggestion` line.
+    pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Error: {}", self.message)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, "\n  -> {suggestion}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<Error> for CliError {
+    fn from(error: Error) -> Self {
+        let code = match &error {
+            Error::Io { .. }
+            | Error::Watch { .. }
+            | Error::Search { .. }
+            | Error::OutOfScope { .. } => IO_ERROR,
+            #[cfg(feature = "git")]
+            Error::Git { .. } => IO_ERROR,
+            Error::Json { .. }
+            | Error::Id(_)
+            | Error::Validation(_)
+            | Error::InvalidPath { .. }
+            | Error::InvalidShortcut { .. } => VALIDATION_ERROR,
+            Error::Parse { .. }
+            | Error::NotFound(_)
+            | Error::Locked { .. }
+            | Error::ShortcutUnavailable { .. } => RESOURCE_ERROR,
+        };
+        let cli_error = CliError::new(code, error.to_string());
+
+        match error {
+            Error::Parse { .. } => {
+                cli_error.with_suggestion("Check the document format and fix any syntax errors")
+            }
+            Error::Locked { .. } => {
+                cli_error.with_suggestion("Retry, or raise MEATYCAPTURE_LOCK_TIMEOUT_MS")
+            }
+            _ => cli_error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serializer::ParseError;
+
+    #[test]
+    fn store_errors_map_to_documented_codes() {
+        let missing = Error::io("Failed to read document /x.md")(std::io::Error::from(
+            std::io::ErrorKind::NotFound,
+        ));
+        let parse = Error::Parse {
+            path: "/x.md".to_string(),
+            source: ParseError::MissingField("doc_id"),
+        };
+
+        assert_eq!(CliError::from(missing).code, IO_ERROR);
+        assert_eq!(CliError::from(parse).code, RESOURCE_ERROR);
+        assert_eq!(
+            CliError::from(Error::Validation("bad".to_string())).code,
+            VALIDATION_ERROR
+        );
+        assert_eq!(
+            CliError::from(Error::NotFound("Project not found: x".to_string())).code,
+            RESOURCE_ERROR
+        );
+    }
+
+    #[test]
+    fn display_includes_suggestion() {
+        let error = CliError::validation("Bad input").with_suggestion("Fix it");
+        assert_eq!(error.to_string(), "Error: Bad input\n  -> Fix it");
+    }
+}
@@ -0,0 +1,583 @@
+//! Field Commands
+//!
+//! Field catalog commands (`meatycapture field ...`):
+//! - add: New global or project-scoped option
+//! - list: Options grouped by field, values sorted
+//! - remove: Delete an option by ID (with confirmation)
+//! - import: Bulk-add options from a JSON or YAML file
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::cli::exit::{CliError, CliResult};
+use crate::cli::output::{to_json, Format, Output, Record};
+use crate::cli::projects::require_project;
+use crate::cli::{confirm, read_input, Context};
+use crate::models::{FieldName, FieldOption, FieldScope, NewFieldOption};
+use crate::ports::{FieldCatalogStore, ProjectStore};
+use crate::serializer::to_iso_string;
+
+/// Suggestion listing the accepted field names.
+fn field_names_hint() -> String {
+    let names: Vec<&str> = FieldName::ALL.iter().map(FieldName::as_str).collect();
+    format!("Valid field names: {}", names.join(", "))
+}
+
+/// Parses a field name, failing with a validation error.
+fn parse_field(field: &str) -> CliResult<FieldName> {
+    FieldName::parse(field).ok_or_else(|| {
+        CliError::validation(format!("Invalid field name: {field}"))
+            .with_suggestion(field_names_hint())
+    })
+}
+
+/// Options in `scope` (global, or one project's own options).
+fn scoped_options(ctx: &Context, project: Option<&str>) -> CliResult<Vec<FieldOption>> {
+    Ok(match project {
+        Some(project) => ctx
+            .fields
+            .get_for_project(project)?
+            .into_iter()
+            .filter(|opt| opt.project_id.as_deref() == Some(project))
+            .collect(),
+        None => ctx.fields.get_global()?,
+    })
+}
+
+fn new_option(field: FieldName, value: String, project: Option<&str>) -> NewFieldOption {
+    NewFieldOption {
+        field,
+        value,
+        scope: match project {
+            Some(_) => FieldScope::Project,
+            None => FieldScope::Global,
+        },
+        project_id: project.map(str::to_string),
+    }
+}
+
+// ============================================================================
+// add / list / remove
+// ============================================================================
+
+/// Options for `field add`.
+#[derive(Debug, Clone, Default)]
+pub struct AddOptions {
+    pub field: String,
+    pub value: String,
+    /// Project for a project-scoped option (default: global)
+    pub project: Option<String>,
+}
+
+/// Adds a field option.
+pub fn add(ctx: &Context, out: &mut Output, opts: AddOptions) -> CliResult {
+    let field = parse_field(&opts.field)?;
+    let value = opts.value.trim();
+    if value.is_empty() {
+        return Err(CliError::validation("Value cannot be empty")
+            .with_suggestion("Provide a non-empty value for the field option"));
+    }
+    let project = opts.project.as_deref();
+    if let Some(project) = project {
+        require_project(ctx, project)?;
+    }
+
+    if scoped_options(ctx, project)?
+        .iter()
+        .any(|opt| opt.field == field && opt.value == value)
+    {
+        return Err(CliError::resource(format!(
+            "Field already exists: {}:{value}",
+            field.as_str()
+        ))
+        .with_suggestion("Use a different ID or remove the existing field first"));
+    }
+
+    let option = ctx
+        .fields
+        .add_option(new_option(field, value.to_string(), project))?;
+
+    match out.format() {
+        // Explicit `project_id: null` for global options, as the TS CLI
+        Format::Json | Format::Yaml => out.structured(&json!({
+            "id": option.id,
+            "field": option.field,
+            "value": option.value,
+            "scope": option.scope,
+            "project_id": option.project_id,
+            "created_at": to_iso_string(&option.created_at),
+        })),
+        _ => {
+            let scope = match &option.project_id {
+                Some(project) => format!("project: {project}"),
+                None => "global".to_string(),
+            };
+            out.line(format!(
+                "Added option: {} to {} [{scope}]\n  ID: {}",
+                option.value,
+                option.field.as_str(),
+                option.id
+            ))
+        }
+    }
+}
+
+/// Options for `field list`.
+#[derive(Debug, Clone, Default)]
+pub struct ListOptions {
+    /// Only this field
+    pub field: Option<String>,
+    /// Effective options for a project (global + project)
+    pub project: Option<String>,
+    /// Global options only (the default without `project`)
+    pub global_only: bool,
+}
+
+/// Lists field options grouped by field name.
+pub fn list(ctx: &Context, out: &mut Output, opts: ListOptions) -> CliResult {
+    let field = opts.field.as_deref().map(parse_field).transpose()?;
+    let project = opts.project.as_deref().filter(|_| !opts.global_only);
+    if let Some(project) = project {
+        require_project(ctx, project)?;
+    }
+
+    let mut options: Vec<FieldOption> = match project {
+        Some(project) => ctx.fields.get_for_project(project)?,
+        None => ctx.fields.get_global()?,
+    }
+    .into_iter()
+    .filter(|opt| field.is_none_or(|field| opt.field == field))
+    .collect();
+    options.sort_by(|a, b| {
+        a.field
+            .as_str()
+            .cmp(b.field.as_str())
+            .then_with(|| a.value.cmp(&b.value))
+    });
+
+    let mut grouped: BTreeMap<&str, Vec<&FieldOption>> = BTreeMap::new();
+    for option in &options {
+        grouped
+            .entry(option.field.as_str())
+            .or_default()
+            .push(option);
+    }
+
+    match out.format() {
+        Format::Json | Format::Yaml if grouped.is_empty() => out.line("{}"),
+        Format::Json | Format::Yaml => out.structured(&grouped),
+        Format::Csv | Format::Table => out.records(&options, "No field options found"),
+        Format::Human if grouped.is_empty() => out.line(format!(
+            "No field options found{}{}.",
+            opts.field
+                .as_deref()
+                .map(|f| format!(" for field: {f}"))
+                .unwrap_or_default(),
+            project
+                .map(|p| format!(" (project: {p})"))
+                .unwrap_or_default(),
+        )),
+        Format::Human => out.line(
+            grouped
+                .iter()
+                .map(|(field, options)| {
+                    let mut lines = vec![format!("{field}:")];
+                    lines.extend(options.iter().map(|opt| format!("  {}", opt.human())));
+                    lines.join("\n")
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n"),
+        ),
+    }
+}
+
+/// Options for `field remove`.
+#[derive(Debug, Clone, Default)]
+pub struct RemoveOptions {
+    pub id: String,
+    /// Skip the confirmation prompt
+    pub force: bool,
+}
+
+/// Removes a field option by ID.
+pub fn remove(ctx: &Context, out: &mut Output, opts: RemoveOptions) -> CliResult {
+    // Look in every scope so project options get a descriptive prompt too
+    let mut options = ctx.fields.get_global()?;
+    for project in ctx.projects.list()? {
+        options.extend(scoped_options(ctx, Some(&project.id))?);
+    }
+    let Some(option) = options.into_iter().find(|opt| opt.id == opts.id) else {
+        return Err(CliError::resource(format!("Field not found: {}", opts.id)));
+    };
+
+    if !opts.force {
+        let scope = match &option.project_id {
+            Some(project) => format!("[project] (project: {project})"),
+            None => "[global]".to_string(),
+        };
+        let prompt = format!(
+            "Remove field option: {} = \"{}\" {scope}?\nType 'y' or 'yes' to confirm: ",
+            option.field.as_str(),
+            option.value
+        );
+        if !confirm(&prompt, &["y", "yes"])? {
+            return Err(CliError::interrupted("Removal cancelled."));
+        }
+    }
+
+    ctx.fields.remove_option(&option.id)?;
+    out.line(format!(
+        "Removed: {} option \"{}\"",
+        option.field.as_str(),
+        option.value
+    ))
+}
+
+// ============================================================================
+// import
+// ============================================================================
+
+/// Options for `field import`.
+#[derive(Debug, Clone, Default)]
+pub struct ImportOptions {
+    /// `.json`, `.yaml` or `.yml` file
+    pub file: String,
+    /// Import as options of this project (default: global)
+    pub project: Option<String>,
+    /// Skip existing values instead of failing
+    pub merge: bool,
+}
+
+/// Per-field import counts.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct FieldCounts {
+    pub added: usize,
+    pub skipped: usize,
+}
+
+/// Result of `field import`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ImportSummary {
+    pub total_fields: usize,
+    pub total_values: usize,
+    pub added: usize,
+    pub skipped: usize,
+    pub fields: BTreeMap<String, FieldCounts>,
+}
+
+/// Imports `{field: [values]}` from a JSON or YAML file.
+pub fn import(ctx: &Context, out: &mut Output, opts: ImportOptions) -> CliResult {
+    let input = parse_import(&opts.file, &read_input(&opts.file)?)?;
+    let project = opts.project.as_deref();
+    if let Some(project) = project {
+        require_project(ctx, project)?;
+    }
+
+    let mut existing: Vec<(FieldName, String)> = scoped_options(ctx, project)?
+        .into_iter()
+        .map(|opt| (opt.field, opt.value))
+        .collect();
+    let duplicates: Vec<String> = input
+        .iter()
+        .flat_map(|(field, values)| values.iter().map(move |value| (*field, value)))
+        .filter(|(field, value)| existing.iter().any(|(f, v)| f == field && v == *value))
+        .map(|(field, value)| format!("{}:{value}", field.as_str()))
+        .collect();
+    if !duplicates.is_empty() && !opts.merge {
+        return Err(
+            CliError::resource(format!("Field already exists: {}", duplicates.join(", ")))
+                .with_suggestion("Use --merge to skip existing values and add only new ones"),
+        );
+    }
+
+    let mut summary = ImportSummary {
+        total_fields: input.len(),
+        total_values: input.iter().map(|(_, values)| values.len()).sum(),
+        ..ImportSummary::default()
+    };
+    for (field, values) in input {
+        let counts = summary
+            .fields
+            .entry(field.as_str().to_string())
+            .or_default();
+        for value in values {
+            if existing.iter().any(|(f, v)| *f == field && *v == value) {
+                counts.skipped += 1;
+                continue;
+            }
+            ctx.fields
+                .add_option(new_option(field, value.clone(), project))?;
+            existing.push((field, value));
+            counts.added += 1;
+        }
+    }
+    summary.added = summary.fields.values().map(|c| c.added).sum();
+    summary.skipped = summary.fields.values().map(|c| c.skipped).sum();
+
+    match out.format() {
+        Format::Json | Format::Yaml => out.structured(&summary),
+        _ => {
+            let mut lines = vec![
+                format!(
+                    "Import complete: {} added, {} skipped",
+                    summary.added, summary.skipped
+                ),
+                String::new(),
+            ];
+            for (field, counts) in &summary.fields {
+                let parts: Vec<String> = [(counts.added, "added"), (counts.skipped, "skipped")]
+                    .into_iter()
+                    .filter(|(n, _)| *n > 0)
+                    .map(|(n, label)| format!("{n} {label}"))
+                    .collect();
+                lines.push(format!("  {field}: {}", parts.join(", ")));
+            }
+            out.line(lines.join("\n"))
+        }
+    }
+}
+
+/// Parses and validates import content; values are trimmed.
+fn parse_import(file: &str, content: &str) -> CliResult<Vec<(FieldName, Vec<String>)>> {
+    let is_yaml = Path::new(file)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml"));
+    let parsed = if is_yaml {
+        parse_yaml_lists(content)
+    } else {
+        serde_json::from_str(content).map_err(|error| error.to_string())
+    };
+    let value = parsed.map_err(|error| {
+        CliError::validation(format!("Failed to parse {file}: {error}")).with_suggestion(
+            if is_yaml {
+                "Check YAML syntax and indentation"
+            } else {
+                "Check JSON syntax for missing commas or quotes"
+            },
+        )
+    })?;
+
+    let Value::Object(map) = value else {
+        return Err(
+            CliError::validation("Import file must be an object with field names as keys")
+                .with_suggestion("Format: { \"field\": [\"value1\", \"value2\"], ... }"),
+        );
+    };
+
+    map.into_iter()
+        .map(|(name, values)| {
+            let field = FieldName::parse(&name).ok_or_else(|| {
+                CliError::validation(format!("Invalid field name in import: {name}"))
+                    .with_suggestion(field_names_hint())
+            })?;
+            let Value::Array(values) = values else {
+                return Err(CliError::validation(format!(
+                    "Field \"{name}\" must have an array of values"
+                ))
+                .with_suggestion(format!("Format: \"{name}\": [\"value1\", \"value2\"]")));
+            };
+            let values = values
+                .into_iter()
+                .map(|value| match value.as_str().map(str::trim) {
+                    Some(text) if !text.is_empty() => Ok(text.to_string()),
+                    _ => Err(CliError::validation(format!(
+                        "Field \"{name}\" contains invalid value: {}",
+                        to_json(&value).unwrap_or_default()
+                    ))
+                    .with_suggestion("All values must be non-empty strings")),
+                })
+                .collect::<CliResult<Vec<_>>>()?;
+            Ok((field, values))
+        })
+        .collect()
+}
+
+/// Reads the YAML subset import files use: top-level keys holding block
+/// (`- value`) or flow (`[a, b]`) lists of scalars.
+fn parse_yaml_lists(content: &str) -> Result<Value, String> {
+    let mut map = serde_json::Map::new();
+    let mut current: Option<String> = None;
+
+    for (number, raw) in content.lines().enumerate() {
+        let line = raw.split(" #").next().unwrap_or_default().trim_end();
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+
+        if let Some(item) = line
+            .trim_start()
+            .strip_prefix("- ")
+            .or_else(|| (line.trim_start() == "-").then_some(""))
+        {
+            let key = current
+                .as_ref()
+                .ok_or_else(|| format!("line {}: list item outside a key", number + 1))?;
+            if let Some(Value::Array(values)) = map.get_mut(key) {
+                values.push(yaml_scalar(item.trim()));
+            }
+        } else if !raw.starts_with([' ', '\t']) {
+            let (key, rest) = line
+                .split_once(':')
+                .ok_or_else(|| format!("line {}: expected `key:`", number + 1))?;
+            let key = unquote(key.trim()).to_string();
+            let rest = rest.trim();
+            let value = match rest.strip_prefix('[').and_then(|r| r.strip_suffix(']')) {
+                Some(items) => Value::Array(
+                    items
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|item| !item.is_empty())
+                        .map(yaml_scalar)
+                        .collect(),
+                ),
+                None if rest.is_empty() => Value::Array(Vec::new()),
+                None => yaml_scalar(rest),
+            };
+            map.insert(key.clone(), value);
+            current = Some(key);
+        } else {
+            return Err(format!("line {}: unexpected indentation", number + 1));
+        }
+    }
+
+    Ok(Value::Object(map))
+}
+
+fn yaml_scalar(text: &str) -> Value {
+    match text {
+        "" | "~" | "null" => Value::Null,
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => match text.parse::<f64>() {
+            Ok(number) if !text.starts_with(['"', '\'']) => json!(number),
+            _ => Value::String(unquote(text).to_string()),
+        },
+    }
+}
+
+fn unquote(text: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = text.strip_prefix(quote).and_then(|t| t.strip_suffix(quote)) {
+            return inner;
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::exit::{RESOURCE_ERROR, VALIDATION_ERROR};
+    use tempfile::TempDir;
+
+    #[test]
+    fn yaml_subset_parses_block_and_flow_lists() {
+        let value =
+            parse_yaml_lists("# options\ntype:\n  - spike\n  - \"chore\"\ndomain: [web, 'api']\n")
+                .unwrap();
+        assert_eq!(
+            value,
+            json!({"type": ["spike", "chore"], "domain": ["web", "api"]})
+        );
+        assert!(parse_yaml_lists("  - orphan\n").is_err());
+    }
+
+    #[test]
+    fn add_list_and_import() {
+        let temp = TempDir::new().unwrap();
+        let ctx = Context::new(Some(temp.path().to_path_buf()));
+        let mut buffer = Vec::new();
+        let mut out = Output::new(Format::Human, false, &mut buffer);
+
+        let add_opts = |field: &str, value: &str| AddOptions {
+            field: field.to_string(),
+            value: value.to_string(),
+            project: None,
+        };
+        add(&ctx, &mut out, add_opts("type", " spike ")).unwrap();
+        assert_eq!(
+            add(&ctx, &mut out, add_opts("type", "spike"))
+                .unwrap_err()
+                .code,
+            RESOURCE_ERROR
+        );
+        assert_eq!(
+            add(&ctx, &mut out, add_opts("colour", "red"))
+                .unwrap_err()
+                .code,
+            VALIDATION_ERROR
+        );
+
+        let file = temp.path().join("options.json");
+        std::fs::write(&file, r#"{"type": ["spike", "chore"], "domain": ["web"]}"#).unwrap();
+        let import_opts = ImportOptions {
+            file: file.display().to_string(),
+            project: None,
+            merge: false,
+        };
+        assert_eq!(
+            import(&ctx, &mut out, import_opts.clone())
+                .unwrap_err()
+                .code,
+            RESOURCE_ERROR
+        );
+        import(
+            &ctx,
+            &mut out,
+            ImportOptions {
+                merge: true,
+                ..import_opts
+            },
+        )
+        .unwrap();
+
+        list(
+            &ctx,
+            &mut out,
+            ListOptions {
+                field: Some("domain".to_string()),
+                ..ListOptions::default()
+            },
+        )
+        .unwrap();
+
+        let text = String::from_utf8(buffer).unwrap();
+        assert!(text.contains("Added option: spike to type [global]"));
+        assert!(text.contains("Import complete: 2 added, 1 skipped\n\n  domain: 1 added\n  type: 1 added, 1 skipped\n"));
+        assert!(text.contains("\ndomain:\n  web (domain-web-"));
+        assert!(!text.contains("\ntype:\n"));
+    }
+
+    #[test]
+    fn remove_by_id() {
+        let temp = TempDir::new().unwrap();
+        let ctx = Context::new(Some(temp.path().to_path_buf()));
+        let mut buffer = Vec::new();
+        let mut out = Output::new(Format::Human, false, &mut buffer);
+
+        let bug = ctx
+            .fields
+            .get_by_field(FieldName::Type, None)
+            .unwrap()
+            .into_iter()
+            .find(|opt| opt.value == "bug")
+            .unwrap();
+        let opts = RemoveOptions {
+            id: bug.id,
+            force: true,
+        };
+        remove(&ctx, &mut out, opts.clone()).unwrap();
+        assert_eq!(
+            remove(&ctx, &mut out, opts).unwrap_err().code,
+            RESOURCE_ERROR
+        );
+
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            "Removed: type option \"bug\"\n"
+        );
+    }
+}
@@ -0,0 +1,175 @@
+//! Headless CLI
+//!
+//! `meatycapture-cli`, a webview-free port of the TypeScript CLI that reads
+//! and writes the same files:
+//! - docs: `log create|append|list|view|search|delete`
+//! - projects: `project add|list|update|enable|disable|set-default`
+//! - fields: `field add|list|remove|import`
+//! - config: `config init|show|set`
+//! - mcp: Model Context Protocol server on stdio (see [`crate::mcp`])
+//!
+//! Handlers take a [`Context`] and an [`Output`](output::Output) and return
+//! a [`CliResult`](exit::CliResult); only [`main`] touches the process
+//! (argument parsing, stdout/stderr, exit code). Remote API mode is not
+//! supported: every command works on local files.
+
+mod args;
+pub mod config;
+pub mod docs;
+pub mod exit;
+pub mod fields;
+pub mod output;
+pub mod projects;
+pub mod search;
+
+use std::io::{self, BufRead, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use crate::backups::BackupHistory;
+use crate::config_store::{
+    config_dir, LocalConfigStore, LocalFieldCatalogStore, LocalProjectStore,
+};
+use crate::doc_store::{expand_path, FsDocStore};
+use crate::lock::lock_timeout_from_env;
+use crate::ports::{Clock, SystemClock};
+
+use exit::{CliError, CliResult};
+
+/// Stores and settings shared by every command.
+pub struct Context {
+    /// Directory holding `config.json`, `projects.json` and `fields.json`
+    pub config_dir: PathBuf,
+    pub projects: LocalProjectStore,
+    pub fields: LocalFieldCatalogStore,
+    pub config: LocalConfigStore,
+    pub clock: Box<dyn Clock>,
+}
+
+impl Context {
+    /// Opens the stores in `config_dir` (defaults to [`config_dir()`]).
+    pub fn new(config_dir: Option<PathBuf>) -> Self {
+        let config_dir = config_dir.unwrap_or_else(self::config_dir);
+        Self {
+            projects: LocalProjectStore::new(Some(config_dir.clone())),
+            fields: LocalFieldCatalogStore::new(Some(config_dir.clone())),
+            config: LocalConfigStore::new(Some(config_dir.clone())),
+            config_dir,
+            clock: Box::new(SystemClock),
+        }
+    }
+
+    /// Replaces the system clock (tests).
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Document store honoring `MEATYCAPTURE_LOCK_TIMEOUT_MS`; `backup`
+    /// controls the `.bak` copy and history snapshot made before overwriting.
+    /// Writes to auto-commit projects are committed (`git` feature).
+    pub fn docs(&self, backup: bool) -> FsDocStore {
+        let store = FsDocStore::with_lock_timeout(lock_timeout_from_env())
+            .with_history(Arc::new(BackupHistory::open(&self.config_dir)));
+        #[cfg(feature = "git")]
+        let store = store.with_git(Arc::new(crate::git::GitAutoCommit::new(
+            LocalProjectStore::new(Some(self.config_dir.clone())),
+        )));
+        if backup {
+            store
+        } else {
+            store.without_backups()
+        }
+    }
+}
+
+/// Directory used when no project path applies: `~/.meatycapture/docs`.
+pub fn default_docs_dir() -> PathBuf {
+    expand_path("~/.meatycapture/docs")
+}
+
+/// Expands `~` and makes `path` absolute against the working directory.
+pub fn resolve(path: &str) -> PathBuf {
+    let path = expand_path(path);
+    std::path::absolute(&path).unwrap_or(path)
+}
+
+/// Reads a file, or stdin when `path` is `-`.
+pub fn read_input(path: &str) -> CliResult<String> {
+    if path == "-" {
+        let mut content = String::new();
+        io::stdin()
+            .read_to_string(&mut content)
+            .map_err(|error| CliError::io(format!("Failed to read stdin: {error}")))?;
+        return Ok(content);
+    }
+
+    let path = expand_path(path);
+    std::fs::read_to_string(&path)
+        .map_err(|error| CliError::io(format!("Failed to read {}: {error}", path.display())))
+}
+
+/// Human label for an input source in error messages.
+pub fn input_label(path: &str) -> String {
+    match path {
+        "-" => "stdin".to_string(),
+        path => path.to_string(),
+    }
+}
+
+/// Prompts on stderr and reads one answer from stdin.
+///
+/// Returns whether the trimmed, lowercased answer is one of `accepted`.
+/// End of input counts as a decline.
+pub fn confirm(prompt: &str, accepted: &[&str]) -> CliResult<bool> {
+    eprint!("{prompt}");
+    io::stderr().flush().ok();
+
+    let mut answer = String::new();
+    io::stdin()
+        .lock()
+        .read_line(&mut answer)
+        .map_err(|error| CliError::io(format!("Failed to read confirmation: {error}")))?;
+
+    let answer = answer.trim().to_lowercase();
+    Ok(accepted.contains(&answer.as_str()))
+}
+
+/// Whether `path` is a directory this process can create files in.
+pub fn is_writable_dir(path: &Path) -> bool {
+    std::fs::metadata(path).is_ok_and(|meta| meta.is_dir() && !meta.permissions().readonly())
+}
+
+/// Entry point of the `meatycapture-cli` binary.
+pub fn main() -> ExitCode {
+    let cli = match args::parse() {
+        Ok(cli) => cli,
+        Err(error) => {
+            let code = match error.kind() {
+                clap::error::ErrorKind::DisplayHelp
+                | clap::error::ErrorKind::DisplayVersion
+                | clap::error::ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand => exit::SUCCESS,
+                _ => exit::USAGE_ERROR,
+            };
+            error.print().ok();
+            return ExitCode::from(code);
+        }
+    };
+
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    match args::dispatch(cli, &mut stdout) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            stdout.flush().ok();
+            // Cancellations are not failures: print the bare message
+            if error.code == exit::INTERRUPTED {
+                eprintln!("{}", error.message);
+            } else {
+                eprintln!("{error}");
+            }
+            ExitCode::from(error.code)
+        }
+    }
+}
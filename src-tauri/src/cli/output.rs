@@ -0,0 +1,668 @@
+//! Output Formatting
+//!
+//! Renders command results in the formats the TS CLI supports:
+//! - human: Plain, labelled text (default)
+//! - json: Pretty-printed JSON (2-space indent)
+//! - yaml: Block-style YAML
+//! - csv: RFC 4180 with a header row; arrays joined with `;`
+//! - table: Boxed ASCII table with long values truncated
+//!
+//! Timestamps use the `toISOString` format everywhere (see `models::iso8601`).
+
+use std::io::Write;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::cli::exit::{CliError, CliResult};
+use crate::models::{DocMeta, FieldOption, FieldScope, Project, RequestLogDoc, RequestLogItem};
+use crate::serializer::to_iso_string;
+
+/// Maximum table cell width before truncation.
+const MAX_COL_WIDTH: usize = 40;
+
+/// Width of the rule between records in human output.
+const RULE_WIDTH: usize = 60;
+
+/// Output format selected by the `--json/--yaml/--csv/--table` flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Human,
+    Json,
+    Yaml,
+    Csv,
+    Table,
+}
+
+impl Format {
+    /// Resolves mutually exclusive flags: json > yaml > csv > table > human.
+    pub fn from_flags(json: bool, yaml: bool, csv: bool, table: bool) -> Self {
+        if json {
+            Format::Json
+        } else if yaml {
+            Format::Yaml
+        } else if csv {
+            Format::Csv
+        } else if table {
+            Format::Table
+        } else {
+            Format::Human
+        }
+    }
+}
+
+/// A value that can be listed in every output format.
+pub trait Record: Serialize + Sized {
+    /// CSV header and table columns, in order
+    const COLUMNS: &'static [&'static str];
+    /// Plural noun for human list headers, e.g. `document(s)`
+    const NOUN: &'static str;
+
+    /// Cell values matching [`Record::COLUMNS`].
+    fn row(&self) -> Vec<String>;
+
+    /// Multi-line human rendering of a single record.
+    fn human(&self) -> String;
+
+    /// Human rendering of a non-empty list.
+    fn human_list(records: &[Self]) -> String {
+        let mut lines = vec![
+            format!("Found {} {}:", records.len(), Self::NOUN),
+            String::new(),
+        ];
+        for record in records {
+            lines.push(rule());
+            lines.push(record.human());
+            lines.push(String::new());
+        }
+        lines.join("\n")
+    }
+}
+
+/// Destination for command output honoring `--quiet` and the format flags.
+///
+/// Errors are not written here; they go to stderr from the entry point.
+pub struct Output<'w> {
+    format: Format,
+    quiet: bool,
+    writer: &'w mut dyn Write,
+}
+
+impl<'w> Output<'w> {
+    /// Creates an output writing to `writer` (stdout in the binary).
+    pub fn new(format: Format, quiet: bool, writer: &'w mut dyn Write) -> Self {
+        Self {
+            format,
+            quiet,
+            writer,
+        }
+    }
+
+    /// Selected output format.
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Whether non-error output is suppressed.
+    pub fn is_quiet(&self) -> bool {
+        self.quiet
+    }
+
+    /// Writes `text` plus a newline unless quiet.
+    pub fn line(&mut self, text: impl AsRef<str>) -> CliResult {
+        if self.quiet {
+            return Ok(());
+        }
+        writeln!(self.writer, "{}", text.as_ref())
+            .map_err(|error| CliError::io(format!("Failed to write output: {error}")))
+    }
+
+    /// Writes `value` as JSON or YAML depending on the format; any other
+    /// format falls back to JSON.
+    pub fn structured<T: Serialize>(&mut self, value: &T) -> CliResult {
+        let text = match self.format {
+            Format::Yaml => to_yaml(value)?,
+            _ => to_json(value)?,
+        };
+        self.line(text)
+    }
+
+    /// Writes a single record in the selected format.
+    pub fn record<T: Record>(&mut self, record: &T) -> CliResult {
+        let text = match self.format {
+            Format::Human => record.human(),
+            Format::Json => to_json(record)?,
+            Format::Yaml => to_yaml(record)?,
+            Format::Csv => csv(T::COLUMNS, &[record.row()]),
+            Format::Table => table(T::COLUMNS, &[record.row()]),
+        };
+        self.line(text)
+    }
+
+    /// Writes a list of records; `empty` is the human/table message when
+    /// there are none (JSON/YAML print `[]`, CSV the header only).
+    pub fn records<T: Record>(&mut self, records: &[T], empty: &str) -> CliResult {
+        let text = match self.format {
+            Format::Json if records.is_empty() => "[]".to_string(),
+            Format::Yaml if records.is_empty() => "[]".to_string(),
+            Format::Human | Format::Table if records.is_empty() => empty.to_string(),
+            Format::Human => T::human_list(records),
+            Format::Json => to_json(&records)?,
+            Format::Yaml => to_yaml(&records)?,
+            Format::Csv => csv(
+                T::COLUMNS,
+                &records.iter().map(Record::row).collect::<Vec<_>>(),
+            ),
+            Format::Table => table(
+                T::COLUMNS,
+                &records.iter().map(Record::row).collect::<Vec<_>>(),
+            ),
+        };
+        self.line(text)
+    }
+}
+
+// ============================================================================
+// Serializers
+// ============================================================================
+
+/// Pretty-prints JSON like `JSON.stringify(data, null, 2)`.
+pub fn to_json<T: Serialize + ?Sized>(value: &T) -> CliResult<String> {
+    serde_json::to_string_pretty(value)
+        .map_err(|error| CliError::validation(format!("Failed to format output: {error}")))
+}
+
+/// Renders a value as block-style YAML.
+///
+/// Strings stay plain when unambiguous and are otherwise double-quoted
+/// (JSON escapes are valid YAML), so output round-trips through any parser.
+pub fn to_yaml<T: Serialize + ?Sized>(value: &T) -> CliResult<String> {
+    let value = serde_json::to_value(value)
+        .map_err(|error| CliError::validation(format!("Failed to format output: {error}")))?;
+    let mut out = String::new();
+    write_yaml(&value, 0, &mut out);
+    Ok(out.trim_end().to_string())
+}
+
+fn write_yaml(value: &Value, indent: usize, out: &mut String) {
+    let pad = "  ".repeat(indent);
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, value) in map {
+                out.push_str(&pad);
+                out.push_str(&yaml_string(key));
+                out.push(':');
+                match value {
+                    Value::Object(m) if !m.is_empty() => {
+                        out.push('\n');
+                        write_yaml(value, indent + 1, out);
+                    }
+                    Value::Array(a) if !a.is_empty() => {
+                        out.push('\n');
+                        write_yaml(value, indent + 1, out);
+                    }
+                    _ => {
+                        out.push(' ');
+                        out.push_str(&yaml_scalar(value));
+                        out.push('\n');
+                    }
+                }
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for item in items {
+                out.push_str(&pad);
+                out.push('-');
+                match item {
+                    // `- key: value` with the remaining keys aligned under it
+                    Value::Object(m) if !m.is_empty() => {
+                        let mut nested = String::new();
+                        write_yaml(item, indent + 1, &mut nested);
+                        out.push(' ');
+                        out.push_str(nested.trim_start());
+                    }
+                    Value::Array(a) if !a.is_empty() => {
+                        out.push('\n');
+                        write_yaml(item, indent + 1, out);
+                    }
+                    _ => {
+                        out.push(' ');
+                        out.push_str(&yaml_scalar(item));
+                        out.push('\n');
+                    }
+                }
+            }
+        }
+        _ => {
+            out.push_str(&pad);
+            out.push_str(&yaml_scalar(value));
+            out.push('\n');
+        }
+    }
+}
+
+fn yaml_scalar(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => yaml_string(s),
+        Value::Array(_) => "[]".to_string(),
+        Value::Object(_) => "{}".to_string(),
+    }
+}
+
+fn yaml_string(s: &str) -> String {
+    if is_plain_yaml(s) {
+        s.to_string()
+    } else {
+        Value::String(s.to_string()).to_string()
+    }
+}
+
+/// Whether a string can be written unquoted without changing its type.
+fn is_plain_yaml(s: &str) -> bool {
+    const INDICATORS: &str = "-?:,[]{}#&*!|>'\"%@`";
+    const RESERVED: &[&str] = &["null", "~", "true", "false", "yes", "no", "on", "off"];
+
+    !s.is_empty()
+        && s.trim() == s
+        && !s.starts_with(|c| INDICATORS.contains(c))
+        && !s.ends_with(':')
+        && !s.contains(": ")
+        && !s.contains(" #")
+        && !s.chars().any(char::is_control)
+        && !RESERVED.contains(&s.to_ascii_lowercase().as_str())
+        && s.parse::<f64>().is_err()
+}
+
+/// Renders RFC 4180 CSV: header row plus one line per row.
+pub fn csv(columns: &[&str], rows: &[Vec<String>]) -> String {
+    let header = columns.iter().map(|c| csv_field(c)).collect::<Vec<_>>();
+    std::iter::once(header.join(","))
+        .chain(rows.iter().map(|row| {
+            row.iter()
+                .map(|cell| csv_field(cell))
+                .collect::<Vec<_>>()
+                .join(",")
+        }))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Renders a boxed table; cells are flattened to one line and truncated.
+pub fn table(columns: &[&str], rows: &[Vec<String>]) -> String {
+    let rows: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| row.iter().map(|cell| truncate(cell)).collect())
+        .collect();
+    let widths: Vec<usize> = columns
+        .iter()
+        .enumerate()
+        .map(|(i, column)| {
+            rows.iter()
+                .filter_map(|row| row.get(i))
+                .map(|cell| cell.chars().count())
+                .chain(std::iter::once(column.chars().count()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let border = |left: &str, mid: &str, right: &str| {
+        let segments: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+        format!("{left}{}{right}", segments.join(mid))
+    };
+    let line = |cells: &[String]| {
+        let padded: Vec<String> = widths
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                let cell = cells.get(i).map(String::as_str).unwrap_or("");
+                format!(" {cell}{} ", " ".repeat(w - cell.chars().count()))
+            })
+            .collect();
+        format!("│{}│", padded.join("│"))
+    };
+
+    let header: Vec<String> = columns.iter().map(|c| c.to_string()).collect();
+    let mut lines = vec![border("┌", "┬", "┐"), line(&header), border("├", "┼", "┤")];
+    lines.extend(rows.iter().map(|row| line(row)));
+    lines.push(border("└", "┴", "┘"));
+    lines.join("\n")
+}
+
+fn truncate(value: &str) -> String {
+    let flat = value.replace(['\r', '\n'], " ");
+    if flat.chars().count() <= MAX_COL_WIDTH {
+        return flat;
+    }
+    let kept: String = flat.chars().take(MAX_COL_WIDTH - 3).collect();
+    format!("{kept}...")
+}
+
+// ============================================================================
+// Human Helpers
+// ============================================================================
+
+/// Horizontal rule separating records in human lists.
+pub fn rule() -> String {
+    "─".repeat(RULE_WIDTH)
+}
+
+/// Formats a timestamp as `YYYY-MM-DD HH:MM:SS` (UTC).
+pub fn human_date(date: &chrono::DateTime<chrono::Utc>) -> String {
+    to_iso_string(date).replace('T', " ")[..19].to_string()
+}
+
+/// Formats tags as `#a #b`, or `(none)`.
+pub fn human_tags(tags: &[String]) -> String {
+    if tags.is_empty() {
+        return "(none)".to_string();
+    }
+    tags.iter()
+        .map(|tag| format!("#{tag}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// ============================================================================
+// Record Implementations
+// ============================================================================
+
+impl Record for RequestLogItem {
+    const COLUMNS: &'static [&'static str] = &[
+        "id",
+        "title",
+        "type",
+        "domain",
+        "context",
+        "priority",
+        "status",
+        "tags",
+        "notes",
+        "created_at",
+    ];
+    const NOUN: &'static str = "item(s)";
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.title.clone(),
+            self.item_type.clone(),
+            self.domain.clone(),
+            self.context.clone(),
+            self.priority.clone(),
+            self.status.clone(),
+            self.tags.join(";"),
+            self.notes.clone(),
+            to_iso_string(&self.created_at),
+        ]
+    }
+
+    fn human(&self) -> String {
+        let mut lines = vec![
+            self.title.clone(),
+            format!("ID: {}", self.id),
+            String::new(),
+            format!(
+                "Type: {}  Priority: {}  Status: {}",
+                self.item_type, self.priority, self.status
+            ),
+            format!("Domain: {}", self.domain),
+            format!("Context: {}", self.context),
+            format!("Tags: {}", human_tags(&self.tags)),
+            format!("Created: {}", human_date(&self.created_at)),
+        ];
+        if !self.notes.is_empty() {
+            lines.extend([String::new(), "Notes:".to_string(), self.notes.clone()]);
+        }
+        lines.join("\n")
+    }
+}
+
+impl Record for DocMeta {
+    const COLUMNS: &'static [&'static str] =
+        &["path", "doc_id", "title", "item_count", "updated_at"];
+    const NOUN: &'static str = "document(s)";
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.path.clone(),
+            self.doc_id.clone(),
+            self.title.clone(),
+            self.item_count.to_string(),
+            to_iso_string(&self.updated_at),
+        ]
+    }
+
+    fn human(&self) -> String {
+        [
+            self.title.clone(),
+            format!("ID: {}", self.doc_id),
+            format!("Path: {}", self.path),
+            format!("Items: {}", self.item_count),
+            format!("Updated: {}", human_date(&self.updated_at)),
+        ]
+        .join("\n")
+    }
+}
+
+impl Record for RequestLogDoc {
+    const COLUMNS: &'static [&'static str] = &[
+        "doc_id",
+        "title",
+        "project_id",
+        "item_count",
+        "tags",
+        "created_at",
+        "updated_at",
+    ];
+    const NOUN: &'static str = "document(s)";
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.doc_id.clone(),
+            self.title.clone(),
+            self.project_id.clone(),
+            self.item_count.to_string(),
+            self.tags.join(";"),
+            to_iso_string(&self.created_at),
+            to_iso_string(&self.updated_at),
+        ]
+    }
+
+    fn human(&self) -> String {
+        let mut lines = vec![
+            self.title.clone(),
+            format!("ID: {}", self.doc_id),
+            String::new(),
+            format!("Project: {}", self.project_id),
+            format!("Items: {}", self.item_count),
+            format!("Tags: {}", human_tags(&self.tags)),
+            format!("Created: {}", human_date(&self.created_at)),
+            format!("Updated: {}", human_date(&self.updated_at)),
+        ];
+        if !self.items.is_empty() {
+            lines.extend([String::new(), "Items:".to_string()]);
+            for item in &self.items {
+                lines.extend([String::new(), "─".repeat(50), item.human()]);
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+impl Record for Project {
+    const COLUMNS: &'static [&'static str] = &[
+        "id",
+        "name",
+        "default_path",
+        "repo_url",
+        "enabled",
+        "created_at",
+        "updated_at",
+    ];
+    const NOUN: &'static str = "project(s)";
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.name.clone(),
+            self.default_path.clone(),
+            self.repo_url.clone().unwrap_or_default(),
+            self.enabled.to_string(),
+            to_iso_string(&self.created_at),
+            to_iso_string(&self.updated_at),
+        ]
+    }
+
+    fn human(&self) -> String {
+        let mut lines = vec![
+            self.name.clone(),
+            format!("ID: {}", self.id),
+            String::new(),
+            format!("Path: {}", self.default_path),
+        ];
+        if let Some(repo_url) = &self.repo_url {
+            lines.push(format!("Repo: {repo_url}"));
+        }
+        if self.auto_commit == Some(true) {
+            lines.push("Git: auto-commit".to_string());
+        }
+        lines.extend([
+            format!(
+                "Status: {}",
+                if self.enabled { "enabled" } else { "disabled" }
+            ),
+            format!("Created: {}", human_date(&self.created_at)),
+            format!("Updated: {}", human_date(&self.updated_at)),
+        ]);
+        lines.join("\n")
+    }
+
+    fn human_list(projects: &[Self]) -> String {
+        let enabled = projects.iter().filter(|p| p.enabled).count();
+        let mut lines = vec![
+            format!("Found {} project(s) ({enabled} enabled):", projects.len()),
+            String::new(),
+        ];
+        for project in projects {
+            lines.extend([rule(), project.human(), String::new()]);
+        }
+        lines.join("\n")
+    }
+}
+
+impl Record for FieldOption {
+    const COLUMNS: &'static [&'static str] =
+        &["field", "value", "scope", "project_id", "id", "created_at"];
+    const NOUN: &'static str = "option(s)";
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.field.as_str().to_string(),
+            self.value.clone(),
+            match self.scope {
+                FieldScope::Global => "global",
+                FieldScope::Project => "project",
+            }
+            .to_string(),
+            self.project_id.clone().unwrap_or_default(),
+            self.id.clone(),
+            to_iso_string(&self.created_at),
+        ]
+    }
+
+    fn human(&self) -> String {
+        let scope = match (&self.scope, &self.project_id) {
+            (FieldScope::Project, Some(project_id)) => format!("[project: {project_id}]"),
+            (FieldScope::Project, None) => "[project: unknown]".to_string(),
+            (FieldScope::Global, _) => "[global]".to_string(),
+        };
+        format!("{} ({}) {scope}", self.value, self.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn meta(title: &str) -> DocMeta {
+        DocMeta {
+            path: "/docs/a.md".to_string(),
+            doc_id: "REQ-20251203-app".to_string(),
+            title: title.to_string(),
+            item_count: 2,
+            updated_at: Utc.with_ymd_and_hms(2025, 12, 3, 10, 0, 0).unwrap(),
+        }
+    }
+
+    fn render(format: Format, metas: &[DocMeta]) -> String {
+        let mut buffer = Vec::new();
+        Output::new(format, false, &mut buffer)
+            .records(metas, "No documents found")
+            .unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn csv_quotes_special_characters() {
+        let out = render(Format::Csv, &[meta("Log, \"v2\"")]);
+        assert_eq!(
+            out,
+            "path,doc_id,title,item_count,updated_at\n\
+             /docs/a.md,REQ-20251203-app,\"Log, \"\"v2\"\"\",2,2025-12-03T10:00:00.000Z\n"
+        );
+    }
+
+    #[test]
+    fn empty_lists_follow_format_conventions() {
+        assert_eq!(render(Format::Json, &[]), "[]\n");
+        assert_eq!(render(Format::Yaml, &[]), "[]\n");
+        assert_eq!(
+            render(Format::Csv, &[]),
+            "path,doc_id,title,item_count,updated_at\n"
+        );
+        assert_eq!(render(Format::Table, &[]), "No documents found\n");
+    }
+
+    #[test]
+    fn yaml_quotes_ambiguous_strings() {
+        let value = serde_json::json!({
+            "title": "Dark mode",
+            "tags": ["ux", "true", "42"],
+            "notes": "Line one\nkey: value",
+            "items": [{ "id": "REQ-1", "type": "bug" }],
+            "empty": [],
+        });
+
+        assert_eq!(
+            to_yaml(&value).unwrap(),
+            "empty: []\n\
+             items:\n  - id: REQ-1\n    type: bug\n\
+             notes: \"Line one\\nkey: value\"\n\
+             tags:\n  - ux\n  - \"true\"\n  - \"42\"\n\
+             title: Dark mode"
+        );
+    }
+
+    #[test]
+    fn quiet_suppresses_output() {
+        let mut buffer = Vec::new();
+        Output::new(Format::Human, true, &mut buffer)
+            .line("hidden")
+            .unwrap();
+        assert!(buffer.is_empty());
+    }
+}
@@ -0,0 +1,338 @@
+//! Project Commands
+//!
+//! Project registry commands (`meatycapture project ...`):
+//! - add: Register a project directory
+//! - list: Registered projects, sorted and filtered
+//! - update: Change name, path or repository URL
+//! - enable / disable: Toggle availability
+//! - set-default: Store `default_project` in `config.json`
+
+use crate::cli::exit::{CliError, CliResult};
+use crate::cli::output::{Format, Output};
+use crate::cli::{is_writable_dir, Context};
+use crate::doc_store::expand_path;
+use crate::ids::slugify;
+use crate::models::{AppConfigUpdate, NewProject, Project, ProjectUpdate};
+use crate::ports::{ConfigStore, ProjectStore};
+
+/// Suggestion attached to "project not found" errors.
+const LIST_HINT: &str = "Run 'meatycapture project list' to see available projects";
+
+/// Looks up a project, failing with a resource error when missing.
+pub fn require_project(ctx: &Context, id: &str) -> CliResult<Project> {
+    ctx.projects.get(id)?.ok_or_else(|| {
+        CliError::resource(format!("Project not found: {id}")).with_suggestion(LIST_HINT)
+    })
+}
+
+/// Checks that `path` is an existing, writable directory.
+fn validate_dir(path: &str) -> CliResult {
+    let dir = expand_path(path);
+    if !dir.exists() {
+        return Err(CliError::io(format!("Path does not exist: {path}"))
+            .with_suggestion("Create the directory first or choose an existing path"));
+    }
+    if !dir.is_dir() {
+        return Err(
+            CliError::validation(format!("Path is not a directory: {path}"))
+                .with_suggestion("Provide a directory path, not a file"),
+        );
+    }
+    if !is_writable_dir(&dir) {
+        return Err(
+            CliError::io(format!("Permission denied: cannot write {path}"))
+                .with_suggestion("Ensure the directory exists and you have write permissions"),
+        );
+    }
+    Ok(())
+}
+
+/// Options for `project add`.
+#[derive(Debug, Clone, Default)]
+pub struct AddOptions {
+    pub name: String,
+    /// Directory for the project's documents
+    pub path: String,
+    /// Explicit kebab-case ID (default: slug of `name`)
+    pub id: Option<String>,
+    pub repo_url: Option<String>,
+    /// Commit document writes to the enclosing git repository
+    pub auto_commit: bool,
+}
+
+/// Registers a new project.
+pub fn add(ctx: &Context, out: &mut Output, opts: AddOptions) -> CliResult {
+    if opts.name.trim().is_empty() {
+        return Err(CliError::validation("Project name is required")
+            .with_suggestion("Provide a non-empty project name"));
+    }
+    if opts.path.trim().is_empty() {
+        return Err(CliError::validation("Default path is required")
+            .with_suggestion("Provide a valid directory path for project documents"));
+    }
+
+    // The store validates an explicit ID's format on create
+    let id = opts.id.clone().unwrap_or_else(|| slugify(&opts.name));
+    validate_dir(&opts.path)?;
+
+    if ctx.projects.get(&id)?.is_some() {
+        let suggestion = match opts.id {
+            Some(_) => "Choose a different ID or remove the existing project".to_string(),
+            None => format!(
+                "Project ID \"{id}\" is auto-generated from the name. Use --id to specify a custom ID"
+            ),
+        };
+        return Err(
+            CliError::resource(format!("Project already exists: {id}")).with_suggestion(suggestion)
+        );
+    }
+
+    let project = ctx.projects.create(NewProject {
+        id: opts.id,
+        name: opts.name,
+        default_path: opts.path,
+        repo_url: opts.repo_url.filter(|url| !url.is_empty()),
+        enabled: true,
+        auto_commit: opts.auto_commit.then_some(true),
+    })?;
+
+    out.records(std::slice::from_ref(&project), "")?;
+    if out.format() == Format::Human {
+        out.line(format!(
+            "\nProject \"{}\" created successfully with ID: {}",
+            project.name, project.id
+        ))?;
+        out.line(format!(
+            "Documents will be stored in: {}",
+            project.default_path
+        ))?;
+        if let Some(repo_url) = &project.repo_url {
+            out.line(format!("Repository: {repo_url}"))?;
+        }
+    }
+    Ok(())
+}
+
+/// Sort order for `project list` (always ascending).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProjectSort {
+    Id,
+    #[default]
+    Name,
+    Created,
+}
+
+impl ProjectSort {
+    /// Parses `id`, `name` or `created`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "id" => Some(ProjectSort::Id),
+            "name" => Some(ProjectSort::Name),
+            "created" => Some(ProjectSort::Created),
+            _ => None,
+        }
+    }
+}
+
+/// Options for `project list`.
+#[derive(Debug, Clone, Default)]
+pub struct ListOptions {
+    pub sort: ProjectSort,
+    pub enabled_only: bool,
+    pub disabled_only: bool,
+}
+
+/// Lists registered projects.
+pub fn list(ctx: &Context, out: &mut Output, opts: ListOptions) -> CliResult {
+    let mut projects: Vec<Project> = ctx
+        .projects
+        .list()?
+        .into_iter()
+        .filter(|p| !opts.enabled_only || p.enabled)
+        .filter(|p| !opts.disabled_only || !p.enabled)
+        .collect();
+
+    match opts.sort {
+        ProjectSort::Id => projects.sort_by(|a, b| a.id.cmp(&b.id)),
+        ProjectSort::Name => projects.sort_by(|a, b| a.name.cmp(&b.name)),
+        ProjectSort::Created => projects.sort_by_key(|p| p.created_at),
+    }
+
+    let filter = if opts.enabled_only {
+        " (enabled)"
+    } else if opts.disabled_only {
+        " (disabled)"
+    } else {
+        ""
+    };
+    out.records(
+        &projects,
+        &format!(
+            "No projects found{filter}.\nRun 'meatycapture project add <name>' to create one."
+        ),
+    )
+}
+
+/// Options for `project update`.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateOptions {
+    pub id: String,
+    pub name: Option<String>,
+    pub path: Option<String>,
+    pub repo_url: Option<String>,
+    pub auto_commit: Option<bool>,
+}
+
+/// Updates a project's name, path, repository URL or auto-commit setting.
+pub fn update(ctx: &Context, out: &mut Output, opts: UpdateOptions) -> CliResult {
+    if opts.name.is_none()
+        && opts.path.is_none()
+        && opts.repo_url.is_none()
+        && opts.auto_commit.is_none()
+    {
+        return Err(
+            CliError::validation("At least one field must be specified for update")
+                .with_suggestion(
+                    "Use --name, --path, --repo-url or --auto-commit to specify updates",
+                ),
+        );
+    }
+    if let Some(path) = &opts.path {
+        if !is_writable_dir(&expand_path(path)) {
+            return Err(
+                CliError::validation(format!("Path is not writable: {path}"))
+                    .with_suggestion("Ensure the directory exists and you have write permissions"),
+            );
+        }
+    }
+    require_project(ctx, &opts.id)?;
+
+    let project = ctx.projects.update(
+        &opts.id,
+        ProjectUpdate {
+            name: opts.name,
+            default_path: opts.path,
+            repo_url: opts.repo_url,
+            enabled: None,
+            auto_commit: opts.auto_commit,
+        },
+    )?;
+
+    if out.format() == Format::Human {
+        out.line(format!("Project '{}' updated successfully.\n", opts.id))?;
+    }
+    out.record(&project)
+}
+
+/// Enables (`enabled = true`) or disables a project.
+pub fn set_enabled(ctx: &Context, out: &mut Output, id: &str, enabled: bool) -> CliResult {
+    let was_enabled = require_project(ctx, id)?.enabled;
+    let project = ctx.projects.update(
+        id,
+        ProjectUpdate {
+            enabled: Some(enabled),
+            ..ProjectUpdate::default()
+        },
+    )?;
+
+    match out.format() {
+        Format::Json | Format::Yaml => out.structured(&project),
+        _ => {
+            let state = match (enabled, was_enabled == enabled) {
+                (true, false) => "enabled",
+                (true, true) => "is already enabled",
+                (false, false) => "disabled",
+                (false, true) => "is already disabled",
+            };
+            out.line(format!(
+                "✓ Project \"{}\" ({}) {state}",
+                project.name, project.id
+            ))
+        }
+    }
+}
+
+/// Makes `id` the default project for new documents.
+pub fn set_default(ctx: &Context, out: &mut Output, id: &str) -> CliResult {
+    let project = require_project(ctx, id)?;
+    let config = ctx.config.update(AppConfigUpdate {
+        default_project: Some(project.id.clone()),
+        ..AppConfigUpdate::default()
+    })?;
+
+    match out.format() {
+        Format::Json | Format::Yaml => out.structured(&config),
+        _ => out.line(format!(
+            "✓ Default project set to \"{}\" ({})",
+            project.name, project.id
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::exit::{IO_ERROR, RESOURCE_ERROR, VALIDATION_ERROR};
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn add_opts(name: &str, path: &Path) -> AddOptions {
+        AddOptions {
+            name: name.to_string(),
+            path: path.display().to_string(),
+            ..AddOptions::default()
+        }
+    }
+
+    #[test]
+    fn add_rejects_duplicates_and_bad_ids() {
+        let temp = TempDir::new().unwrap();
+        let ctx = Context::new(Some(temp.path().to_path_buf()));
+        let mut buffer = Vec::new();
+        let mut out = Output::new(Format::Human, false, &mut buffer);
+
+        add(&ctx, &mut out, add_opts("My App", temp.path())).unwrap();
+        let duplicate = add(&ctx, &mut out, add_opts("My App", temp.path())).unwrap_err();
+        assert_eq!(duplicate.code, RESOURCE_ERROR);
+
+        let bad_id = AddOptions {
+            id: Some("My_App".to_string()),
+            ..add_opts("Other", temp.path())
+        };
+        assert_eq!(
+            add(&ctx, &mut out, bad_id).unwrap_err().code,
+            VALIDATION_ERROR
+        );
+
+        let missing = add_opts("Gone", &temp.path().join("missing"));
+        assert_eq!(add(&ctx, &mut out, missing).unwrap_err().code, IO_ERROR);
+
+        let text = String::from_utf8(buffer).unwrap();
+        assert!(text.contains("Project \"My App\" created successfully with ID: my-app"));
+    }
+
+    #[test]
+    fn enable_disable_and_set_default() {
+        let temp = TempDir::new().unwrap();
+        let ctx = Context::new(Some(temp.path().to_path_buf()));
+        let mut buffer = Vec::new();
+        let mut out = Output::new(Format::Human, false, &mut buffer);
+
+        add(&ctx, &mut out, add_opts("App", temp.path())).unwrap();
+        set_enabled(&ctx, &mut out, "app", false).unwrap();
+        set_enabled(&ctx, &mut out, "app", false).unwrap();
+        set_default(&ctx, &mut out, "app").unwrap();
+        assert_eq!(
+            set_enabled(&ctx, &mut out, "nope", true).unwrap_err().code,
+            RESOURCE_ERROR
+        );
+
+        let text = String::from_utf8(buffer).unwrap();
+        assert!(text.contains("✓ Project \"App\" (app) disabled\n"));
+        assert!(text.contains("✓ Project \"App\" (app) is already disabled\n"));
+        assert_eq!(
+            ctx.config.get().unwrap().default_project.as_deref(),
+            Some("app")
+        );
+    }
+}
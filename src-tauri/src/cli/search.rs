@@ -0,0 +1,414 @@
+//! Item Search
+//!
+//! Linear search over parsed documents for `log search`, a port of
+//! `src/cli/handlers/search.ts`:
+//! - Free text matches title, then notes (30-char context around the hit)
+//! - `tag:`/`tags:`, `type:` and `status:` prefixes match those fields
+//! - Quoted phrases are single terms; all terms must match (AND)
+//!
+//! The desktop app's tantivy index is not used: only one process may hold
+//! its writer, and the CLI must work without the app running.
+
+use serde::Serialize;
+
+use crate::cli::output::{human_tags, rule, Record};
+use crate::models::{RequestLogDoc, RequestLogItem};
+
+/// Characters of context kept on each side of a text match.
+const CONTEXT_CHARS: usize = 30;
+
+/// How query values are compared with field values (case-insensitive).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MatchMode {
+    /// Whole value must equal the query
+    Full,
+    /// Value must start with the query
+    Starts,
+    /// Value must contain the query
+    #[default]
+    Contains,
+}
+
+impl MatchMode {
+    /// Parses `full`, `starts` or `contains`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "full" => Some(MatchMode::Full),
+            "starts" => Some(MatchMode::Starts),
+            "contains" => Some(MatchMode::Contains),
+            _ => None,
+        }
+    }
+}
+
+/// One parsed query term.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryTerm {
+    Text(String),
+    Tag(String),
+    ItemType(String),
+    Status(String),
+}
+
+/// Where an item matched a query term.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MatchedField {
+    /// Field name (`title`, `notes`, `tags`, `type`, `status`)
+    pub field: String,
+    /// Match start, in JavaScript string indices (text matches only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<usize>,
+    /// Match end, in JavaScript string indices (text matches only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<usize>,
+    /// Matched value, or the text around a text match
+    pub match_text: String,
+}
+
+/// An item matching every query term.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SearchMatch {
+    pub item: RequestLogItem,
+    pub doc_id: String,
+    pub doc_path: String,
+    pub matched_fields: Vec<MatchedField>,
+}
+
+/// Splits a query into terms, honoring quotes and field prefixes.
+pub fn parse_query(query: &str) -> Vec<QueryTerm> {
+    tokenize(query.trim())
+        .into_iter()
+        .filter_map(|token| {
+            let lower = token.to_lowercase();
+            let value = |prefix: &str| token[prefix.len()..].to_string();
+            let term = if lower.starts_with("tags:") {
+                QueryTerm::Tag(value("tags:"))
+            } else if lower.starts_with("tag:") {
+                QueryTerm::Tag(value("tag:"))
+            } else if lower.starts_with("type:") {
+                QueryTerm::ItemType(value("type:"))
+            } else if lower.starts_with("status:") {
+                QueryTerm::Status(value("status:"))
+            } else {
+                return Some(QueryTerm::Text(token));
+            };
+            // Bare prefixes (`tag:`) are ignored
+            match &term {
+                QueryTerm::Tag(v) | QueryTerm::ItemType(v) | QueryTerm::Status(v)
+                    if v.is_empty() =>
+                {
+                    None
+                }
+                _ => Some(term),
+            }
+        })
+        .collect()
+}
+
+fn tokenize(query: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+
+    for c in query.chars() {
+        match quote {
+            Some(q) if c == q => {
+                tokens.extend((!current.is_empty()).then(|| std::mem::take(&mut current)));
+                quote = None;
+            }
+            Some(_) => current.push(c),
+            None if c == '"' || c == '\'' => {
+                tokens.extend((!current.is_empty()).then(|| std::mem::take(&mut current)));
+                quote = Some(c);
+            }
+            None if c == ' ' || c == '\t' => {
+                tokens.extend((!current.is_empty()).then(|| std::mem::take(&mut current)));
+            }
+            None => current.push(c),
+        }
+    }
+    tokens.extend((!current.is_empty()).then_some(current));
+    tokens
+}
+
+/// Searches `docs` (path, document) in order; `limit` 0 means unlimited.
+pub fn search_documents(
+    docs: &[(String, RequestLogDoc)],
+    query: &str,
+    mode: MatchMode,
+    limit: usize,
+) -> Vec<SearchMatch> {
+    let terms = parse_query(query);
+    if terms.is_empty() {
+        return Vec::new();
+    }
+
+    let matches = docs.iter().flat_map(|(path, doc)| {
+        doc.items.iter().filter_map(|item| {
+            let matched_fields = terms
+                .iter()
+                .map(|term| match_term(item, term, mode))
+                .collect::<Option<Vec<_>>>()?;
+            Some(SearchMatch {
+                item: item.clone(),
+                doc_id: doc.doc_id.clone(),
+                doc_path: path.clone(),
+                matched_fields,
+            })
+        })
+    });
+
+    match limit {
+        0 => matches.collect(),
+        limit => matches.take(limit).collect(),
+    }
+}
+
+fn match_term(item: &RequestLogItem, term: &QueryTerm, mode: MatchMode) -> Option<MatchedField> {
+    let whole = |field: &str, value: &str| MatchedField {
+        field: field.to_string(),
+        start: None,
+        end: None,
+        match_text: value.to_string(),
+    };
+
+    match term {
+        QueryTerm::Tag(query) => item
+            .tags
+            .iter()
+            .find(|tag| matches(tag, query, mode))
+            .map(|tag| whole("tags", tag)),
+        QueryTerm::ItemType(query) => {
+            matches(&item.item_type, query, mode).then(|| whole("type", &item.item_type))
+        }
+        QueryTerm::Status(query) => {
+            matches(&item.status, query, mode).then(|| whole("status", &item.status))
+        }
+        QueryTerm::Text(query) => [("title", &item.title), ("notes", &item.notes)]
+            .into_iter()
+            .find(|(_, text)| matches(text, query, mode))
+            .map(|(field, text)| match find(text, query) {
+                Some((start, end)) => MatchedField {
+                    field: field.to_string(),
+                    start: Some(text[..start].encode_utf16().count()),
+                    end: Some(text[..end].encode_utf16().count()),
+                    match_text: context(text, start, end),
+                },
+                None => whole(field, text),
+            }),
+    }
+}
+
+fn matches(haystack: &str, needle: &str, mode: MatchMode) -> bool {
+    let haystack = haystack.to_lowercase();
+    let needle = needle.to_lowercase();
+    match mode {
+        MatchMode::Full => haystack == needle,
+        MatchMode::Starts => haystack.starts_with(&needle),
+        MatchMode::Contains => haystack.contains(&needle),
+    }
+}
+
+/// Byte range of the first case-insensitive occurrence of `needle`.
+fn find(haystack: &str, needle: &str) -> Option<(usize, usize)> {
+    let needle = needle.to_lowercase();
+    let len = needle.chars().count();
+    haystack.char_indices().find_map(|(start, _)| {
+        let rest = &haystack[start..];
+        let candidate: String = rest.chars().take(len).collect();
+        (candidate.to_lowercase() == needle).then(|| (start, start + candidate.len()))
+    })
+}
+
+/// The match plus up to 30 characters each side, with `...` where cut.
+fn context(text: &str, start: usize, end: usize) -> String {
+    let before: Vec<char> = text[..start].chars().collect();
+    let after: Vec<char> = text[end..].chars().collect();
+    let lead = before.len().saturating_sub(CONTEXT_CHARS);
+    let tail = after.len().min(CONTEXT_CHARS);
+
+    format!(
+        "{}{}{}{}{}",
+        if lead > 0 { "..." } else { "" },
+        before[lead..].iter().collect::<String>(),
+        &text[start..end],
+        after[..tail].iter().collect::<String>(),
+        if tail < after.len() { "..." } else { "" },
+    )
+}
+
+impl Record for SearchMatch {
+    const COLUMNS: &'static [&'static str] = &[
+        "doc_id",
+        "doc_path",
+        "item_id",
+        "item_title",
+        "item_type",
+        "matched_fields",
+        "match_text",
+    ];
+    const NOUN: &'static str = "match(es)";
+
+    fn row(&self) -> Vec<String> {
+        let join = |f: fn(&MatchedField) -> &str| {
+            self.matched_fields
+                .iter()
+                .map(f)
+                .collect::<Vec<_>>()
+                .join(";")
+        };
+        vec![
+            self.doc_id.clone(),
+            self.doc_path.clone(),
+            self.item.id.clone(),
+            self.item.title.clone(),
+            self.item.item_type.clone(),
+            join(|m| &m.field),
+            join(|m| &m.match_text),
+        ]
+    }
+
+    fn human(&self) -> String {
+        let fields: Vec<&str> = self
+            .matched_fields
+            .iter()
+            .map(|m| m.field.as_str())
+            .collect();
+        let mut lines = vec![
+            self.item.title.clone(),
+            format!("ID: {}", self.item.id),
+            format!("Doc: {} ({})", self.doc_id, self.doc_path),
+            String::new(),
+            format!("Matched in: {}", fields.join(", ")),
+        ];
+        lines.extend(
+            self.matched_fields
+                .iter()
+                .map(|m| format!("  {}: {}", m.field, m.match_text)),
+        );
+        lines.extend([
+            String::new(),
+            format!(
+                "Type: {}  Priority: {}  Status: {}",
+                self.item.item_type, self.item.priority, self.item.status
+            ),
+            format!("Tags: {}", human_tags(&self.item.tags)),
+        ]);
+        lines.join("\n")
+    }
+
+    fn human_list(matches: &[Self]) -> String {
+        let mut lines = vec![format!("Found {} match(es):", matches.len()), String::new()];
+        for found in matches {
+            lines.extend([rule(), found.human(), String::new()]);
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    use crate::models::SCHEMA_VERSION;
+
+    fn item(id: &str, title: &str, status: &str, tags: &[&str], notes: &str) -> RequestLogItem {
+        RequestLogItem {
+            id: id.to_string(),
+            title: title.to_string(),
+            item_type: "bug".to_string(),
+            domain: "web".to_string(),
+            context: String::new(),
+            priority: "high".to_string(),
+            status: status.to_string(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            notes: notes.to_string(),
+            extra: String::new(),
+            trailing: String::new(),
+            created_at: Utc.with_ymd_and_hms(2025, 12, 3, 10, 0, 0).unwrap(),
+        }
+    }
+
+    fn docs() -> Vec<(String, RequestLogDoc)> {
+        let items = vec![
+            item(
+                "REQ-20251203-app-01",
+                "Dark mode toggle",
+                "triage",
+                &["ux"],
+                "",
+            ),
+            item(
+                "REQ-20251203-app-02",
+                "Export",
+                "done",
+                &["api"],
+                "Users want the export to include a dark mode palette for printing reports",
+            ),
+        ];
+        let created = items[0].created_at;
+        let doc = RequestLogDoc {
+            schema_version: SCHEMA_VERSION,
+            doc_id: "REQ-20251203-app".to_string(),
+            title: "App".to_string(),
+            project_id: "app".to_string(),
+            items,
+            items_index: vec![],
+            tags: vec![],
+            item_count: 2,
+            created_at: created,
+            updated_at: created,
+            extra_frontmatter: vec![],
+            preamble: String::new(),
+        };
+        vec![("/docs/app.md".to_string(), doc)]
+    }
+
+    #[test]
+    fn parses_quotes_and_prefixes() {
+        assert_eq!(
+            parse_query(r#"tag:ux "dark mode" Status:done tag:"#),
+            vec![
+                QueryTerm::Tag("ux".to_string()),
+                QueryTerm::Text("dark mode".to_string()),
+                QueryTerm::Status("done".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn matches_title_before_notes_with_context() {
+        let found = search_documents(&docs(), "\"dark mode\"", MatchMode::Contains, 0);
+
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].matched_fields[0].field, "title");
+        assert_eq!(found[0].matched_fields[0].start, Some(0));
+        let notes = &found[1].matched_fields[0];
+        assert_eq!(notes.field, "notes");
+        assert_eq!(
+            notes.match_text,
+            "... want the export to include a dark mode palette for printing reports"
+        );
+    }
+
+    #[test]
+    fn all_terms_must_match() {
+        let docs = docs();
+        assert_eq!(
+            search_documents(&docs, "dark status:done", MatchMode::Contains, 0).len(),
+            1
+        );
+        assert!(search_documents(&docs, "dark tag:missing", MatchMode::Contains, 0).is_empty());
+        assert_eq!(
+            search_documents(&docs, "export", MatchMode::Full, 0)[0]
+                .item
+                .id,
+            "REQ-20251203-app-02"
+        );
+        assert_eq!(
+            search_documents(&docs, "dark", MatchMode::Contains, 1).len(),
+            1
+        );
+    }
+}
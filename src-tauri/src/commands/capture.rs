@@ -0,0 +1,72 @@
+//! Capture Commands
+//!
+//! Handlers for the quick-capture window (`capture_*`).
+
+use tauri::{AppHandle, State};
+
+use crate::capture::{self, CaptureTarget};
+use crate::config_store::{LocalConfigStore, LocalProjectStore};
+use crate::doc_store::{capture_to_daily_doc, FsDocStore};
+use crate::error::{Error, Result};
+use crate::models::{AppConfigUpdate, ItemDraft};
+use crate::path_guard::Access;
+use crate::ports::{ConfigStore, DocStore, ProjectStore, SystemClock};
+use crate::scope::ProjectScopes;
+
+/// Opens (or focuses) the quick-capture window.
+#[tauri::command]
+pub async fn capture_show(app: AppHandle) -> Result<()> {
+    capture::show(&app);
+    Ok(())
+}
+
+/// Project, document and title to preselect when the capture webview mounts.
+#[tauri::command]
+pub async fn capture_target(app: AppHandle) -> Result<CaptureTarget> {
+    Ok(capture::initial_target(&app))
+}
+
+/// Hides the quick-capture window (submit / Escape).
+#[tauri::command]
+pub async fn capture_hide(app: AppHandle) -> Result<()> {
+    capture::hide(&app);
+    Ok(())
+}
+
+/// Saves an item from the quick-capture window and returns the document
+/// path, which is remembered as `last_document`.
+///
+/// With `doc_path` the item is appended to that document; without it, to
+/// the project's document for today, created under the document lock on
+/// first capture.
+#[tauri::command]
+pub async fn capture_submit(
+    projects: State<'_, LocalProjectStore>,
+    docs: State<'_, FsDocStore>,
+    config: State<'_, LocalConfigStore>,
+    scopes: State<'_, ProjectScopes>,
+    project_id: String,
+    doc_path: Option<String>,
+    item: ItemDraft,
+) -> Result<String> {
+    let path = match doc_path {
+        Some(path) => {
+            let path = scopes.check_str(&path, Access::Write)?;
+            docs.append(&path, item, &SystemClock)?;
+            path
+        }
+        None => {
+            let project = projects
+                .get(&project_id)?
+                .filter(|project| project.enabled)
+                .ok_or_else(|| Error::NotFound(format!("Project not found: {project_id}")))?;
+            capture_to_daily_doc(&docs, &project, item, &SystemClock)?.path
+        }
+    };
+
+    config.update(AppConfigUpdate {
+        last_document: Some(path.clone()),
+        ..Default::default()
+    })?;
+    Ok(path)
+}
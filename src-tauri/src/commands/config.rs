@@ -0,0 +1,104 @@
+//! Config Commands
+//!
+//! `config_*` handlers backed by the native ConfigStore (`config.json`).
+//!
+//! Every window can read the config, so the local API bearer token is left
+//! out of it; only admin windows see it, through `local_api_token_*`.
+
+use serde::Serialize;
+use tauri::{AppHandle, State};
+
+use crate::backups::Retention;
+use crate::config_store::{generate_local_api_token, LocalConfigStore};
+use crate::doc_store::FsDocStore;
+use crate::error::Result;
+use crate::models::{AppConfig, AppConfigUpdate};
+use crate::ports::ConfigStore;
+
+/// `AppConfig` as sent to the webview: `local_api_token` is replaced by
+/// `has_local_api_token`.
+#[derive(Debug, Serialize)]
+pub struct ConfigView {
+    #[serde(flatten)]
+    config: AppConfig,
+    has_local_api_token: bool,
+}
+
+impl From<AppConfig> for ConfigView {
+    fn from(mut config: AppConfig) -> Self {
+        let has_local_api_token = config.local_api_token.take().is_some();
+        ConfigView {
+            config,
+            has_local_api_token,
+        }
+    }
+}
+
+/// Gets the application configuration (defaults if no file exists yet),
+/// without the local API token.
+#[tauri::command]
+pub async fn config_get(store: State<'_, LocalConfigStore>) -> Result<ConfigView> {
+    store.get().map(ConfigView::from)
+}
+
+/// Merges partial updates into the application configuration.
+///
+/// A new `capture_shortcut` is registered before it is saved, so a
+/// combination that is invalid or already taken is rejected and the
+/// previous binding stays active. Backup retention changes apply to the
+/// next snapshot.
+#[tauri::command]
+pub async fn config_update(
+    app: AppHandle,
+    store: State<'_, LocalConfigStore>,
+    docs: State<'_, FsDocStore>,
+    updates: AppConfigUpdate,
+) -> Result<ConfigView> {
+    let retention_changed = updates.backup_keep.is_some() || updates.backup_max_age_days.is_some();
+    let config = update(&app, &store, updates)?;
+    if retention_changed {
+        docs.set_backup_retention(Retention::from_config(&config));
+    }
+    Ok(config.into())
+}
+
+/// Shows the local API bearer token, or `null` if none is set.
+#[tauri::command]
+pub async fn local_api_token_get(store: State<'_, LocalConfigStore>) -> Result<Option<String>> {
+    Ok(store.get()?.local_api_token)
+}
+
+/// Replaces the local API bearer token with a new random one and returns it.
+///
+/// The server reads its token at launch, so the new one applies after a
+/// restart (and `MEATYCAPTURE_AUTH_TOKEN` still overrides it).
+#[tauri::command]
+pub async fn local_api_token_regenerate(store: State<'_, LocalConfigStore>) -> Result<String> {
+    let token = generate_local_api_token()?;
+    store.update(AppConfigUpdate {
+        local_api_token: Some(token.clone()),
+        ..Default::default()
+    })?;
+    Ok(token)
+}
+
+fn update(
+    app: &AppHandle,
+    store: &LocalConfigStore,
+    updates: AppConfigUpdate,
+) -> Result<AppConfig> {
+    #[cfg(not(any(target_os = "android", target_os = "ios")))]
+    if let Some(binding) = &updates.capture_shortcut {
+        let current = store.get()?.capture_shortcut().to_string();
+        crate::shortcut::rebind(app, &current, binding)?;
+        return store.update(updates.clone()).inspect_err(|_| {
+            // Keep the active binding in sync with what is on disk
+            let _ = crate::shortcut::rebind(app, binding, &current);
+        });
+    }
+
+    #[cfg(any(target_os = "android", target_os = "ios"))]
+    let _ = app;
+
+    store.update(updates)
+}
@@ -0,0 +1,135 @@
+//! Document Commands
+//!
+//! `doc_*` handlers implementing the DocStore port natively, so the webview
+//! needs no filesystem access of its own. Every path goes
+//! through `path_guard` first: traversal, symlink escapes and non-`.md`
+//! write targets fail with `Error::InvalidPath`, and paths outside the data
+//! directory and enabled projects with `Error::OutOfScope`. The store is
+//! then handed the resolved path, never the one the webview sent.
+
+use tauri::State;
+
+use crate::backups::BackupEntry;
+use crate::doc_store::FsDocStore;
+use crate::error::Result;
+use crate::migrate::{self, MigrationReport};
+use crate::models::{DocMeta, ItemDraft, RequestLogDoc};
+use crate::path_guard::Access;
+use crate::ports::{DocStore, SystemClock};
+use crate::scope::ProjectScopes;
+
+/// Lists request-log documents in a directory (sorted by updated_at desc).
+#[tauri::command]
+pub async fn doc_list(
+    store: State<'_, FsDocStore>,
+    scopes: State<'_, ProjectScopes>,
+    directory: String,
+) -> Result<Vec<DocMeta>> {
+    let directory = scopes.check_str(&directory, Access::Directory)?;
+    store.list(&directory)
+}
+
+/// Reads and parses a request-log document.
+#[tauri::command]
+pub async fn doc_read(
+    store: State<'_, FsDocStore>,
+    scopes: State<'_, ProjectScopes>,
+    path: String,
+) -> Result<RequestLogDoc> {
+    let path = scopes.check_str(&path, Access::Read)?;
+    store.read(&path)
+}
+
+/// Writes/overwrites a complete request-log document.
+#[tauri::command]
+pub async fn doc_write(
+    store: State<'_, FsDocStore>,
+    scopes: State<'_, ProjectScopes>,
+    path: String,
+    doc: RequestLogDoc,
+) -> Result<()> {
+    let path = scopes.check_str(&path, Access::Write)?;
+    store.write(&path, &doc)
+}
+
+/// Appends an item draft to an existing document and returns the result.
+#[tauri::command]
+pub async fn doc_append(
+    store: State<'_, FsDocStore>,
+    scopes: State<'_, ProjectScopes>,
+    path: String,
+    item: ItemDraft,
+) -> Result<RequestLogDoc> {
+    let path = scopes.check_str(&path, Access::Write)?;
+    store.append(&path, item, &SystemClock)
+}
+
+/// Creates a `.bak` copy of a document and returns the backup path.
+#[tauri::command]
+pub async fn doc_backup(
+    store: State<'_, FsDocStore>,
+    scopes: State<'_, ProjectScopes>,
+    path: String,
+) -> Result<String> {
+    let path = scopes.check_str(&path, Access::Write)?;
+    store.backup(&path)
+}
+
+/// Checks whether a path exists and is writable.
+#[tauri::command]
+pub async fn doc_is_writable(
+    store: State<'_, FsDocStore>,
+    scopes: State<'_, ProjectScopes>,
+    path: String,
+) -> Result<bool> {
+    let path = scopes.check_str(&path, Access::Read)?;
+    Ok(store.is_writable(&path))
+}
+
+/// Upgrades every request-log document in a directory to the current
+/// schema version; `dry_run` returns the diffs without writing.
+#[tauri::command]
+pub async fn migrate_documents(
+    store: State<'_, FsDocStore>,
+    scopes: State<'_, ProjectScopes>,
+    directory: String,
+    dry_run: bool,
+) -> Result<MigrationReport> {
+    let directory = scopes.check_str(&directory, Access::Directory)?;
+    migrate::migrate_documents(&store, &directory, dry_run)
+}
+
+/// Lists the backup snapshots of a document, newest first.
+#[tauri::command]
+pub async fn list_backups(
+    store: State<'_, FsDocStore>,
+    scopes: State<'_, ProjectScopes>,
+    path: String,
+) -> Result<Vec<BackupEntry>> {
+    let path = scopes.check_str(&path, Access::Read)?;
+    store.list_backups(&path)
+}
+
+/// Unified diff from a backup snapshot to the current document.
+#[tauri::command]
+pub async fn diff_backup(
+    store: State<'_, FsDocStore>,
+    scopes: State<'_, ProjectScopes>,
+    path: String,
+    backup_id: String,
+) -> Result<String> {
+    let path = scopes.check_str(&path, Access::Read)?;
+    store.diff_backup(&path, &backup_id)
+}
+
+/// Restores a document from a backup snapshot and returns it.
+#[tauri::command]
+pub async fn restore_backup(
+    store: State<'_, FsDocStore>,
+    scopes: State<'_, ProjectScopes>,
+    path: String,
+    backup_id: String,
+) -> Result<RequestLogDoc> {
+    let path = scopes.check_str(&path, Access::Write)?;
+    store.restore_backup(&path, &backup_id)
+}
@@ -0,0 +1,55 @@
+//! Field Catalog Commands
+//!
+//! `field_*` handlers backed by the native FieldCatalogStore (`fields.json`).
+
+use tauri::State;
+
+use crate::config_store::LocalFieldCatalogStore;
+use crate::error::Result;
+use crate::models::{FieldName, FieldOption, NewFieldOption};
+use crate::ports::FieldCatalogStore;
+
+/// Gets all global field options.
+#[tauri::command]
+pub async fn field_get_global(
+    store: State<'_, LocalFieldCatalogStore>,
+) -> Result<Vec<FieldOption>> {
+    store.get_global()
+}
+
+/// Gets global plus project-specific options for a project.
+#[tauri::command]
+pub async fn field_get_for_project(
+    store: State<'_, LocalFieldCatalogStore>,
+    project_id: String,
+) -> Result<Vec<FieldOption>> {
+    store.get_for_project(&project_id)
+}
+
+/// Gets options for one field, optionally including a project's additions.
+#[tauri::command]
+pub async fn field_get_by_field(
+    store: State<'_, LocalFieldCatalogStore>,
+    field: FieldName,
+    project_id: Option<String>,
+) -> Result<Vec<FieldOption>> {
+    store.get_by_field(field, project_id.as_deref())
+}
+
+/// Adds a global or project-scoped option.
+#[tauri::command]
+pub async fn field_add_option(
+    store: State<'_, LocalFieldCatalogStore>,
+    option: NewFieldOption,
+) -> Result<FieldOption> {
+    store.add_option(option)
+}
+
+/// Removes an option by ID.
+#[tauri::command]
+pub async fn field_remove_option(
+    store: State<'_, LocalFieldCatalogStore>,
+    id: String,
+) -> Result<()> {
+    store.remove_option(&id)
+}
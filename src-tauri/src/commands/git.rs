@@ -0,0 +1,19 @@
+//! Git Commands
+//!
+//! Repository state for documents in auto-commit projects (`git` feature).
+//! Commit attempts themselves are pushed to the webview as `git-commit`
+//! events carrying a `CommitReport`.
+
+use tauri::State;
+
+use crate::error::Result;
+use crate::git::{self, RepoStatus};
+use crate::path_guard::Access;
+use crate::scope::ProjectScopes;
+
+/// Describes the git repository containing a document (branch, clean,
+/// dirty, conflicted or mid-merge, and the changed paths).
+#[tauri::command]
+pub async fn git_status(scopes: State<'_, ProjectScopes>, path: String) -> Result<RepoStatus> {
+    git::repo_status(&scopes.check(&path, Access::Read)?)
+}
@@ -0,0 +1,27 @@
+//! Tauri Commands
+//!
+//! IPC handlers exposed to the webview, grouped by store:
+//! - capture: Quick-capture window (`capture_*`, desktop only)
+//! - config: ConfigStore operations (`config_*`)
+//! - docs: DocStore operations (`doc_*`), `migrate_documents` and backup
+//!   history (`list_backups`, `diff_backup`, `restore_backup`)
+//! - projects: ProjectStore operations (`project_*`)
+//! - fields: FieldCatalogStore operations (`field_*`)
+//! - git: Repository state for auto-commit projects (`git_status`)
+//! - search: Full-text search over all items (`search*`)
+//! - windows: Opens the capture/viewer/admin windows (`window_open`, desktop only)
+//!
+//! Handlers are thin wrappers: each performs a whole store operation in a
+//! single round trip and returns serde-serialized domain models.
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub mod capture;
+pub mod config;
+pub mod docs;
+pub mod fields;
+#[cfg(feature = "git")]
+pub mod git;
+pub mod projects;
+pub mod search;
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub mod windows;
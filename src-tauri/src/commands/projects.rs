@@ -0,0 +1,74 @@
+//! Project Commands
+//!
+//! `project_*` handlers backed by the native ProjectStore (`projects.json`).
+//! Mutations re-sync the project scopes so the document commands accept a
+//! project folder as soon as it is registered, and refuse it on removal.
+//! Folders that would expose too much (filesystem roots, the home or data
+//! directory, or anything containing them) are rejected first.
+
+use tauri::{AppHandle, State};
+
+use crate::app::sync_project_scopes;
+use crate::config_store::LocalProjectStore;
+use crate::error::Result;
+use crate::models::{NewProject, Project, ProjectUpdate};
+use crate::ports::ProjectStore;
+use crate::scope::ProjectScopes;
+
+/// Lists all projects (enabled and disabled).
+#[tauri::command]
+pub async fn project_list(store: State<'_, LocalProjectStore>) -> Result<Vec<Project>> {
+    store.list()
+}
+
+/// Gets a project by ID, or `null` if it does not exist.
+#[tauri::command]
+pub async fn project_get(
+    store: State<'_, LocalProjectStore>,
+    id: String,
+) -> Result<Option<Project>> {
+    store.get(&id)
+}
+
+/// Creates a project with a slug ID derived from its name.
+#[tauri::command]
+pub async fn project_create(
+    app: AppHandle,
+    store: State<'_, LocalProjectStore>,
+    scopes: State<'_, ProjectScopes>,
+    project: NewProject,
+) -> Result<Project> {
+    scopes.check_project_root(&project.default_path)?;
+    let created = store.create(project)?;
+    sync_project_scopes(&app);
+    Ok(created)
+}
+
+/// Merges partial updates into a project.
+#[tauri::command]
+pub async fn project_update(
+    app: AppHandle,
+    store: State<'_, LocalProjectStore>,
+    scopes: State<'_, ProjectScopes>,
+    id: String,
+    updates: ProjectUpdate,
+) -> Result<Project> {
+    if let Some(path) = &updates.default_path {
+        scopes.check_project_root(path)?;
+    }
+    let updated = store.update(&id, updates)?;
+    sync_project_scopes(&app);
+    Ok(updated)
+}
+
+/// Deletes a project (documents and field options are left untouched).
+#[tauri::command]
+pub async fn project_delete(
+    app: AppHandle,
+    store: State<'_, LocalProjectStore>,
+    id: String,
+) -> Result<()> {
+    store.delete(&id)?;
+    sync_project_scopes(&app);
+    Ok(())
+}
@@ -0,0 +1,39 @@
+//! Search Commands
+//!
+//! `search*` handlers backed by the native full-text index.
+
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::config_store::LocalProjectStore;
+use crate::doc_store::{enabled_project_dirs, FsDocStore};
+use crate::error::Result;
+use crate::search::{SearchIndex, SearchResults, SyncStats};
+
+/// Default number of hits returned when `limit` is omitted.
+const DEFAULT_LIMIT: usize = 50;
+
+/// Searches items across all enabled projects.
+///
+/// Supports phrases (`"dark mode"`), field prefixes (`tag:ux status:triage`)
+/// and boolean operators; results are ranked with highlighted snippets.
+#[tauri::command]
+pub async fn search(
+    index: State<'_, Arc<SearchIndex>>,
+    query: String,
+    limit: Option<usize>,
+) -> Result<SearchResults> {
+    index.search(&query, limit.unwrap_or(DEFAULT_LIMIT))
+}
+
+/// Drops the search index and re-indexes every enabled project from disk.
+#[tauri::command]
+pub async fn search_rebuild(
+    index: State<'_, Arc<SearchIndex>>,
+    projects: State<'_, LocalProjectStore>,
+    docs: State<'_, FsDocStore>,
+) -> Result<SyncStats> {
+    let dirs = enabled_project_dirs(&*projects)?;
+    index.sync_dirs(&dirs, &docs, true)
+}
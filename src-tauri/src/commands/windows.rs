@@ -0,0 +1,15 @@
+//! Window Commands
+//!
+//! Opens the desktop windows (`window_open`).
+
+use tauri::AppHandle;
+
+use crate::error::Result;
+use crate::windows::{self, AppWindow};
+
+/// Opens (or focuses) the `capture`, `viewer` or `admin` window.
+#[tauri::command]
+pub async fn window_open(app: AppHandle, window: AppWindow) -> Result<()> {
+    windows::open(&app, window);
+    Ok(())
+}
@@ -0,0 +1,694 @@
+//! Local Configuration Stores
+//!
+//! Native implementation of ConfigStore, ProjectStore and FieldCatalogStore
+//! backed by the same JSON files the TypeScript `config-local` adapter uses:
+//! - config.json: Application configuration
+//! - projects.json: Project registry
+//! - fields.json: Global + project-scoped field options
+//! - Default location: ~/.meatycapture/ (`MEATYCAPTURE_CONFIG_DIR` overrides)
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+
+use chrono::{DateTime, SubsecRound, Utc};
+use regex::Regex;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::atomic_write::write_atomic;
+use crate::error::{Error, Result};
+use crate::ids::slugify;
+use crate::models::{
+    AppConfig, AppConfigUpdate, FieldName, FieldOption, FieldScope, NewFieldOption, NewProject,
+    Project, ProjectUpdate, DEFAULT_FIELD_OPTIONS,
+};
+use crate::ports::{ConfigStore, FieldCatalogStore, ProjectStore};
+
+/// Kebab-case project IDs: lowercase alphanumerics separated by single hyphens.
+static PROJECT_ID_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-z0-9]+(-[a-z0-9]+)*$").unwrap());
+
+/// Configuration directory path resolution.
+///
+/// Priority:
+/// 1. `MEATYCAPTURE_CONFIG_DIR` environment variable
+/// 2. Default: `~/.meatycapture/`
+pub fn config_dir() -> PathBuf {
+    std::env::var_os("MEATYCAPTURE_CONFIG_DIR")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| dirs::home_dir().unwrap_or_default().join(".meatycapture"))
+}
+
+/// Generates a random bearer token for the embedded API server (32 bytes
+/// from the OS random source, hex-encoded).
+pub fn generate_local_api_token() -> Result<String> {
+    let mut bytes = [0u8; 32];
+    getrandom::fill(&mut bytes)
+        .map_err(|error| Error::io("Failed to generate local API token")(error.into()))?;
+    Ok(bytes.iter().map(|byte| format!("{byte:02x}")).collect())
+}
+
+/// Current time at the millisecond precision the JSON files store.
+fn now() -> DateTime<Utc> {
+    Utc::now().trunc_subsecs(3)
+}
+
+/// Reads a JSON config file, returning `None` when it does not exist yet.
+fn read_json<T: DeserializeOwned>(path: &Path, label: &str) -> Result<Option<T>> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(Error::io(format!("Failed to read {label} file"))(error)),
+    };
+
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(Error::json(format!("Failed to read {label} file")))
+}
+
+/// Writes a JSON config file with two-space indentation (`JSON.stringify(data, null, 2)`).
+fn write_json<T: Serialize>(dir: &Path, path: &Path, data: &T, label: &str) -> Result<()> {
+    fs::create_dir_all(dir).map_err(Error::io(format!(
+        "Failed to create config directory {}",
+        dir.display()
+    )))?;
+
+    let content = serde_json::to_string_pretty(data)
+        .map_err(Error::json(format!("Failed to write {label} file")))?;
+
+    write_atomic(path, content).map_err(Error::io(format!("Failed to write {label} file")))
+}
+
+// ============================================================================
+// ConfigStore Implementation
+// ============================================================================
+
+/// Config file format version written by new files.
+const CONFIG_VERSION: &str = "1.0.0";
+
+/// Local filesystem implementation of ConfigStore.
+///
+/// Stores settings in `config.json`. Unknown keys are preserved so the CLI
+/// and desktop app can each add settings without clobbering the other's.
+#[derive(Debug)]
+pub struct LocalConfigStore {
+    config_dir: PathBuf,
+    config_file: PathBuf,
+    write_lock: Mutex<()>,
+}
+
+impl LocalConfigStore {
+    /// Creates a store rooted at `config_dir` (defaults to [`config_dir()`]).
+    pub fn new(config_dir: Option<PathBuf>) -> Self {
+        let config_dir = config_dir.unwrap_or_else(self::config_dir);
+        Self {
+            config_file: config_dir.join("config.json"),
+            config_dir,
+            write_lock: Mutex::new(()),
+        }
+    }
+}
+
+impl ConfigStore for LocalConfigStore {
+    fn get(&self) -> Result<AppConfig> {
+        // Missing file: defaults without writing, like the TS store
+        Ok(read_json(&self.config_file, "config")?.unwrap_or_else(|| {
+            let now = now();
+            AppConfig {
+                version: CONFIG_VERSION.to_string(),
+                default_project: None,
+                api_url: None,
+                close_to_tray: None,
+                capture_shortcut: None,
+                last_document: None,
+                deep_link_auto_submit: None,
+                local_api_port: None,
+                local_api_token: None,
+                backup_keep: None,
+                backup_max_age_days: None,
+                created_at: now,
+                updated_at: now,
+                extra: Default::default(),
+            }
+        }))
+    }
+
+    fn update(&self, updates: AppConfigUpdate) -> Result<AppConfig> {
+        let _guard = self.write_lock.lock().unwrap_or_else(|e| e.into_inner());
+        let mut config = self.get()?;
+
+        if let Some(default_project) = updates.default_project {
+            config.default_project = Some(default_project);
+        }
+        if let Some(api_url) = updates.api_url {
+            // Empty clears the setting (switches clients back to local mode)
+            config.api_url = Some(api_url.trim().to_string()).filter(|url| !url.is_empty());
+        }
+        if let Some(close_to_tray) = updates.close_to_tray {
+            config.close_to_tray = Some(close_to_tray);
+        }
+        if let Some(capture_shortcut) = updates.capture_shortcut {
+            config.capture_shortcut = Some(capture_shortcut.trim().to_string());
+        }
+        if let Some(last_document) = updates.last_document {
+            config.last_document = Some(last_document);
+        }
+        if let Some(auto_submit) = updates.deep_link_auto_submit {
+            config.deep_link_auto_submit = Some(auto_submit);
+        }
+        if let Some(port) = updates.local_api_port {
+            // Port 0 turns the embedded API server off
+            config.local_api_port = Some(port).filter(|port| *port != 0);
+        }
+        if let Some(token) = updates.local_api_token {
+            config.local_api_token = Some(token.trim().to_string()).filter(|t| !t.is_empty());
+        }
+        if let Some(keep) = updates.backup_keep {
+            config.backup_keep = Some(keep);
+        }
+        if let Some(days) = updates.backup_max_age_days {
+            config.backup_max_age_days = Some(days);
+        }
+        config.updated_at = now();
+
+        write_json(&self.config_dir, &self.config_file, &config, "config")?;
+        Ok(config)
+    }
+
+    fn exists(&self) -> bool {
+        self.config_file.exists()
+    }
+}
+
+// ============================================================================
+// ProjectStore Implementation
+// ============================================================================
+
+/// Projects JSON file structure.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ProjectsFile {
+    projects: Vec<Project>,
+}
+
+/// Local filesystem implementation of ProjectStore.
+///
+/// Stores projects in `projects.json`; generates IDs with `slugify` and
+/// manages timestamps. Mutations are serialized within the process.
+#[derive(Debug)]
+pub struct LocalProjectStore {
+    config_dir: PathBuf,
+    projects_file: PathBuf,
+    write_lock: Mutex<()>,
+}
+
+impl LocalProjectStore {
+    /// Creates a store rooted at `config_dir` (defaults to [`config_dir()`]).
+    pub fn new(config_dir: Option<PathBuf>) -> Self {
+        let config_dir = config_dir.unwrap_or_else(self::config_dir);
+        Self {
+            projects_file: config_dir.join("projects.json"),
+            config_dir,
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    /// Path of the backing `projects.json`.
+    pub fn projects_file(&self) -> &Path {
+        &self.projects_file
+    }
+
+    fn read_projects(&self) -> Result<Vec<Project>> {
+        Ok(read_json::<ProjectsFile>(&self.projects_file, "projects")?
+            .unwrap_or_default()
+            .projects)
+    }
+
+    fn write_projects(&self, projects: Vec<Project>) -> Result<()> {
+        write_json(
+            &self.config_dir,
+            &self.projects_file,
+            &ProjectsFile { projects },
+            "projects",
+        )
+    }
+}
+
+impl ProjectStore for LocalProjectStore {
+    fn list(&self) -> Result<Vec<Project>> {
+        self.read_projects()
+    }
+
+    fn get(&self, id: &str) -> Result<Option<Project>> {
+        Ok(self.read_projects()?.into_iter().find(|p| p.id == id))
+    }
+
+    fn create(&self, project: NewProject) -> Result<Project> {
+        let _guard = self.write_lock.lock().unwrap_or_else(|e| e.into_inner());
+        let mut projects = self.read_projects()?;
+
+        let id = match project.id {
+            Some(id) if PROJECT_ID_PATTERN.is_match(&id) => id,
+            Some(id) => {
+                return Err(Error::Validation(format!(
+                    "Invalid project ID format: \"{id}\""
+                )))
+            }
+            None => slugify(&project.name),
+        };
+        if id.is_empty() {
+            return Err(Error::Validation(format!(
+                "Invalid project name: cannot generate ID from \"{}\"",
+                project.name
+            )));
+        }
+        if projects.iter().any(|p| p.id == id) {
+            return Err(Error::Validation(format!(
+                "Project with ID \"{id}\" already exists"
+            )));
+        }
+
+        let now = now();
+        let new_project = Project {
+            id,
+            name: project.name,
+            default_path: project.default_path,
+            repo_url: project.repo_url,
+            enabled: project.enabled,
+            auto_commit: project.auto_commit,
+            created_at: now,
+            updated_at: now,
+        };
+
+        projects.push(new_project.clone());
+        self.write_projects(projects)?;
+        Ok(new_project)
+    }
+
+    fn update(&self, id: &str, updates: ProjectUpdate) -> Result<Project> {
+        let _guard = self.write_lock.lock().unwrap_or_else(|e| e.into_inner());
+        let mut projects = self.read_projects()?;
+
+        let project = projects
+            .iter_mut()
+            .find(|p| p.id == id)
+            .ok_or_else(|| Error::NotFound(format!("Project not found: {id}")))?;
+
+        if let Some(name) = updates.name {
+            project.name = name;
+        }
+        if let Some(default_path) = updates.default_path {
+            project.default_path = default_path;
+        }
+        if let Some(repo_url) = updates.repo_url {
+            project.repo_url = Some(repo_url);
+        }
+        if let Some(enabled) = updates.enabled {
+            project.enabled = enabled;
+        }
+        if let Some(auto_commit) = updates.auto_commit {
+            project.auto_commit = Some(auto_commit);
+        }
+        project.updated_at = now();
+
+        let updated = project.clone();
+        self.write_projects(projects)?;
+        Ok(updated)
+    }
+
+    fn delete(&self, id: &str) -> Result<()> {
+        let _guard = self.write_lock.lock().unwrap_or_else(|e| e.into_inner());
+        let mut projects = self.read_projects()?;
+
+        let index = projects
+            .iter()
+            .position(|p| p.id == id)
+            .ok_or_else(|| Error::NotFound(format!("Project not found: {id}")))?;
+
+        projects.remove(index);
+        self.write_projects(projects)
+    }
+}
+
+// ============================================================================
+// FieldCatalogStore Implementation
+// ============================================================================
+
+/// Fields JSON file structure.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct FieldsFile {
+    global: Vec<FieldOption>,
+    projects: BTreeMap<String, Vec<FieldOption>>,
+}
+
+/// Local filesystem implementation of FieldCatalogStore.
+///
+/// Stores options in `fields.json`, initializing it with
+/// [`DEFAULT_FIELD_OPTIONS`] on first access.
+#[derive(Debug)]
+pub struct LocalFieldCatalogStore {
+    config_dir: PathBuf,
+    fields_file: PathBuf,
+    write_lock: Mutex<()>,
+}
+
+impl LocalFieldCatalogStore {
+    /// Creates a store rooted at `config_dir` (defaults to [`config_dir()`]).
+    pub fn new(config_dir: Option<PathBuf>) -> Self {
+        let config_dir = config_dir.unwrap_or_else(self::config_dir);
+        Self {
+            fields_file: config_dir.join("fields.json"),
+            config_dir,
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    /// Generates an option ID: `{field}-{value-slug}-{timestamp_ms}`.
+    fn generate_option_id(field: FieldName, value: &str) -> String {
+        format!(
+            "{}-{}-{}",
+            field.as_str(),
+            slugify(value),
+            now().timestamp_millis()
+        )
+    }
+
+    fn read_fields(&self) -> Result<FieldsFile> {
+        match read_json::<FieldsFile>(&self.fields_file, "fields")? {
+            Some(data) => Ok(data),
+            None => self.initialize_defaults(),
+        }
+    }
+
+    fn initialize_defaults(&self) -> Result<FieldsFile> {
+        let now = now();
+        let global = DEFAULT_FIELD_OPTIONS
+            .iter()
+            .flat_map(|(field, values)| {
+                values.iter().map(move |value| FieldOption {
+                    id: Self::generate_option_id(*field, value),
+                    field: *field,
+                    value: value.to_string(),
+                    scope: FieldScope::Global,
+                    project_id: None,
+                    created_at: now,
+                })
+            })
+            .collect();
+
+        let data = FieldsFile {
+            global,
+            projects: BTreeMap::new(),
+        };
+        self.write_fields(&data)?;
+        Ok(data)
+    }
+
+    fn write_fields(&self, data: &FieldsFile) -> Result<()> {
+        write_json(&self.config_dir, &self.fields_file, data, "fields")
+    }
+}
+
+impl FieldCatalogStore for LocalFieldCatalogStore {
+    fn get_global(&self) -> Result<Vec<FieldOption>> {
+        Ok(self.read_fields()?.global)
+    }
+
+    fn get_for_project(&self, project_id: &str) -> Result<Vec<FieldOption>> {
+        let mut data = self.read_fields()?;
+        let project_options = data.projects.remove(project_id).unwrap_or_default();
+
+        data.global.extend(project_options);
+        Ok(data.global)
+    }
+
+    fn get_by_field(&self, field: FieldName, project_id: Option<&str>) -> Result<Vec<FieldOption>> {
+        let options = match project_id {
+            Some(project_id) => self.get_for_project(project_id)?,
+            None => self.get_global()?,
+        };
+        Ok(options
+            .into_iter()
+            .filter(|opt| opt.field == field)
+            .collect())
+    }
+
+    fn add_option(&self, option: NewFieldOption) -> Result<FieldOption> {
+        let _guard = self.write_lock.lock().unwrap_or_else(|e| e.into_inner());
+        let mut data = self.read_fields()?;
+
+        let new_option = FieldOption {
+            id: Self::generate_option_id(option.field, &option.value),
+            field: option.field,
+            value: option.value,
+            scope: option.scope,
+            project_id: option.project_id,
+            created_at: now(),
+        };
+
+        let (options, scope_label) = match (new_option.scope, new_option.project_id.as_deref()) {
+            (FieldScope::Global, _) => (&mut data.global, "Global"),
+            (FieldScope::Project, Some(project_id)) if !project_id.is_empty() => (
+                data.projects.entry(project_id.to_string()).or_default(),
+                "Project",
+            ),
+            (FieldScope::Project, _) => {
+                return Err(Error::Validation(
+                    "project_id is required for project-scoped options".to_string(),
+                ))
+            }
+        };
+
+        if options
+            .iter()
+            .any(|opt| opt.field == new_option.field && opt.value == new_option.value)
+        {
+            return Err(Error::Validation(format!(
+                "{scope_label} option already exists for {}: {}",
+                new_option.field.as_str(),
+                new_option.value
+            )));
+        }
+
+        options.push(new_option.clone());
+        self.write_fields(&data)?;
+        Ok(new_option)
+    }
+
+    fn remove_option(&self, id: &str) -> Result<()> {
+        let _guard = self.write_lock.lock().unwrap_or_else(|e| e.into_inner());
+        let mut data = self.read_fields()?;
+
+        let scopes = std::iter::once(&mut data.global).chain(data.projects.values_mut());
+        for options in scopes {
+            if let Some(index) = options.iter().position(|opt| opt.id == id) {
+                options.remove(index);
+                return self.write_fields(&data);
+            }
+        }
+
+        Err(Error::NotFound(format!("Field option not found: {id}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DEFAULT_CAPTURE_SHORTCUT;
+
+    fn new_project(name: &str) -> NewProject {
+        NewProject {
+            id: None,
+            name: name.to_string(),
+            default_path: "~/docs".to_string(),
+            repo_url: None,
+            enabled: true,
+            auto_commit: None,
+        }
+    }
+
+    #[test]
+    fn project_crud_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalProjectStore::new(Some(dir.path().to_path_buf()));
+
+        let created = store.create(new_project("My Project")).unwrap();
+        assert_eq!(created.id, "my-project");
+        assert!(store.create(new_project("my project")).is_err());
+        assert!(store.create(new_project("!!!")).is_err());
+
+        let custom = NewProject {
+            id: Some("app-2".to_string()),
+            ..new_project("My Project")
+        };
+        assert_eq!(store.create(custom.clone()).unwrap().id, "app-2");
+        let invalid = NewProject {
+            id: Some("App_2".to_string()),
+            ..custom
+        };
+        assert!(matches!(store.create(invalid), Err(Error::Validation(_))));
+        store.delete("app-2").unwrap();
+
+        let updated = store
+            .update(
+                "my-project",
+                ProjectUpdate {
+                    enabled: Some(false),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert!(!updated.enabled);
+        assert_eq!(updated.created_at, created.created_at);
+        assert_eq!(store.get("my-project").unwrap(), Some(updated));
+
+        store.delete("my-project").unwrap();
+        assert!(store.list().unwrap().is_empty());
+        assert!(matches!(
+            store.delete("my-project"),
+            Err(Error::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn projects_file_matches_ts_format() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalProjectStore::new(Some(dir.path().to_path_buf()));
+        store.create(new_project("App")).unwrap();
+
+        let content = fs::read_to_string(dir.path().join("projects.json")).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+        let project = &json["projects"][0];
+
+        assert!(content.starts_with("{\n  \"projects\": [\n"));
+        assert!(project.get("repo_url").is_none());
+        assert!(project["created_at"].as_str().unwrap().ends_with('Z'));
+        assert_eq!(project["created_at"].as_str().unwrap().len(), 24);
+    }
+
+    #[test]
+    fn field_catalog_merges_scopes() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalFieldCatalogStore::new(Some(dir.path().to_path_buf()));
+
+        assert_eq!(store.get_global().unwrap().len(), 15);
+
+        let spike = store
+            .add_option(NewFieldOption {
+                field: FieldName::Type,
+                value: "spike".to_string(),
+                scope: FieldScope::Project,
+                project_id: Some("app".to_string()),
+            })
+            .unwrap();
+        assert!(spike.id.starts_with("type-spike-"));
+
+        assert_eq!(store.get_by_field(FieldName::Type, None).unwrap().len(), 5);
+        assert_eq!(
+            store
+                .get_by_field(FieldName::Type, Some("app"))
+                .unwrap()
+                .len(),
+            6
+        );
+
+        let missing_project = NewFieldOption {
+            field: FieldName::Tags,
+            value: "ux".to_string(),
+            scope: FieldScope::Project,
+            project_id: None,
+        };
+        assert!(store.add_option(missing_project).is_err());
+
+        store.remove_option(&spike.id).unwrap();
+        assert_eq!(store.get_for_project("app").unwrap().len(), 15);
+        assert!(store.remove_option(&spike.id).is_err());
+    }
+
+    #[test]
+    fn config_update_preserves_unknown_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("config.json"),
+            r#"{
+  "version": "1.0.0",
+  "default_project": "app",
+  "future_setting": { "enabled": true },
+  "created_at": "2025-12-03T10:00:00.000Z",
+  "updated_at": "2025-12-03T10:00:00.000Z"
+}"#,
+        )
+        .unwrap();
+        let store = LocalConfigStore::new(Some(dir.path().to_path_buf()));
+
+        let config = store
+            .update(AppConfigUpdate {
+                close_to_tray: Some(true),
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert!(config.close_to_tray());
+        assert_eq!(config.default_project.as_deref(), Some("app"));
+        let content = fs::read_to_string(dir.path().join("config.json")).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(json["future_setting"]["enabled"], true);
+        assert_eq!(json["close_to_tray"], true);
+        assert_eq!(json["created_at"], "2025-12-03T10:00:00.000Z");
+    }
+
+    #[test]
+    fn deep_link_auto_submit_is_opt_in() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalConfigStore::new(Some(dir.path().to_path_buf()));
+        assert!(!store.get().unwrap().deep_link_auto_submit());
+
+        let config = store
+            .update(AppConfigUpdate {
+                deep_link_auto_submit: Some(true),
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(config.deep_link_auto_submit());
+    }
+
+    #[test]
+    fn local_api_tokens_are_random_hex() {
+        let first = generate_local_api_token().unwrap();
+        let second = generate_local_api_token().unwrap();
+        assert_eq!(first.len(), 64);
+        assert!(first.bytes().all(|byte| byte.is_ascii_hexdigit()));
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn config_capture_shortcut_defaults_and_can_be_disabled() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalConfigStore::new(Some(dir.path().to_path_buf()));
+        assert_eq!(
+            store.get().unwrap().capture_shortcut(),
+            DEFAULT_CAPTURE_SHORTCUT
+        );
+        assert!(!store.exists());
+
+        let config = store
+            .update(AppConfigUpdate {
+                capture_shortcut: Some(" Alt+Shift+C ".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(config.capture_shortcut(), "Alt+Shift+C");
+
+        let config = store
+            .update(AppConfigUpdate {
+                capture_shortcut: Some(String::new()),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(config.capture_shortcut(), "");
+        assert_eq!(store.get().unwrap().capture_shortcut(), "");
+    }
+}
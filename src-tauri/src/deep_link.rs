@@ -0,0 +1,529 @@
+//! Deep Links
+//!
+//! `meatycapture://` URLs for capturing from scripts, bookmarklets and other
+//! apps without the CLI:
+//! - `capture?project=&doc=&title=&type=&tags=a,b&notes=`: opens the wizard
+//!   prefilled; with `&submit=1` the capture window asks for confirmation,
+//!   or the item is saved directly if `deep_link_auto_submit` is set
+//! - `open?doc_id=REQ-...`: shows a document in the viewer
+//!
+//! Links come from untrusted sources, so every parameter is checked against
+//! the project store and field catalog before anything is shown or written.
+
+use serde::Serialize;
+use url::Url;
+
+use crate::doc_store::{
+    capture_to_daily_doc, enabled_project_dirs, expand_path, DailyCapture, FsDocStore,
+};
+use crate::error::{Error, Result};
+use crate::ids::is_valid_doc_id;
+use crate::models::{DocMeta, FieldName, ItemDraft};
+use crate::ports::{Clock, ConfigStore, DocStore, FieldCatalogStore, ProjectStore};
+
+/// URL scheme registered with the OS.
+pub const SCHEME: &str = "meatycapture";
+
+/// Longest accepted link; notes are meant to be short when sent by URL.
+const MAX_LINK_LEN: usize = 8 * 1024;
+
+/// Parsed `meatycapture://` link.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeepLink {
+    /// `meatycapture://capture?...`
+    Capture(CaptureLink),
+    /// `meatycapture://open?doc_id=...`
+    Open { doc_id: String },
+}
+
+/// Parameters of a capture link, not yet checked against the stores.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CaptureLink {
+    /// Project ID; falls back to `default_project`
+    pub project: Option<String>,
+    /// Existing document (doc ID or file name) in the project
+    pub doc: Option<String>,
+    pub title: Option<String>,
+    pub item_type: Option<String>,
+    pub tags: Vec<String>,
+    pub notes: Option<String>,
+    /// Save without showing the wizard
+    pub submit: bool,
+}
+
+/// Item fields prefilled in the wizard (serialized into `navigate`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CapturePrefill {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub item_type: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+/// Capture link validated against the stores.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapturePlan {
+    pub project_id: String,
+    /// Document to append to; `None` captures into today's document
+    pub doc_path: Option<String>,
+    pub prefill: CapturePrefill,
+    pub submit: bool,
+}
+
+impl DeepLink {
+    /// True if a launch argument is a `meatycapture:` URL.
+    pub fn matches(arg: &str) -> bool {
+        arg.split_once(':')
+            .is_some_and(|(scheme, _)| scheme.eq_ignore_ascii_case(SCHEME))
+    }
+
+    /// Parses and syntax-checks a link; unknown actions and parameters are
+    /// rejected so typos in scripts surface instead of being ignored.
+    pub fn parse(link: &str) -> Result<Self> {
+        if link.len() > MAX_LINK_LEN {
+            return Err(invalid(format!("longer than {MAX_LINK_LEN} bytes")));
+        }
+        let url = Url::parse(link).map_err(|error| invalid(error.to_string()))?;
+        if url.scheme() != SCHEME {
+            return Err(invalid(format!("expected {SCHEME}:// scheme")));
+        }
+        let action = match url.host_str() {
+            Some(host) if !host.is_empty() => host.to_string(),
+            _ => url.path().trim_matches('/').to_string(),
+        };
+
+        let mut params: Vec<(String, String)> = Vec::new();
+        for (key, value) in url.query_pairs() {
+            if params.iter().any(|(seen, _)| *seen == key) {
+                return Err(invalid(format!("duplicate parameter \"{key}\"")));
+            }
+            params.push((key.into_owned(), value.into_owned()));
+        }
+
+        match action.as_str() {
+            "capture" => Self::capture(params),
+            "open" => Self::open(params),
+            other => Err(invalid(format!(
+                "unknown action \"{other}\" (expected capture or open)"
+            ))),
+        }
+    }
+
+    fn capture(params: Vec<(String, String)>) -> Result<Self> {
+        let mut link = CaptureLink::default();
+        for (key, value) in params {
+            match key.as_str() {
+                "project" => link.project = non_empty(value),
+                "doc" => link.doc = non_empty(value),
+                "title" => link.title = non_empty(value),
+                "type" => link.item_type = non_empty(value),
+                "notes" => link.notes = non_empty(value),
+                "tags" => {
+                    for tag in value.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+                        if !link.tags.iter().any(|seen| seen == tag) {
+                            link.tags.push(tag.to_string());
+                        }
+                    }
+                }
+                "submit" => {
+                    link.submit = match value.as_str() {
+                        "1" | "true" => true,
+                        "" | "0" | "false" => false,
+                        _ => {
+                            return Err(invalid(format!("submit must be 1 or 0, got \"{value}\"")))
+                        }
+                    }
+                }
+                _ => return Err(unknown_param(&key)),
+            }
+        }
+        Ok(Self::Capture(link))
+    }
+
+    fn open(params: Vec<(String, String)>) -> Result<Self> {
+        let mut doc_id = None;
+        for (key, value) in params {
+            match key.as_str() {
+                "doc_id" => doc_id = non_empty(value),
+                _ => return Err(unknown_param(&key)),
+            }
+        }
+        let doc_id = doc_id.ok_or_else(|| invalid("open requires doc_id".to_string()))?;
+        if !is_valid_doc_id(&doc_id) {
+            return Err(invalid(format!("\"{doc_id}\" is not a document ID")));
+        }
+        Ok(Self::Open { doc_id })
+    }
+}
+
+impl CaptureLink {
+    /// Checks the project, document and type against the stores.
+    pub fn resolve(
+        self,
+        projects: &dyn ProjectStore,
+        fields: &dyn FieldCatalogStore,
+        docs: &dyn DocStore,
+        config: &dyn ConfigStore,
+    ) -> Result<CapturePlan> {
+        let project_id = match self.project {
+            Some(id) => id,
+            None => config.get()?.default_project.ok_or_else(|| {
+                Error::Validation(
+                    "Deep link needs a project (no default_project configured)".to_string(),
+                )
+            })?,
+        };
+        let project = projects
+            .get(&project_id)?
+            .ok_or_else(|| Error::NotFound(format!("Project not found: {project_id}")))?;
+        if !project.enabled {
+            return Err(Error::Validation(format!(
+                "Project {project_id} is disabled"
+            )));
+        }
+
+        let doc_path = match &self.doc {
+            Some(doc) => {
+                let directory = expand_path(&project.default_path).display().to_string();
+                let meta = docs
+                    .list(&directory)?
+                    .into_iter()
+                    .find(|meta| is_document(meta, doc))
+                    .ok_or_else(|| {
+                        Error::NotFound(format!("Document {doc} not found in project {project_id}"))
+                    })?;
+                Some(meta.path)
+            }
+            None => None,
+        };
+
+        if let Some(item_type) = &self.item_type {
+            let allowed: Vec<String> = fields
+                .get_by_field(FieldName::Type, Some(&project.id))?
+                .into_iter()
+                .map(|option| option.value)
+                .collect();
+            if !allowed.contains(item_type) {
+                return Err(Error::Validation(format!(
+                    "Invalid type \"{item_type}\". Valid types: {}",
+                    allowed.join(", ")
+                )));
+            }
+        }
+        if self.submit && (self.title.is_none() || self.item_type.is_none()) {
+            return Err(Error::Validation(
+                "submit=1 requires title and type".to_string(),
+            ));
+        }
+
+        Ok(CapturePlan {
+            project_id: project.id,
+            doc_path,
+            prefill: CapturePrefill {
+                title: self.title,
+                item_type: self.item_type,
+                tags: self.tags,
+                notes: self.notes,
+            },
+            submit: self.submit,
+        })
+    }
+}
+
+impl CapturePlan {
+    /// Saves the item: appends to the linked document, or to today's
+    /// project document (created under the document lock on first capture).
+    pub fn submit(
+        &self,
+        projects: &dyn ProjectStore,
+        docs: &FsDocStore,
+        clock: &dyn Clock,
+    ) -> Result<DailyCapture> {
+        let draft = ItemDraft {
+            title: self.prefill.title.clone().unwrap_or_default(),
+            item_type: self.prefill.item_type.clone().unwrap_or_default(),
+            domain: String::new(),
+            context: String::new(),
+            priority: "medium".to_string(),
+            status: "triage".to_string(),
+            tags: self.prefill.tags.clone(),
+            notes: self.prefill.notes.clone().unwrap_or_default(),
+        };
+
+        if let Some(path) = &self.doc_path {
+            let doc = docs.append(path, draft, clock)?;
+            return Ok(DailyCapture {
+                doc,
+                path: path.clone(),
+                created: false,
+            });
+        }
+        let project = projects
+            .get(&self.project_id)?
+            .ok_or_else(|| Error::NotFound(format!("Project not found: {}", self.project_id)))?;
+        capture_to_daily_doc(docs, &project, draft, clock)
+    }
+}
+
+/// Finds a document by ID across all enabled projects.
+pub fn find_document(
+    doc_id: &str,
+    projects: &dyn ProjectStore,
+    docs: &dyn DocStore,
+) -> Result<DocMeta> {
+    for dir in enabled_project_dirs(projects)? {
+        if let Some(meta) = docs
+            .list(&dir.display().to_string())?
+            .into_iter()
+            .find(|meta| meta.doc_id == doc_id)
+        {
+            return Ok(meta);
+        }
+    }
+    Err(Error::NotFound(format!("Document not found: {doc_id}")))
+}
+
+/// Matches a `doc` parameter by doc ID, file name or file stem.
+fn is_document(meta: &DocMeta, doc: &str) -> bool {
+    let file = std::path::Path::new(&meta.path);
+    meta.doc_id == doc
+        || file.file_name().is_some_and(|name| name == doc)
+        || file.file_stem().is_some_and(|stem| stem == doc)
+}
+
+fn non_empty(value: String) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+fn invalid(reason: String) -> Error {
+    Error::Validation(format!("Invalid deep link: {reason}"))
+}
+
+fn unknown_param(key: &str) -> Error {
+    invalid(format!("unknown parameter \"{key}\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config_store::{LocalConfigStore, LocalFieldCatalogStore, LocalProjectStore};
+    use crate::doc_store::FsDocStore;
+    use crate::models::{AppConfigUpdate, NewProject, ProjectUpdate};
+    use chrono::{DateTime, TimeZone, Utc};
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    struct FixedClock;
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            Utc.with_ymd_and_hms(2025, 12, 3, 12, 0, 0).unwrap()
+        }
+    }
+
+    struct Stores {
+        _temp: TempDir,
+        docs_dir: PathBuf,
+        projects: LocalProjectStore,
+        fields: LocalFieldCatalogStore,
+        config: LocalConfigStore,
+        docs: FsDocStore,
+    }
+
+    impl Stores {
+        fn new() -> Self {
+            let temp = TempDir::new().unwrap();
+            let config_dir = temp.path().join("config");
+            let docs_dir = temp.path().join("docs");
+            let projects = LocalProjectStore::new(Some(config_dir.clone()));
+            projects
+                .create(NewProject {
+                    id: None,
+                    name: "App".to_string(),
+                    default_path: docs_dir.display().to_string(),
+                    repo_url: None,
+                    enabled: true,
+                    auto_commit: None,
+                })
+                .unwrap();
+            Self {
+                _temp: temp,
+                docs_dir,
+                projects,
+                fields: LocalFieldCatalogStore::new(Some(config_dir.clone())),
+                config: LocalConfigStore::new(Some(config_dir)),
+                docs: FsDocStore::default(),
+            }
+        }
+
+        fn resolve(&self, link: &str) -> Result<CapturePlan> {
+            match DeepLink::parse(link)? {
+                DeepLink::Capture(capture) => {
+                    capture.resolve(&self.projects, &self.fields, &self.docs, &self.config)
+                }
+                other => panic!("expected a capture link, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn parses_capture_links() {
+        let link = DeepLink::parse(
+            "meatycapture://capture?project=app&title=Fix+login%20redirect&type=bug\
+             &tags=auth,%20ux,,auth&notes=Line%201%0ALine%202&submit=1",
+        )
+        .unwrap();
+        assert_eq!(
+            link,
+            DeepLink::Capture(CaptureLink {
+                project: Some("app".to_string()),
+                doc: None,
+                title: Some("Fix login redirect".to_string()),
+                item_type: Some("bug".to_string()),
+                tags: vec!["auth".to_string(), "ux".to_string()],
+                notes: Some("Line 1\nLine 2".to_string()),
+                submit: true,
+            })
+        );
+        assert_eq!(
+            DeepLink::parse("meatycapture:///capture").unwrap(),
+            DeepLink::Capture(CaptureLink::default())
+        );
+        assert_eq!(
+            DeepLink::parse("meatycapture://open?doc_id=REQ-20251203-app").unwrap(),
+            DeepLink::Open {
+                doc_id: "REQ-20251203-app".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_links() {
+        for link in [
+            "https://capture?title=x",
+            "meatycapture://delete?project=app",
+            "meatycapture://capture?titel=typo",
+            "meatycapture://capture?title=a&title=b",
+            "meatycapture://capture?submit=yes",
+            "meatycapture://open",
+            "meatycapture://open?doc_id=../../etc/passwd",
+            "not a url",
+        ] {
+            assert!(
+                matches!(DeepLink::parse(link), Err(Error::Validation(_))),
+                "{link}"
+            );
+        }
+        let long = format!("meatycapture://capture?notes={}", "x".repeat(MAX_LINK_LEN));
+        assert!(DeepLink::parse(&long).is_err());
+
+        assert!(DeepLink::matches("MeatyCapture://open?doc_id=x"));
+        assert!(!DeepLink::matches("/home/u/log.md"));
+        assert!(!DeepLink::matches("C:\\notes\\log.md"));
+    }
+
+    #[test]
+    fn resolves_against_project_store_and_catalog() {
+        let stores = Stores::new();
+
+        let plan = stores
+            .resolve("meatycapture://capture?project=app&title=Hi&type=bug")
+            .unwrap();
+        assert_eq!(plan.project_id, "app");
+        assert_eq!(plan.doc_path, None);
+
+        assert!(matches!(
+            stores.resolve("meatycapture://capture?project=nope"),
+            Err(Error::NotFound(_))
+        ));
+        assert!(matches!(
+            stores.resolve("meatycapture://capture?project=app&type=rant"),
+            Err(Error::Validation(message)) if message.contains("Valid types")
+        ));
+        assert!(matches!(
+            stores.resolve("meatycapture://capture?project=app&title=Hi&submit=1"),
+            Err(Error::Validation(_))
+        ));
+
+        // project falls back to default_project
+        assert!(stores.resolve("meatycapture://capture?title=Hi").is_err());
+        stores
+            .config
+            .update(AppConfigUpdate {
+                default_project: Some("app".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(
+            stores
+                .resolve("meatycapture://capture?title=Hi")
+                .unwrap()
+                .project_id,
+            "app"
+        );
+
+        stores
+            .projects
+            .update(
+                "app",
+                ProjectUpdate {
+                    enabled: Some(false),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert!(matches!(
+            stores.resolve("meatycapture://capture?project=app"),
+            Err(Error::Validation(message)) if message.contains("disabled")
+        ));
+    }
+
+    #[test]
+    fn submit_captures_into_daily_or_linked_document() {
+        let stores = Stores::new();
+        let plan = stores
+            .resolve("meatycapture://capture?project=app&title=First&type=bug&tags=ux&submit=1")
+            .unwrap();
+        let first = plan
+            .submit(&stores.projects, &stores.docs, &FixedClock)
+            .unwrap();
+        assert!(first.created);
+        assert_eq!(first.doc.items[0].title, "First");
+        assert_eq!(first.doc.items[0].tags, vec!["ux".to_string()]);
+        assert!(first
+            .path
+            .starts_with(&stores.docs_dir.display().to_string()));
+
+        let doc_id = first.doc.doc_id.clone();
+        for doc in [doc_id.as_str(), &format!("{doc_id}.md")] {
+            let plan = stores
+                .resolve(&format!(
+                    "meatycapture://capture?project=app&doc={doc}&title=Next&type=idea&submit=1"
+                ))
+                .unwrap();
+            assert_eq!(plan.doc_path.as_deref(), Some(first.path.as_str()));
+            let next = plan
+                .submit(&stores.projects, &stores.docs, &FixedClock)
+                .unwrap();
+            assert!(!next.created);
+        }
+        assert_eq!(stores.docs.read(&first.path).unwrap().item_count, 3);
+
+        assert!(matches!(
+            stores.resolve("meatycapture://capture?project=app&doc=REQ-20200101-app"),
+            Err(Error::NotFound(_))
+        ));
+        assert_eq!(
+            find_document(&doc_id, &stores.projects, &stores.docs)
+                .unwrap()
+                .path,
+            first.path
+        );
+        assert!(find_document("REQ-20200101-app", &stores.projects, &stores.docs).is_err());
+    }
+}
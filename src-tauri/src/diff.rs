@@ -0,0 +1,173 @@
+//! Line Diffs
+//!
+//! Minimal unified diff used to preview document rewrites:
+//! - Longest-common-subsequence alignment of lines
+//! - `@@ -a,b +c,d @@` hunks with three lines of context
+//! - Deletions are listed before insertions within a change
+
+/// Unchanged lines shown around each change.
+const CONTEXT: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Unified diff from `old` to `new`, or an empty string when the texts have
+/// the same lines.
+pub fn unified_diff(old: &str, new: &str, old_label: &str, new_label: &str) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let ops = diff_lines(&old_lines, &new_lines);
+
+    // Runs of ops to print, extended by the context and merged when they touch
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for (index, _) in ops
+        .iter()
+        .enumerate()
+        .filter(|(_, (op, _))| *op != Op::Equal)
+    {
+        let start = index.saturating_sub(CONTEXT);
+        let end = (index + 1 + CONTEXT).min(ops.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+    if hunks.is_empty() {
+        return String::new();
+    }
+
+    let mut lines = vec![format!("--- {old_label}"), format!("+++ {new_label}")];
+    let (mut old_pos, mut new_pos, mut next) = (0, 0, 0);
+    for (start, end) in hunks {
+        for (op, _) in &ops[next..start] {
+            old_pos += usize::from(*op != Op::Insert);
+            new_pos += usize::from(*op != Op::Delete);
+        }
+        let hunk = &ops[start..end];
+        let old_count = hunk.iter().filter(|(op, _)| *op != Op::Insert).count();
+        let new_count = hunk.iter().filter(|(op, _)| *op != Op::Delete).count();
+        lines.push(format!(
+            "@@ -{} +{} @@",
+            hunk_range(old_pos, old_count),
+            hunk_range(new_pos, new_count)
+        ));
+        for (op, line) in hunk {
+            let marker = match op {
+                Op::Equal => ' ',
+                Op::Delete => '-',
+                Op::Insert => '+',
+            };
+            lines.push(format!("{marker}{line}"));
+        }
+        old_pos += old_count;
+        new_pos += new_count;
+        next = end;
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// `start,count` as in GNU diff: 1-based, and the line before an empty range.
+fn hunk_range(before: usize, count: usize) -> String {
+    match count {
+        0 => format!("{before},0"),
+        1 => (before + 1).to_string(),
+        _ => format!("{},{count}", before + 1),
+    }
+}
+
+/// Aligns two line lists; the common prefix and suffix skip the LCS table.
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<(Op, &'a str)> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let a = &old[prefix..old.len() - suffix];
+    let b = &new[prefix..new.len() - suffix];
+
+    // lcs[i * width + j]: common subsequence length of a[i..] and b[j..]
+    let width = b.len() + 1;
+    let mut lcs = vec![0u32; (a.len() + 1) * width];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i * width + j] = if a[i] == b[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut ops: Vec<(Op, &str)> = old[..prefix].iter().map(|l| (Op::Equal, *l)).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            ops.push((Op::Equal, a[i]));
+            i += 1;
+            j += 1;
+        } else if i < a.len()
+            && (j == b.len() || lcs[(i + 1) * width + j] >= lcs[i * width + j + 1])
+        {
+            ops.push((Op::Delete, a[i]));
+            i += 1;
+        } else {
+            ops.push((Op::Insert, b[j]));
+            j += 1;
+        }
+    }
+    ops.extend(old[old.len() - suffix..].iter().map(|l| (Op::Equal, *l)));
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_texts_have_no_diff() {
+        assert_eq!(unified_diff("a\nb\n", "a\nb\n", "old", "new"), "");
+    }
+
+    #[test]
+    fn changes_are_grouped_into_hunks_with_context() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n";
+        let new = "0\n1\n2\n3\n4\n5\n6\n7\n8\n9\nten\n11\n12\n";
+
+        assert_eq!(
+            unified_diff(old, new, "a/doc.md", "b/doc.md"),
+            "--- a/doc.md
++++ b/doc.md
+@@ -1,3 +1,4 @@
++0
+ 1
+ 2
+ 3
+@@ -7,6 +8,6 @@
+ 7
+ 8
+ 9
+-10
++ten
+ 11
+ 12
+"
+        );
+    }
+
+    #[test]
+    fn nearby_changes_share_a_hunk() {
+        let diff = unified_diff("a\nb\nc\nd\n", "a\nB\nc\nD\n", "old", "new");
+
+        assert_eq!(
+            diff,
+            "--- old\n+++ new\n@@ -1,4 +1,4 @@\n a\n-b\n+B\n c\n-d\n+D\n"
+        );
+    }
+}
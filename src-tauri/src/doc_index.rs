@@ -0,0 +1,438 @@
+//! Document Metadata Index
+//!
+//! Persistent cache that lets catalog listings skip reparsing unchanged files:
+//! - Keyed by document path; validated by file mtime and size
+//! - Stores DocMeta, tags, items_index and item fields (notes excluded)
+//! - Revalidated incrementally: only new or changed files are parsed
+//! - Persisted as JSON at `~/.meatycapture/doc-index.json` (atomic writes)
+//!
+//! The index is a cache, never a source of truth. A missing, corrupt or
+//! outdated file is discarded and rebuilt from disk, and concurrent writers
+//! in other processes are harmless because every entry is re-checked
+//! against the file's current mtime and size.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::UNIX_EPOCH;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::atomic_write::write_atomic;
+use crate::config_store::config_dir;
+use crate::error::{Error, Result};
+use crate::models::{iso8601, DocMeta, ItemIndexEntry, RequestLogDoc, RequestLogItem};
+
+/// Bump when the on-disk layout changes; older files are rebuilt.
+const INDEX_VERSION: u32 = 1;
+
+/// Default index location inside the config directory.
+pub fn default_index_file() -> PathBuf {
+    config_dir().join("doc-index.json")
+}
+
+/// Item fields kept in the index (everything except `notes`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedItem {
+    pub id: String,
+    pub title: String,
+    #[serde(rename = "type")]
+    pub item_type: String,
+    pub domain: String,
+    pub context: String,
+    pub priority: String,
+    pub status: String,
+    pub tags: Vec<String>,
+    #[serde(with = "iso8601")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&RequestLogItem> for IndexedItem {
+    fn from(item: &RequestLogItem) -> Self {
+        Self {
+            id: item.id.clone(),
+            title: item.title.clone(),
+            item_type: item.item_type.clone(),
+            domain: item.domain.clone(),
+            context: item.context.clone(),
+            priority: item.priority.clone(),
+            status: item.status.clone(),
+            tags: item.tags.clone(),
+            created_at: item.created_at,
+        }
+    }
+}
+
+/// Cached summary of one request-log file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedDoc {
+    /// File modification time (nanoseconds since the Unix epoch)
+    pub mtime_ns: u64,
+    /// File size in bytes
+    pub size: u64,
+    pub meta: DocMeta,
+    pub project_id: String,
+    pub tags: Vec<String>,
+    pub items_index: Vec<ItemIndexEntry>,
+    pub items: Vec<IndexedItem>,
+}
+
+impl IndexedDoc {
+    fn new(path: &Path, stamp: FileStamp, doc: RequestLogDoc) -> Self {
+        Self {
+            mtime_ns: stamp.mtime_ns,
+            size: stamp.size,
+            project_id: doc.project_id.clone(),
+            tags: doc.tags.clone(),
+            items_index: doc.items_index.clone(),
+            items: doc.items.iter().map(IndexedItem::from).collect(),
+            meta: DocMeta {
+                path: path.display().to_string(),
+                doc_id: doc.doc_id,
+                title: doc.title,
+                item_count: doc.item_count,
+                updated_at: doc.updated_at,
+            },
+        }
+    }
+
+    fn is_fresh(&self, stamp: FileStamp) -> bool {
+        self.mtime_ns == stamp.mtime_ns && self.size == stamp.size
+    }
+}
+
+/// On-disk layout of the index file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct IndexFile {
+    version: u32,
+    docs: Vec<IndexedDoc>,
+}
+
+/// Cache hit/miss counts for one directory refresh.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RefreshStats {
+    /// Entries served from the cache
+    pub hits: usize,
+    /// Files parsed because they were new or changed
+    pub misses: usize,
+    /// Cached entries dropped because the file disappeared
+    pub removed: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileStamp {
+    mtime_ns: u64,
+    size: u64,
+}
+
+impl FileStamp {
+    fn of(metadata: &fs::Metadata) -> Self {
+        let mtime_ns = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |age| age.as_nanos() as u64);
+        Self {
+            mtime_ns,
+            size: metadata.len(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct IndexState {
+    docs: HashMap<PathBuf, IndexedDoc>,
+    dirty: bool,
+}
+
+/// Persistent, incrementally revalidated document index.
+#[derive(Debug)]
+pub struct DocIndex {
+    file: PathBuf,
+    state: Mutex<IndexState>,
+}
+
+impl DocIndex {
+    /// Opens the index at `file`, starting empty if it is missing or unusable.
+    pub fn open(file: PathBuf) -> Self {
+        let docs = match fs::read_to_string(&file) {
+            Ok(content) => match serde_json::from_str::<IndexFile>(&content) {
+                Ok(index) if index.version == INDEX_VERSION => index.docs,
+                Ok(index) => {
+                    log::info!(
+                        "Rebuilding document index (version {} != {INDEX_VERSION})",
+                        index.version
+                    );
+                    Vec::new()
+                }
+                Err(error) => {
+                    log::warn!(
+                        "Discarding corrupt document index {}: {error}",
+                        file.display()
+                    );
+                    Vec::new()
+                }
+            },
+            Err(_) => Vec::new(),
+        };
+
+        let docs = docs
+            .into_iter()
+            .map(|doc| (PathBuf::from(&doc.meta.path), doc))
+            .collect();
+        Self {
+            file,
+            state: Mutex::new(IndexState { docs, dirty: false }),
+        }
+    }
+
+    /// Lists `.md` request-logs in `dir`, parsing only new or changed files.
+    ///
+    /// `parse` is called for cache misses; files it rejects are skipped
+    /// like in an unindexed listing. The index is saved if anything changed.
+    pub fn list<F>(&self, dir: &Path, parse: F) -> Result<Vec<DocMeta>>
+    where
+        F: Fn(&Path) -> Result<RequestLogDoc>,
+    {
+        let (mut metas, stats) = self.refresh_dir(dir, parse)?;
+        if stats.misses > 0 || stats.removed > 0 {
+            log::debug!(
+                "Document index {}: {} cached, {} parsed, {} removed",
+                dir.display(),
+                stats.hits,
+                stats.misses,
+                stats.removed
+            );
+        }
+        self.flush()?;
+
+        // Most recently updated first
+        metas.sort_by_key(|meta| std::cmp::Reverse(meta.updated_at));
+        Ok(metas)
+    }
+
+    /// Returns cached entries for documents directly inside `dir`.
+    ///
+    /// Call [`DocIndex::list`] first to revalidate the directory.
+    pub fn entries(&self, dir: &Path) -> Vec<IndexedDoc> {
+        self.state()
+            .docs
+            .iter()
+            .filter(|(path, _)| path.parent() == Some(dir))
+            .map(|(_, doc)| doc.clone())
+            .collect()
+    }
+
+    /// Records a document just written at `path` so the next listing is a hit.
+    pub fn record(&self, path: &Path, doc: &RequestLogDoc) {
+        let Ok(metadata) = fs::metadata(path) else {
+            return;
+        };
+        let entry = IndexedDoc::new(path, FileStamp::of(&metadata), doc.clone());
+        let mut state = self.state();
+        state.docs.insert(path.to_path_buf(), entry);
+        state.dirty = true;
+    }
+
+    /// Saves the index if it changed since the last save.
+    pub fn flush(&self) -> Result<()> {
+        let docs = {
+            let mut state = self.state();
+            if !state.dirty {
+                return Ok(());
+            }
+            state.dirty = false;
+            state.docs.values().cloned().collect()
+        };
+
+        let index = IndexFile {
+            version: INDEX_VERSION,
+            docs,
+        };
+        let json = serde_json::to_vec(&index)
+            .map_err(Error::json("Failed to serialize document index"))?;
+        if let Some(dir) = self.file.parent() {
+            fs::create_dir_all(dir).map_err(Error::io(format!(
+                "Failed to create directory {}",
+                dir.display()
+            )))?;
+        }
+        write_atomic(&self.file, json).map_err(Error::io(format!(
+            "Failed to write document index {}",
+            self.file.display()
+        )))
+    }
+
+    fn state(&self) -> MutexGuard<'_, IndexState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn refresh_dir<F>(&self, dir: &Path, parse: F) -> Result<(Vec<DocMeta>, RefreshStats)>
+    where
+        F: Fn(&Path) -> Result<RequestLogDoc>,
+    {
+        let mut stats = RefreshStats::default();
+        let mut seen: Vec<(PathBuf, FileStamp)> = Vec::new();
+
+        if dir.exists() {
+            let entries = fs::read_dir(dir).map_err(Error::io(format!(
+                "Failed to list documents in {}",
+                dir.display()
+            )))?;
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+                if !metadata.is_file() || path.extension().is_none_or(|ext| ext != "md") {
+                    continue;
+                }
+                seen.push((path, FileStamp::of(&metadata)));
+            }
+        } else {
+            log::debug!("Directory does not exist: {}", dir.display());
+        }
+
+        let mut state = self.state();
+        let mut metas = Vec::with_capacity(seen.len());
+
+        for (path, stamp) in &seen {
+            if let Some(cached) = state.docs.get(path).filter(|doc| doc.is_fresh(*stamp)) {
+                stats.hits += 1;
+                metas.push(cached.meta.clone());
+                continue;
+            }
+
+            stats.misses += 1;
+            state.dirty = true;
+            // Skip files that fail to parse (not request-log format)
+            match parse(path) {
+                Ok(doc) => {
+                    let entry = IndexedDoc::new(path, *stamp, doc);
+                    metas.push(entry.meta.clone());
+                    state.docs.insert(path.clone(), entry);
+                }
+                Err(error) => {
+                    log::warn!("Skipping file - {error}");
+                    state.docs.remove(path);
+                }
+            }
+        }
+
+        // Drop entries for files deleted since the last refresh
+        let present: HashSet<&PathBuf> = seen.iter().map(|(path, _)| path).collect();
+        let before = state.docs.len();
+        state
+            .docs
+            .retain(|path, _| path.parent() != Some(dir) || present.contains(path));
+        stats.removed = before - state.docs.len();
+        if stats.removed > 0 {
+            state.dirty = true;
+        }
+
+        Ok((metas, stats))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    use crate::doc_store::FsDocStore;
+    use crate::models::SCHEMA_VERSION;
+    use crate::ports::DocStore;
+
+    fn doc(doc_id: &str, hour: u32) -> RequestLogDoc {
+        let at = Utc.with_ymd_and_hms(2025, 12, 3, hour, 0, 0).unwrap();
+        RequestLogDoc {
+            schema_version: SCHEMA_VERSION,
+            doc_id: doc_id.to_string(),
+            title: "App Log".to_string(),
+            project_id: "app".to_string(),
+            items: vec![],
+            items_index: vec![],
+            tags: vec![],
+            item_count: 0,
+            created_at: at,
+            updated_at: at,
+            extra_frontmatter: vec![],
+            preamble: String::new(),
+        }
+    }
+
+    fn parse_with(store: &FsDocStore) -> impl Fn(&Path) -> Result<RequestLogDoc> + '_ {
+        move |path| store.read(path.to_str().unwrap())
+    }
+
+    #[test]
+    fn reparses_only_changed_files() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = root.path().join("docs");
+        let store = FsDocStore::new();
+        for (n, name) in ["a", "b", "c"].iter().enumerate() {
+            let path = dir.join(format!("{name}.md"));
+            store
+                .write(
+                    path.to_str().unwrap(),
+                    &doc(&format!("REQ-2025120{}-app", n + 1), 9),
+                )
+                .unwrap();
+        }
+
+        let index = DocIndex::open(root.path().join("doc-index.json"));
+        let (_, cold) = index.refresh_dir(&dir, parse_with(&store)).unwrap();
+        assert_eq!(cold.misses, 3);
+
+        store
+            .write(
+                dir.join("b.md").to_str().unwrap(),
+                &doc("REQ-20251202-app", 12),
+            )
+            .unwrap();
+        fs::remove_file(dir.join("c.md")).unwrap();
+
+        let (metas, warm) = index.refresh_dir(&dir, parse_with(&store)).unwrap();
+        assert_eq!(
+            warm,
+            RefreshStats {
+                hits: 1,
+                misses: 1,
+                removed: 1
+            }
+        );
+        assert_eq!(metas.len(), 2);
+        assert_eq!(index.entries(&dir).len(), 2);
+    }
+
+    #[test]
+    fn persists_across_opens_and_survives_corruption() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = root.path().join("docs");
+        let file = root.path().join("doc-index.json");
+        let store = FsDocStore::new();
+        store
+            .write(
+                dir.join("a.md").to_str().unwrap(),
+                &doc("REQ-20251203-app", 9),
+            )
+            .unwrap();
+
+        let listed = DocIndex::open(file.clone())
+            .list(&dir, parse_with(&store))
+            .unwrap();
+
+        let reopened = DocIndex::open(file.clone());
+        let (metas, stats) = reopened.refresh_dir(&dir, parse_with(&store)).unwrap();
+        assert_eq!(stats.hits, 1);
+        assert_eq!(metas, listed);
+
+        fs::write(&file, "{ not json").unwrap();
+        let (_, stats) = DocIndex::open(file)
+            .refresh_dir(&dir, parse_with(&store))
+            .unwrap();
+        assert_eq!(stats.misses, 1);
+    }
+}
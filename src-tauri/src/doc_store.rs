@@ -0,0 +1,958 @@
+//! File System Document Store
+//!
+//! Native implementation of the DocStore port:
+//! - Read/write request-log markdown files (atomic temp-file + rename)
+//! - Backup creation (.bak files, plus versioned snapshots when a
+//!   BackupHistory is attached) with list, diff and restore
+//! - Schema migrations with a backup of the original file
+//! - Cross-process locking around read-modify-write
+//! - Git auto-commit of each write (`git` feature, see [`crate::git`])
+//! - Directory listing and metadata (optionally served from a DocIndex)
+//! - Tilde expansion matching the TS `expandPath`
+//! - Daily-document capture shared by MCP and deep links
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{Local, Utc};
+
+use crate::atomic_write::write_atomic;
+use crate::backups::{BackupEntry, BackupHistory, Retention};
+use crate::diff::unified_diff;
+use crate::doc_index::DocIndex;
+use crate::error::{Error, Result};
+#[cfg(feature = "git")]
+use crate::git::GitAutoCommit;
+use crate::ids::{generate_doc_id, generate_item_id, get_next_item_number};
+use crate::lock::{DocLock, DEFAULT_LOCK_TIMEOUT};
+use crate::migrate::{upgrade, DocMigration};
+use crate::models::{DocMeta, ItemDraft, Project, RequestLogDoc, RequestLogItem, SCHEMA_VERSION};
+use crate::ports::{Clock, DocStore, ProjectStore};
+use crate::search::SearchIndex;
+use crate::serializer::{aggregate_tags, parse, serialize, update_items_index};
+
+/// Gets the base directory for tilde expansion.
+///
+/// In server/Docker mode (`MEATYCAPTURE_DATA_DIR` set), uses the data
+/// directory. Otherwise uses the user's home directory.
+pub fn base_dir() -> PathBuf {
+    std::env::var_os("MEATYCAPTURE_DATA_DIR")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(dirs::home_dir)
+        .unwrap_or_default()
+}
+
+/// Expands a leading `~` or `~/` to the base directory.
+pub fn expand_path(path: &str) -> PathBuf {
+    if path == "~" {
+        return base_dir();
+    }
+    match path.strip_prefix("~/") {
+        Some(rest) => base_dir().join(rest),
+        None => PathBuf::from(path),
+    }
+}
+
+/// Expanded `default_path` of every enabled project.
+pub fn enabled_project_dirs(projects: &dyn ProjectStore) -> Result<Vec<PathBuf>> {
+    Ok(projects
+        .list()?
+        .into_iter()
+        .filter(|project| project.enabled)
+        .map(|project| expand_path(&project.default_path))
+        .collect())
+}
+
+/// Result of `capture_to_daily_doc`.
+#[derive(Debug, Clone)]
+pub struct DailyCapture {
+    /// Document after the capture
+    pub doc: RequestLogDoc,
+    /// Where it was written
+    pub path: String,
+    /// True if this capture created the document
+    pub created: bool,
+}
+
+/// Appends `draft` to the project's document for today
+/// (`<default_path>/<doc_id>.md`), creating the document on first capture.
+///
+/// Uses [`FsDocStore::append_or_create`], so concurrent first captures of
+/// the day (e.g. the CLI and a deep link) end up in one document.
+pub fn capture_to_daily_doc(
+    docs: &FsDocStore,
+    project: &Project,
+    draft: ItemDraft,
+    clock: &dyn Clock,
+) -> Result<DailyCapture> {
+    let now = clock.now();
+    let doc_id = generate_doc_id(&project.id, now.with_timezone(&Local).date_naive())?;
+    let path = expand_path(&project.default_path).join(format!("{doc_id}.md"));
+    let path = path.display().to_string();
+
+    let (doc, created) = docs.append_or_create(&path, draft, clock, || RequestLogDoc {
+        schema_version: SCHEMA_VERSION,
+        title: format!("Request Log - {}", project.id),
+        doc_id,
+        project_id: project.id.clone(),
+        items: vec![],
+        items_index: vec![],
+        tags: vec![],
+        item_count: 0,
+        created_at: now,
+        updated_at: now,
+        extra_frontmatter: vec![],
+        preamble: String::new(),
+    })?;
+    Ok(DailyCapture { doc, path, created })
+}
+
+/// Builds the listing metadata for a parsed document at `path`.
+fn doc_meta(path: &Path, doc: RequestLogDoc) -> DocMeta {
+    DocMeta {
+        path: path.display().to_string(),
+        doc_id: doc.doc_id,
+        title: doc.title,
+        item_count: doc.item_count,
+        updated_at: doc.updated_at,
+    }
+}
+
+/// Auto-commit message for newly captured items.
+///
+/// `capture: <first item ID> <title>`, with `(+N more)` for batches.
+fn capture_message(items: &[RequestLogItem]) -> String {
+    match items {
+        [] => "capture: no items".to_string(),
+        [item] => format!("capture: {} {}", item.id, item.title),
+        [item, rest @ ..] => format!("capture: {} {} (+{} more)", item.id, item.title, rest.len()),
+    }
+}
+
+/// Local filesystem implementation of DocStore.
+///
+/// `write` and `append` hold an exclusive [`DocLock`] on the document so
+/// concurrent writers (desktop, CLI, server) never interleave.
+#[derive(Debug, Clone)]
+pub struct FsDocStore {
+    lock_timeout: Duration,
+    backups: bool,
+    history: Option<Arc<BackupHistory>>,
+    #[cfg(feature = "git")]
+    git: Option<Arc<GitAutoCommit>>,
+    index: Option<Arc<DocIndex>>,
+    search: Option<Arc<SearchIndex>>,
+}
+
+impl Default for FsDocStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FsDocStore {
+    /// Creates a new filesystem document store with the default lock timeout.
+    pub fn new() -> Self {
+        Self::with_lock_timeout(DEFAULT_LOCK_TIMEOUT)
+    }
+
+    /// Creates a store that waits up to `lock_timeout` for a document lock
+    /// before failing with [`Error::Locked`].
+    pub fn with_lock_timeout(lock_timeout: Duration) -> Self {
+        Self {
+            lock_timeout,
+            backups: true,
+            history: None,
+            #[cfg(feature = "git")]
+            git: None,
+            index: None,
+            search: None,
+        }
+    }
+
+    /// Skips the `.bak` copy normally made before overwriting a document.
+    pub fn without_backups(mut self) -> Self {
+        self.backups = false;
+        self
+    }
+
+    /// Also snapshots every backup into `history`.
+    pub fn with_history(mut self, history: Arc<BackupHistory>) -> Self {
+        self.history = Some(history);
+        self
+    }
+
+    /// Commits each write with `git` when the document's project opted in.
+    #[cfg(feature = "git")]
+    pub fn with_git(mut self, git: Arc<GitAutoCommit>) -> Self {
+        self.git = Some(git);
+        self
+    }
+
+    /// Updates the retention of the attached backup history, if any.
+    pub fn set_backup_retention(&self, retention: Retention) {
+        if let Some(history) = &self.history {
+            history.set_retention(retention);
+        }
+    }
+
+    /// Serves `list` from `index` and keeps it updated on writes.
+    pub fn with_index(mut self, index: Arc<DocIndex>) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Re-indexes documents in `search` whenever they are written.
+    pub fn with_search(mut self, search: Arc<SearchIndex>) -> Self {
+        self.search = Some(search);
+        self
+    }
+
+    /// Appends several items in a single locked read-modify-write.
+    ///
+    /// Items are numbered consecutively after the highest existing ID and
+    /// share one timestamp; the document is written (and backed up) once.
+    pub fn append_all(
+        &self,
+        path: &str,
+        items: Vec<ItemDraft>,
+        clock: &dyn Clock,
+    ) -> Result<RequestLogDoc> {
+        let path = expand_path(path);
+        // Held across read and write so the next item number stays unique
+        let _lock = self.lock(&path)?;
+        let doc = self.read_at(&path)?;
+        self.append_locked(&path, doc, items, clock)
+    }
+
+    /// Appends `item` to the document at `path`, or creates the document
+    /// from `new_doc` (with `item` as its first item) if it does not exist.
+    ///
+    /// The lock is taken before checking whether the file exists, so two
+    /// processes capturing into a missing document cannot both create it
+    /// and lose one item. Returns the document and whether it was created.
+    pub fn append_or_create(
+        &self,
+        path: &str,
+        item: ItemDraft,
+        clock: &dyn Clock,
+        new_doc: impl FnOnce() -> RequestLogDoc,
+    ) -> Result<(RequestLogDoc, bool)> {
+        let path = expand_path(path);
+        let _lock = self.lock(&path)?;
+        let created = !path.exists();
+        let doc = if created {
+            new_doc()
+        } else {
+            self.read_at(&path)?
+        };
+        let doc = self.append_locked(&path, doc, vec![item], clock)?;
+        Ok((doc, created))
+    }
+
+    /// Adds `items` to `doc` and writes it; the caller holds the lock.
+    fn append_locked(
+        &self,
+        path: &Path,
+        mut doc: RequestLogDoc,
+        items: Vec<ItemDraft>,
+        clock: &dyn Clock,
+    ) -> Result<RequestLogDoc> {
+        let first_number = get_next_item_number(doc.items.iter().map(|i| i.id.as_str()));
+        let existing = doc.items.len();
+        let now = clock.now();
+        for (number, item) in (first_number..).zip(items) {
+            let item_id = generate_item_id(&doc.doc_id, number)?;
+            doc.items
+                .push(RequestLogItem::from_draft(item, item_id, now));
+        }
+        doc.tags = aggregate_tags(&doc.items);
+        doc.items_index = update_items_index(&doc.items);
+        doc.item_count = doc.items.len() as u64;
+        doc.updated_at = now;
+
+        let message = capture_message(&doc.items[existing..]);
+        self.write_at(path, &doc, message)?;
+        Ok(doc)
+    }
+
+    /// Changes one item in a single locked read-modify-write.
+    ///
+    /// Tags, index and `updated_at` are refreshed after `update` runs.
+    /// Fails with [`Error::NotFound`] if the document has no such item.
+    pub fn update_item(
+        &self,
+        path: &str,
+        item_id: &str,
+        clock: &dyn Clock,
+        update: impl FnOnce(&mut RequestLogItem),
+    ) -> Result<RequestLogDoc> {
+        let path = expand_path(path);
+        let _lock = self.lock(&path)?;
+        let mut doc = self.read_at(&path)?;
+
+        let item = doc
+            .items
+            .iter_mut()
+            .find(|item| item.id == item_id)
+            .ok_or_else(|| {
+                Error::NotFound(format!("Item {item_id} not found in {}", path.display()))
+            })?;
+        update(item);
+        let message = format!("update: {item_id} {}", item.title);
+        doc.tags = aggregate_tags(&doc.items);
+        doc.items_index = update_items_index(&doc.items);
+        doc.updated_at = clock.now();
+
+        self.write_at(&path, &doc, message)?;
+        Ok(doc)
+    }
+
+    /// Upgrades a document to [`SCHEMA_VERSION`] in a single locked
+    /// read-modify-write.
+    ///
+    /// The original is always backed up first, even when backups are
+    /// disabled for normal writes. With `dry_run` only the diff is computed.
+    /// Returns `None` when the document is already current.
+    pub fn migrate(&self, path: &str, dry_run: bool) -> Result<Option<DocMigration>> {
+        let path = expand_path(path);
+        let _lock = self.lock(&path)?;
+        let content = fs::read_to_string(&path).map_err(Error::io(format!(
+            "Failed to read document {}",
+            path.display()
+        )))?;
+
+        let upgrade = upgrade(&path, &content)?;
+        if upgrade.applied.is_empty() {
+            return Ok(None);
+        }
+        let doc = parse(&upgrade.content).map_err(|source| Error::Parse {
+            path: path.display().to_string(),
+            source,
+        })?;
+
+        let label = path.display().to_string();
+        let mut migration = DocMigration {
+            path: label.clone(),
+            from_version: upgrade.from_version,
+            to_version: SCHEMA_VERSION,
+            applied: upgrade.applied.iter().map(|s| s.to_string()).collect(),
+            diff: unified_diff(&content, &upgrade.content, &label, &label),
+            backup: None,
+        };
+        if !dry_run {
+            migration.backup = Some(self.backup_at(&path)?.display().to_string());
+            let message = format!("migrate: {} to schema_version {SCHEMA_VERSION}", doc.doc_id);
+            self.store_content(&path, &doc, serialize(&doc), message)?;
+            log::info!(
+                "Migrated {} from schema_version {} to {}",
+                path.display(),
+                upgrade.from_version,
+                SCHEMA_VERSION
+            );
+        }
+        Ok(Some(migration))
+    }
+
+    /// Backup snapshots of a document, newest first.
+    pub fn list_backups(&self, path: &str) -> Result<Vec<BackupEntry>> {
+        self.history()?.list(&expand_path(path))
+    }
+
+    /// Unified diff from a backup snapshot to the current document (empty
+    /// when the document no longer exists).
+    pub fn diff_backup(&self, path: &str, backup_id: &str) -> Result<String> {
+        let path = expand_path(path);
+        let (backup, old) = self.history()?.read(&path, backup_id)?;
+        let current = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(error) => {
+                return Err(Error::io(format!(
+                    "Failed to read document {}",
+                    path.display()
+                ))(error))
+            }
+        };
+        Ok(unified_diff(
+            &old,
+            &current,
+            &backup.path,
+            &path.display().to_string(),
+        ))
+    }
+
+    /// Replaces a document with a backup snapshot in a single locked write.
+    ///
+    /// The current content is snapshotted first, so a restore can itself be
+    /// undone. The snapshot must parse; it is written back byte for byte.
+    pub fn restore_backup(&self, path: &str, backup_id: &str) -> Result<RequestLogDoc> {
+        let history = self.history()?;
+        let path = expand_path(path);
+        let _lock = self.lock(&path)?;
+        let (backup, content) = history.read(&path, backup_id)?;
+        let doc = parse(&content).map_err(|source| Error::Parse {
+            path: backup.path.clone(),
+            source,
+        })?;
+
+        if path.exists() {
+            self.backup_at(&path)?;
+        }
+        let message = format!("restore: {} from backup {backup_id}", doc.doc_id);
+        self.store_content(&path, &doc, content, message)?;
+        log::info!("Restored {} from backup {backup_id}", path.display());
+        Ok(doc)
+    }
+
+    /// Reads a document and returns its listing metadata.
+    pub fn read_meta(&self, path: &Path) -> Result<DocMeta> {
+        self.read_at(path).map(|doc| doc_meta(path, doc))
+    }
+
+    fn history(&self) -> Result<&BackupHistory> {
+        self.history
+            .as_deref()
+            .ok_or_else(|| Error::Validation("Backup history is not enabled".to_string()))
+    }
+
+    fn lock(&self, path: &Path) -> Result<DocLock> {
+        DocLock::acquire(path, self.lock_timeout)
+    }
+
+    fn read_at(&self, path: &Path) -> Result<RequestLogDoc> {
+        let content = fs::read_to_string(path).map_err(Error::io(format!(
+            "Failed to read document {}",
+            path.display()
+        )))?;
+
+        parse(&content).map_err(|source| Error::Parse {
+            path: path.display().to_string(),
+            source,
+        })
+    }
+
+    fn write_at(&self, path: &Path, doc: &RequestLogDoc, message: String) -> Result<()> {
+        // This build would drop whatever a newer format added
+        if doc.schema_version > SCHEMA_VERSION {
+            return Err(Error::Validation(format!(
+                "{} has schema_version {}, newer than the supported {SCHEMA_VERSION}; \
+                 update MeatyCapture to edit it",
+                path.display(),
+                doc.schema_version
+            )));
+        }
+        if self.backups && path.exists() {
+            self.backup_at(path)?;
+        }
+        self.store_content(path, doc, serialize(doc), message)
+    }
+
+    /// Writes `content`, the text of `doc`, refreshes the indexes and
+    /// auto-commits with `message`; no backup is made.
+    #[cfg_attr(not(feature = "git"), allow(unused_variables))]
+    fn store_content(
+        &self,
+        path: &Path,
+        doc: &RequestLogDoc,
+        content: String,
+        message: String,
+    ) -> Result<()> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.exists()) {
+            fs::create_dir_all(dir).map_err(Error::io(format!(
+                "Failed to create directory {}",
+                dir.display()
+            )))?;
+            log::debug!("Created parent directory {}", dir.display());
+        }
+
+        write_atomic(path, content).map_err(Error::io(format!(
+            "Failed to write document {}",
+            path.display()
+        )))?;
+        if let Some(index) = &self.index {
+            index.record(path, doc);
+        }
+        // The file is the source of truth; a stale search entry is repaired
+        // by the next reconcile, so indexing failures do not fail the write
+        if let Some(search) = &self.search {
+            if let Err(error) = search.index_document(path, doc) {
+                log::warn!("Failed to update search index - {error}");
+            }
+        }
+        // Likewise a skipped or failed commit is reported, not returned
+        #[cfg(feature = "git")]
+        if let Some(git) = &self.git {
+            git.document_written(path, &doc.project_id, &message);
+        }
+
+        log::info!(
+            "Document written: {} ({}, {} items)",
+            path.display(),
+            doc.doc_id,
+            doc.item_count
+        );
+        Ok(())
+    }
+
+    fn backup_at(&self, path: &Path) -> Result<PathBuf> {
+        let mut backup = path.as_os_str().to_owned();
+        backup.push(".bak");
+        let backup = PathBuf::from(backup);
+
+        let contents = fs::read(path).map_err(Error::io(format!(
+            "Failed to create backup of {}",
+            path.display()
+        )))?;
+        write_atomic(&backup, &contents).map_err(Error::io(format!(
+            "Failed to create backup of {}",
+            path.display()
+        )))?;
+        if let Some(history) = &self.history {
+            history.snapshot(path, &contents, Utc::now())?;
+        }
+
+        Ok(backup)
+    }
+}
+
+impl DocStore for FsDocStore {
+    fn list(&self, directory: &str) -> Result<Vec<DocMeta>> {
+        let dir = expand_path(directory);
+        if let Some(index) = &self.index {
+            return index.list(&dir, |path| self.read_at(path));
+        }
+        if !dir.exists() {
+            log::debug!("Directory does not exist: {}", dir.display());
+            return Ok(Vec::new());
+        }
+
+        let entries = fs::read_dir(&dir).map_err(Error::io(format!(
+            "Failed to list documents in {directory}"
+        )))?;
+
+        let mut metas = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let is_file = entry.file_type().is_ok_and(|t| t.is_file());
+            if !is_file || path.extension().is_none_or(|ext| ext != "md") {
+                continue;
+            }
+
+            // Skip files that fail to parse (not request-log format)
+            match self.read_meta(&path) {
+                Ok(meta) => metas.push(meta),
+                Err(error) => log::warn!("Skipping file - {error}"),
+            }
+        }
+
+        // Most recently updated first
+        metas.sort_by_key(|meta| std::cmp::Reverse(meta.updated_at));
+        Ok(metas)
+    }
+
+    fn read(&self, path: &str) -> Result<RequestLogDoc> {
+        self.read_at(&expand_path(path))
+    }
+
+    fn write(&self, path: &str, doc: &RequestLogDoc) -> Result<()> {
+        let path = expand_path(path);
+        let _lock = self.lock(&path)?;
+        let verb = if path.exists() { "update" } else { "create" };
+        let message = format!("{verb}: {} {}", doc.doc_id, doc.title);
+        self.write_at(&path, doc, message)
+    }
+
+    fn append(&self, path: &str, item: ItemDraft, clock: &dyn Clock) -> Result<RequestLogDoc> {
+        self.append_all(path, vec![item], clock)
+    }
+
+    fn backup(&self, path: &str) -> Result<String> {
+        let path = expand_path(path);
+        if !path.exists() {
+            return Err(Error::NotFound(format!(
+                "Failed to create backup of {}: Source file does not exist",
+                path.display()
+            )));
+        }
+        self.backup_at(&path).map(|p| p.display().to_string())
+    }
+
+    fn is_writable(&self, path: &str) -> bool {
+        let path = expand_path(path);
+
+        if path.exists() {
+            return fs::OpenOptions::new().append(true).open(&path).is_ok();
+        }
+
+        // New file: writable if the parent exists and is writable, or if the
+        // parent can be created inside an existing grandparent
+        match path.parent() {
+            Some(dir) if dir.is_dir() => {
+                fs::metadata(dir).is_ok_and(|m| !m.permissions().readonly())
+            }
+            Some(dir) => dir.parent().is_some_and(Path::is_dir),
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    struct FixedClock;
+
+    impl Clock for FixedClock {
+        fn now(&self) -> chrono::DateTime<Utc> {
+            Utc.with_ymd_and_hms(2025, 12, 4, 9, 0, 0).unwrap()
+        }
+    }
+
+    fn empty_doc() -> RequestLogDoc {
+        let created = Utc.with_ymd_and_hms(2025, 12, 3, 10, 0, 0).unwrap();
+        RequestLogDoc {
+            schema_version: SCHEMA_VERSION,
+            doc_id: "REQ-20251203-app".to_string(),
+            title: "App Log".to_string(),
+            project_id: "app".to_string(),
+            items: vec![],
+            items_index: vec![],
+            tags: vec![],
+            item_count: 0,
+            created_at: created,
+            updated_at: created,
+            extra_frontmatter: vec![],
+            preamble: String::new(),
+        }
+    }
+
+    fn draft(title: &str, tags: &[&str]) -> ItemDraft {
+        ItemDraft {
+            title: title.to_string(),
+            item_type: "bug".to_string(),
+            domain: "web".to_string(),
+            context: "ctx".to_string(),
+            priority: "high".to_string(),
+            status: "triage".to_string(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            notes: "notes".to_string(),
+        }
+    }
+
+    #[test]
+    fn append_assigns_ids_and_aggregates() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("REQ-20251203-app.md");
+        let path = path.to_str().unwrap();
+        let store = FsDocStore::new();
+
+        store.write(path, &empty_doc()).unwrap();
+        store
+            .append(path, draft("One", &["ux"]), &FixedClock)
+            .unwrap();
+        let doc = store
+            .append(path, draft("Two", &["api", "ux"]), &FixedClock)
+            .unwrap();
+
+        assert_eq!(doc.item_count, 2);
+        assert_eq!(doc.items[1].id, "REQ-20251203-app-02");
+        assert_eq!(doc.tags, vec!["api", "ux"]);
+        assert_eq!(doc.updated_at, FixedClock.now());
+        let reread = store.read(path).unwrap();
+        assert_eq!(reread.items_index, doc.items_index);
+        assert_eq!(reread.tags, doc.tags);
+        assert!(Path::new(&format!("{path}.bak")).exists());
+    }
+
+    #[test]
+    fn append_only_touches_new_item_and_summary_fields() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("REQ-20251203-app.md");
+        let original = "---
+type: request-log
+doc_id: REQ-20251203-app
+title: App Log
+project_id: app
+item_count: 1
+tags: [api]
+items_index:
+  - id: REQ-20251203-app-01
+    type: bug
+    title: One
+created_at: 2025-12-03T10:00:00.000Z
+updated_at: 2025-12-03T10:00:00.000Z
+owner: alice
+---
+
+Triage notes kept by hand.
+
+## REQ-20251203-app-01 - One
+
+**Type:** bug | **Domain:** api | **Priority:** low | **Status:** backlog
+**Tags:** api
+**Context:** 
+
+**Estimate:** 2d
+
+### Problem/Goal
+Existing notes
+
+---
+
+## REQ-draft - Half-written item
+
+Still being drafted.
+";
+        fs::write(&path, original).unwrap();
+
+        FsDocStore::new()
+            .without_backups()
+            .append(path.to_str().unwrap(), draft("Two", &["ux"]), &FixedClock)
+            .unwrap();
+
+        let expected = original
+            .replace("item_count: 1", "item_count: 2")
+            .replace("tags: [api]", "tags: [api, ux]")
+            .replace(
+                "    title: One\n",
+                "    title: One\n  - id: REQ-20251203-app-02\n    type: bug\n    title: Two\n",
+            )
+            .replace(
+                "updated_at: 2025-12-03T10:00:00.000Z",
+                "updated_at: 2025-12-04T09:00:00.000Z",
+            )
+            + "
+---
+
+## REQ-20251203-app-02 - Two
+
+**Type:** bug | **Domain:** web | **Priority:** high | **Status:** triage
+**Tags:** ux
+**Context:** ctx
+
+### Problem/Goal
+notes
+";
+        assert_eq!(fs::read_to_string(&path).unwrap(), expected);
+    }
+
+    #[test]
+    fn list_skips_non_request_logs() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+        let store = FsDocStore::new();
+        let mut newer = empty_doc();
+        newer.doc_id = "REQ-20251204-app".to_string();
+        newer.updated_at = FixedClock.now();
+
+        store
+            .write(dir.join("a.md").to_str().unwrap(), &empty_doc())
+            .unwrap();
+        store
+            .write(dir.join("b.md").to_str().unwrap(), &newer)
+            .unwrap();
+        fs::write(dir.join("notes.md"), "# Not a request log").unwrap();
+        fs::write(dir.join("c.txt"), "ignored").unwrap();
+
+        let metas = store.list(dir.to_str().unwrap()).unwrap();
+
+        let ids: Vec<_> = metas.iter().map(|m| m.doc_id.as_str()).collect();
+        assert_eq!(ids, vec!["REQ-20251204-app", "REQ-20251203-app"]);
+        assert!(store
+            .list(dir.join("missing").to_str().unwrap())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn concurrent_appends_are_serialized() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("REQ-20251203-app.md");
+        let path = path.to_str().unwrap().to_string();
+        FsDocStore::new().write(&path, &empty_doc()).unwrap();
+
+        let handles: Vec<_> = (0..8)
+            .map(|n| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    // Separate store per thread, like separate processes
+                    FsDocStore::with_lock_timeout(Duration::from_secs(10))
+                        .append(&path, draft(&format!("Item {n}"), &[]), &FixedClock)
+                        .unwrap();
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let doc = FsDocStore::new().read(&path).unwrap();
+        let ids: std::collections::HashSet<_> = doc.items.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(doc.item_count, 8);
+        assert_eq!(ids.len(), 8);
+    }
+
+    #[test]
+    fn concurrent_first_captures_share_one_document() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = Project {
+            id: "app".to_string(),
+            name: "App".to_string(),
+            default_path: dir.path().display().to_string(),
+            repo_url: None,
+            enabled: true,
+            auto_commit: None,
+            created_at: FixedClock.now(),
+            updated_at: FixedClock.now(),
+        };
+        let start = Arc::new(std::sync::Barrier::new(2));
+
+        let handles: Vec<_> = (0..2)
+            .map(|n| {
+                let (project, start) = (project.clone(), start.clone());
+                std::thread::spawn(move || {
+                    let store = FsDocStore::with_lock_timeout(Duration::from_secs(10));
+                    start.wait();
+                    capture_to_daily_doc(
+                        &store,
+                        &project,
+                        draft(&format!("Item {n}"), &[]),
+                        &FixedClock,
+                    )
+                    .unwrap()
+                })
+            })
+            .collect();
+        let captures: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+        assert_eq!(captures.iter().filter(|c| c.created).count(), 1);
+        assert_eq!(captures[0].path, captures[1].path);
+        let doc = FsDocStore::new().read(&captures[0].path).unwrap();
+        let mut ids: Vec<_> = doc.items.iter().map(|i| i.id.as_str()).collect();
+        ids.sort();
+        assert_eq!(ids, vec!["REQ-20251204-app-01", "REQ-20251204-app-02"]);
+    }
+
+    #[test]
+    fn write_refuses_documents_from_newer_versions() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("REQ-20251203-app.md");
+        let path = path.to_str().unwrap();
+        let store = FsDocStore::new();
+        let newer = RequestLogDoc {
+            schema_version: SCHEMA_VERSION + 1,
+            ..empty_doc()
+        };
+
+        assert!(matches!(
+            store.write(path, &newer),
+            Err(Error::Validation(_))
+        ));
+        assert!(!Path::new(path).exists());
+    }
+
+    #[test]
+    fn capture_messages_name_the_first_item() {
+        let now = FixedClock.now();
+        let items: Vec<_> = ["Add dark mode toggle", "Two", "Three"]
+            .iter()
+            .enumerate()
+            .map(|(i, title)| {
+                let id = format!("REQ-20251203-app-{:02}", i + 3);
+                RequestLogItem::from_draft(draft(title, &[]), id, now)
+            })
+            .collect();
+
+        assert_eq!(
+            capture_message(&items[..1]),
+            "capture: REQ-20251203-app-03 Add dark mode toggle"
+        );
+        assert_eq!(
+            capture_message(&items),
+            "capture: REQ-20251203-app-03 Add dark mode toggle (+2 more)"
+        );
+    }
+
+    #[test]
+    fn history_lists_diffs_and_restores_earlier_versions() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("docs/REQ-20251203-app.md");
+        let path = path.to_str().unwrap();
+        let history = Arc::new(BackupHistory::new(dir.path().join("backups")));
+        let store = FsDocStore::new().with_history(history);
+        assert!(store.list_backups(path).unwrap().is_empty());
+
+        store.write(path, &empty_doc()).unwrap();
+        let original = fs::read_to_string(path).unwrap();
+        store
+            .append(path, draft("First", &["api"]), &FixedClock)
+            .unwrap();
+        store
+            .append(path, draft("Second", &[]), &FixedClock)
+            .unwrap();
+
+        let backups = store.list_backups(path).unwrap();
+        assert_eq!(backups.len(), 2);
+        let oldest = &backups[1].id;
+        let diff = store.diff_backup(path, oldest).unwrap();
+        assert!(diff.contains("\n-item_count: 0\n"));
+        assert!(diff.contains("\n+item_count: 2\n"));
+
+        let restored = store.restore_backup(path, oldest).unwrap();
+        assert!(restored.items.is_empty());
+        assert_eq!(fs::read_to_string(path).unwrap(), original);
+        // The overwritten version is kept, so the restore can be undone
+        assert_eq!(store.list_backups(path).unwrap().len(), 3);
+        assert!(matches!(
+            store.restore_backup(path, "missing"),
+            Err(Error::NotFound(_))
+        ));
+        assert!(matches!(
+            FsDocStore::new().list_backups(path),
+            Err(Error::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn append_fails_fast_when_locked() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("REQ-20251203-app.md");
+        let store = FsDocStore::with_lock_timeout(Duration::ZERO);
+        store.write(path.to_str().unwrap(), &empty_doc()).unwrap();
+
+        let _held = DocLock::acquire(&path, Duration::ZERO).unwrap();
+        let error = store
+            .append(path.to_str().unwrap(), draft("Blocked", &[]), &FixedClock)
+            .unwrap_err();
+
+        assert!(matches!(error, Error::Locked { .. }));
+        assert_eq!(store.read(path.to_str().unwrap()).unwrap().item_count, 0);
+    }
+
+    #[test]
+    fn append_all_numbers_items_and_can_skip_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("REQ-20251203-app.md");
+        let path = path.to_str().unwrap();
+        let store = FsDocStore::new().without_backups();
+        store.write(path, &empty_doc()).unwrap();
+
+        let doc = store
+            .append_all(
+                path,
+                vec![draft("One", &["ux"]), draft("Two", &[])],
+                &FixedClock,
+            )
+            .unwrap();
+
+        let ids: Vec<_> = doc.items.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["REQ-20251203-app-01", "REQ-20251203-app-02"]);
+        assert_eq!(doc.item_count, 2);
+        assert!(!Path::new(&format!("{path}.bak")).exists());
+    }
+}
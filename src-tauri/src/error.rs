@@ -0,0 +1,210 @@
+//! Error Types
+//!
+//! Crate-wide error returned by the native stores and Tauri commands.
+//! Serializes as `{ kind, message }`: `message` is the same human-readable
+//! text the TypeScript adapters throw, and `kind` lets them rethrow it as
+//! the matching typed error (`NotFoundError`, `ConflictError`, ...).
+
+use std::io;
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+
+use crate::ids::IdError;
+use crate::serializer::ParseError;
+
+/// Crate-wide result alias.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors surfaced by native stores and commands.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Filesystem operation failed
+    #[error("{context}: {source}")]
+    Io {
+        /// What was being attempted, including the path
+        context: String,
+        #[source]
+        source: io::Error,
+    },
+    /// JSON config file could not be (de)serialized
+    #[error("{context}: {source}")]
+    Json {
+        /// What was being attempted, including the path
+        context: String,
+        #[source]
+        source: serde_json::Error,
+    },
+    /// Request-log markdown could not be parsed
+    #[error("Failed to parse document {path}: {source}")]
+    Parse {
+        /// Path of the document that failed to parse
+        path: String,
+        #[source]
+        source: ParseError,
+    },
+    /// ID generation failed
+    #[error(transparent)]
+    Id(#[from] IdError),
+    /// Input rejected by a business rule (invalid name, duplicate, ...)
+    #[error("{0}")]
+    Validation(String),
+    /// Requested entity does not exist
+    #[error("{0}")]
+    NotFound(String),
+    /// Filesystem watcher could not be started or configured
+    #[error("{context}: {source}")]
+    Watch {
+        /// What was being attempted, including the path
+        context: String,
+        #[source]
+        source: notify::Error,
+    },
+    /// Full-text search index operation failed
+    #[error("{context}: {source}")]
+    Search {
+        /// What was being attempted
+        context: String,
+        #[source]
+        source: tantivy::TantivyError,
+    },
+    /// Git repository operation failed
+    #[cfg(feature = "git")]
+    #[error("{context}: {source}")]
+    Git {
+        /// What was being attempted, including the repository
+        context: String,
+        #[source]
+        source: git2::Error,
+    },
+    /// Global shortcut binding could not be parsed
+    #[error("Invalid shortcut \"{shortcut}\": {reason}")]
+    InvalidShortcut {
+        /// Binding as entered, e.g. `CommandOrControl+Shift+Space`
+        shortcut: String,
+        /// Parser message
+        reason: String,
+    },
+    /// Global shortcut is taken by another application (or this one)
+    #[error("Shortcut {shortcut} is already in use, choose another combination ({reason})")]
+    ShortcutUnavailable {
+        /// Binding that failed to register
+        shortcut: String,
+        /// OS / plugin message
+        reason: String,
+    },
+    /// Path from the webview rejected before touching the filesystem
+    #[error("Invalid path {path}: {reason}")]
+    InvalidPath {
+        /// Path as requested
+        path: String,
+        /// Why it was rejected
+        reason: String,
+    },
+    /// Path lies outside the data directory and every enabled project
+    #[error("Access denied: {path} is outside the MeatyCapture data directory and enabled project folders")]
+    OutOfScope {
+        /// Path as requested
+        path: String,
+    },
+    /// Document lock could not be acquired within the timeout
+    #[error("Document is locked by another process: {path}")]
+    Locked {
+        /// Path of the locked document
+        path: String,
+    },
+}
+
+impl Error {
+    /// Category sent to the webview: `NotFound`, `Locked`, `Validation`,
+    /// `PermissionDenied` or `Storage`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Error::NotFound(_) => "NotFound",
+            Error::Io { source, .. } if source.kind() == io::ErrorKind::NotFound => "NotFound",
+            Error::Io { source, .. } if source.kind() == io::ErrorKind::PermissionDenied => {
+                "PermissionDenied"
+            }
+            Error::OutOfScope { .. } => "PermissionDenied",
+            Error::Locked { .. } => "Locked",
+            Error::Validation(_)
+            | Error::Id(_)
+            | Error::InvalidPath { .. }
+            | Error::InvalidShortcut { .. }
+            | Error::ShortcutUnavailable { .. } => "Validation",
+            _ => "Storage",
+        }
+    }
+
+    /// Returns a closure wrapping an `io::Error` with the given context.
+    ///
+    /// Intended for `map_err`: `fs::read(p).map_err(Error::io(format!(...)))`.
+    pub fn io(context: impl Into<String>) -> impl FnOnce(io::Error) -> Error {
+        let context = context.into();
+        move |source| Error::Io { context, source }
+    }
+
+    /// Returns a closure wrapping a `serde_json::Error` with the given context.
+    pub fn json(context: impl Into<String>) -> impl FnOnce(serde_json::Error) -> Error {
+        let context = context.into();
+        move |source| Error::Json { context, source }
+    }
+
+    /// Returns a closure wrapping a `notify::Error` with the given context.
+    pub fn watch(context: impl Into<String>) -> impl FnOnce(notify::Error) -> Error {
+        let context = context.into();
+        move |source| Error::Watch { context, source }
+    }
+
+    /// Returns a closure wrapping a `tantivy::TantivyError` with the given context.
+    pub fn search(context: impl Into<String>) -> impl FnOnce(tantivy::TantivyError) -> Error {
+        let context = context.into();
+        move |source| Error::Search { context, source }
+    }
+
+    /// Returns a closure wrapping a `git2::Error` with the given context.
+    #[cfg(feature = "git")]
+    pub fn git(context: impl Into<String>) -> impl FnOnce(git2::Error) -> Error {
+        let context = context.into();
+        move |source| Error::Git { context, source }
+    }
+}
+
+impl Serialize for Error {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut error = serializer.serialize_struct("Error", 2)?;
+        error.serialize_field("kind", self.kind())?;
+        error.serialize_field("message", &self.to_string())?;
+        error.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_kind_and_message() {
+        let locked = Error::Locked {
+            path: "/docs/a.md".into(),
+        };
+        assert_eq!(
+            serde_json::to_value(&locked).unwrap(),
+            serde_json::json!({
+                "kind": "Locked",
+                "message": "Document is locked by another process: /docs/a.md",
+            })
+        );
+
+        let missing = Error::io("Failed to read /docs/b.md")(io::ErrorKind::NotFound.into());
+        assert_eq!(missing.kind(), "NotFound");
+        assert_eq!(Error::Validation("bad".into()).kind(), "Validation");
+        assert_eq!(
+            Error::OutOfScope {
+                path: "/etc".into()
+            }
+            .kind(),
+            "PermissionDenied"
+        );
+    }
+}
@@ -0,0 +1,509 @@
+//! Git Auto-Commit
+//!
+//! Commits request-log writes to the git repository around a project's
+//! documents (`git` feature, opt-in per project via `Project::auto_commit`):
+//! - One commit per write, with a message from the document layer such as
+//!   `capture: REQ-20251203-app-03 Add dark mode toggle`
+//! - The commit tree is HEAD plus the written file, so unrelated staged and
+//!   unstaged changes are left exactly as they were
+//! - Repositories with conflicts or a merge/rebase in progress are skipped
+//! - Every attempt is reported to listeners (the desktop app emits
+//!   `git-commit`), and [`repo_status`] describes a repository for the UI
+//!
+//! The file is the source of truth: a commit that is skipped or fails never
+//! fails the write itself.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use git2::build::TreeUpdateBuilder;
+use git2::{FileMode, Repository, RepositoryState, Signature, Status, StatusOptions};
+use serde::Serialize;
+
+use crate::atomic_write::is_temp_file;
+use crate::config_store::LocalProjectStore;
+use crate::error::{Error, Result};
+use crate::lock::is_lock_file;
+use crate::ports::ProjectStore;
+
+/// Committer used when the repository has no `user.name`/`user.email`.
+const FALLBACK_NAME: &str = "MeatyCapture";
+const FALLBACK_EMAIL: &str = "meatycapture@localhost";
+
+/// Overall state of a working tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RepoState {
+    /// Nothing to commit
+    Clean,
+    /// Modified, staged or untracked files
+    Dirty,
+    /// Unresolved merge conflicts
+    Conflicted,
+    /// A merge, rebase, cherry-pick, ... has not been finished
+    InProgress,
+}
+
+/// Repository summary shown by the UI.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RepoStatus {
+    /// Working tree root
+    pub workdir: String,
+    /// Checked-out branch (`None` when HEAD is detached)
+    pub branch: Option<String>,
+    pub state: RepoState,
+    /// Changed paths relative to `workdir`
+    pub changed: Vec<String>,
+}
+
+/// Result of one auto-commit attempt.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum CommitOutcome {
+    /// A commit was created
+    Committed { commit: String, message: String },
+    /// The file already matches HEAD
+    Unchanged,
+    /// The repository was left alone (not a repository, conflicts, ...)
+    Skipped { reason: String },
+    /// libgit2 reported an error
+    Failed { error: String },
+}
+
+/// Auto-commit attempt for one document write.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CommitReport {
+    /// Document path
+    pub path: String,
+    pub project_id: String,
+    #[serde(flatten)]
+    pub outcome: CommitOutcome,
+    /// Repository state after the attempt, when there is a repository
+    pub status: Option<RepoStatus>,
+}
+
+type Listener = Box<dyn Fn(&CommitReport) + Send + Sync>;
+
+/// Commits document writes for projects with `auto_commit` enabled.
+pub struct GitAutoCommit {
+    projects: LocalProjectStore,
+    // libgit2 refuses a commit whose parent is no longer HEAD, so commits
+    // from this process are serialized; races with other processes fail
+    // and are reported
+    commit_lock: Mutex<()>,
+    listeners: Mutex<Vec<Listener>>,
+}
+
+impl fmt::Debug for GitAutoCommit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GitAutoCommit")
+            .field("projects", &self.projects)
+            .finish_non_exhaustive()
+    }
+}
+
+impl GitAutoCommit {
+    /// Looks up each document's project in `projects`.
+    pub fn new(projects: LocalProjectStore) -> Self {
+        Self {
+            projects,
+            commit_lock: Mutex::new(()),
+            listeners: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Calls `listener` after every auto-commit attempt.
+    pub fn on_commit(&self, listener: impl Fn(&CommitReport) + Send + Sync + 'static) {
+        self.listeners
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(Box::new(listener));
+    }
+
+    /// Commits `path` with `message` if its project has `auto_commit` on.
+    ///
+    /// Returns `None` when auto-commit does not apply to the project.
+    pub fn document_written(
+        &self,
+        path: &Path,
+        project_id: &str,
+        message: &str,
+    ) -> Option<CommitReport> {
+        let enabled = match self.projects.get(project_id) {
+            Ok(project) => project.is_some_and(|p| p.auto_commit == Some(true)),
+            Err(error) => {
+                log::warn!("Git auto-commit skipped, projects unavailable - {error}");
+                false
+            }
+        };
+        if !enabled {
+            return None;
+        }
+
+        let outcome = {
+            let _guard = self.commit_lock.lock().unwrap_or_else(|e| e.into_inner());
+            commit_file(path, message)
+        };
+        let report = CommitReport {
+            path: path.display().to_string(),
+            project_id: project_id.to_string(),
+            status: repo_status(path).ok(),
+            outcome,
+        };
+        match &report.outcome {
+            CommitOutcome::Committed { commit, .. } => {
+                log::info!("Committed {} as {commit}", report.path)
+            }
+            CommitOutcome::Unchanged => {}
+            CommitOutcome::Skipped { reason } => {
+                log::warn!("Git auto-commit skipped for {}: {reason}", report.path)
+            }
+            CommitOutcome::Failed { error } => {
+                log::error!("Git auto-commit failed for {}: {error}", report.path)
+            }
+        }
+
+        for listener in self
+            .listeners
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+        {
+            listener(&report);
+        }
+        Some(report)
+    }
+}
+
+/// Commits the current content of `path` and nothing else.
+///
+/// The new tree is HEAD's tree with only this file replaced, and only the
+/// file's index entry is refreshed, so whatever else is staged stays staged.
+pub fn commit_file(path: &Path, message: &str) -> CommitOutcome {
+    match try_commit_file(path, message) {
+        Ok(outcome) => outcome,
+        Err(error) => CommitOutcome::Failed {
+            error: error.message().to_string(),
+        },
+    }
+}
+
+fn try_commit_file(path: &Path, message: &str) -> std::result::Result<CommitOutcome, git2::Error> {
+    let skipped = |reason: String| Ok(CommitOutcome::Skipped { reason });
+
+    let Some((repo, relative)) = open_repo(path) else {
+        return skipped(format!("{} is not inside a git repository", path.display()));
+    };
+    if repo.state() != RepositoryState::Clean {
+        return skipped(format!(
+            "a {:?} is in progress in {}",
+            repo.state(),
+            workdir(&repo).display()
+        ));
+    }
+    let mut index = repo.index()?;
+    if index.has_conflicts() {
+        return skipped(format!(
+            "{} has unresolved conflicts",
+            workdir(&repo).display()
+        ));
+    }
+    if repo.status_should_ignore(&relative)? {
+        return skipped(format!("{} is ignored by git", relative.display()));
+    }
+
+    let parent = match repo.head() {
+        Ok(head) => Some(head.peel_to_commit()?),
+        Err(error) if error.code() == git2::ErrorCode::UnbornBranch => None,
+        Err(error) if error.code() == git2::ErrorCode::NotFound => None,
+        Err(error) => return Err(error),
+    };
+    let base = match &parent {
+        Some(commit) => commit.tree()?,
+        None => {
+            let empty = repo.treebuilder(None)?.write()?;
+            repo.find_tree(empty)?
+        }
+    };
+
+    let blob = repo.blob_path(path)?;
+    let tree_id = TreeUpdateBuilder::new()
+        .upsert(git_path(&relative), blob, FileMode::Blob)
+        .create_updated(&repo, &base)?;
+    if parent.is_some() && tree_id == base.id() {
+        return Ok(CommitOutcome::Unchanged);
+    }
+
+    let tree = repo.find_tree(tree_id)?;
+    let signature = repo
+        .signature()
+        .or_else(|_| Signature::now(FALLBACK_NAME, FALLBACK_EMAIL))?;
+    let parents: Vec<_> = parent.iter().collect();
+    let commit = repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        message,
+        &tree,
+        &parents,
+    )?;
+
+    // Stage the committed version of this file only, so `git status` does
+    // not show the commit reverted; other index entries are untouched
+    index.add_path(&relative)?;
+    index.write()?;
+
+    Ok(CommitOutcome::Committed {
+        commit: commit.to_string(),
+        message: message.to_string(),
+    })
+}
+
+/// State of the repository containing `path`.
+///
+/// Fails with [`Error::NotFound`] when `path` is not inside a working tree.
+pub fn repo_status(path: &Path) -> Result<RepoStatus> {
+    let (repo, _) = open_repo(path).ok_or_else(|| {
+        Error::NotFound(format!("{} is not inside a git repository", path.display()))
+    })?;
+    let context = format!("Failed to read git status of {}", workdir(&repo).display());
+
+    let statuses = repo
+        .statuses(Some(
+            StatusOptions::new()
+                .include_untracked(true)
+                .recurse_untracked_dirs(true),
+        ))
+        .map_err(Error::git(context.clone()))?;
+    let changed: Vec<String> = statuses
+        .iter()
+        .filter(|entry| !entry.status().is_ignored())
+        .filter_map(|entry| entry.path().map(str::to_string))
+        .filter(|path| !is_own_artifact(Path::new(path)))
+        .collect();
+    let conflicted = statuses
+        .iter()
+        .any(|entry| entry.status().contains(Status::CONFLICTED));
+
+    let state = if conflicted {
+        RepoState::Conflicted
+    } else if repo.state() != RepositoryState::Clean {
+        RepoState::InProgress
+    } else if changed.is_empty() {
+        RepoState::Clean
+    } else {
+        RepoState::Dirty
+    };
+    let branch = repo
+        .head()
+        .ok()
+        .filter(|head| head.is_branch())
+        .and_then(|head| head.shorthand().map(str::to_string));
+
+    Ok(RepoStatus {
+        workdir: workdir(&repo).display().to_string(),
+        branch,
+        state,
+        changed,
+    })
+}
+
+/// Lock sidecars, temp files and `.bak` copies the document layer leaves
+/// next to documents; they do not make a repository dirty.
+fn is_own_artifact(path: &Path) -> bool {
+    is_lock_file(path) || is_temp_file(path) || path.extension().is_some_and(|ext| ext == "bak")
+}
+
+/// Repository whose working tree contains `path`, and `path` relative to it.
+fn open_repo(path: &Path) -> Option<(Repository, PathBuf)> {
+    // Symlinked temp or home directories must resolve like the workdir does
+    let path = fs::canonicalize(path).ok()?;
+    let repo = Repository::discover(path.parent()?).ok()?;
+    let root = fs::canonicalize(repo.workdir()?).ok()?;
+    let relative = path.strip_prefix(&root).ok()?.to_path_buf();
+    Some((repo, relative))
+}
+
+fn workdir(repo: &Repository) -> &Path {
+    repo.workdir().unwrap_or_else(|| repo.path())
+}
+
+/// Tree paths always use `/`.
+fn git_path(relative: &Path) -> String {
+    relative
+        .components()
+        .map(|part| part.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Repository with one commit containing `notes.txt`.
+    fn repo() -> (tempfile::TempDir, Repository) {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        repo.config().unwrap().set_str("user.name", "Test").unwrap();
+        repo.config()
+            .unwrap()
+            .set_str("user.email", "test@example.com")
+            .unwrap();
+        fs::write(dir.path().join("notes.txt"), "v1\n").unwrap();
+        assert!(matches!(
+            commit_file(&dir.path().join("notes.txt"), "init"),
+            CommitOutcome::Committed { .. }
+        ));
+        (dir, repo)
+    }
+
+    fn head_file(repo: &Repository, path: &str) -> Option<String> {
+        let tree = repo.head().unwrap().peel_to_tree().unwrap();
+        let entry = tree.get_path(Path::new(path)).ok()?;
+        let blob = repo.find_blob(entry.id()).unwrap();
+        Some(String::from_utf8_lossy(blob.content()).into_owned())
+    }
+
+    #[test]
+    fn commits_only_the_document() {
+        let (dir, repo) = repo();
+        // Unrelated work in progress: one staged, one unstaged change
+        fs::write(dir.path().join("staged.txt"), "staged\n").unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("staged.txt")).unwrap();
+        index.write().unwrap();
+        fs::write(dir.path().join("notes.txt"), "v2\n").unwrap();
+
+        let doc = dir.path().join("docs/REQ-20251203-app.md");
+        fs::create_dir_all(doc.parent().unwrap()).unwrap();
+        fs::write(&doc, "log\n").unwrap();
+        let outcome = commit_file(&doc, "capture: REQ-20251203-app-01 One");
+
+        assert!(matches!(outcome, CommitOutcome::Committed { .. }));
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(head.message(), Some("capture: REQ-20251203-app-01 One"));
+        assert_eq!(head.parent_count(), 1);
+        assert_eq!(
+            head_file(&repo, "docs/REQ-20251203-app.md").as_deref(),
+            Some("log\n")
+        );
+        assert_eq!(head_file(&repo, "notes.txt").as_deref(), Some("v1\n"));
+        assert_eq!(head_file(&repo, "staged.txt"), None);
+
+        let status = repo_status(&doc).unwrap();
+        assert_eq!(status.state, RepoState::Dirty);
+        assert_eq!(status.changed, vec!["notes.txt", "staged.txt"]);
+        let staged = repo.status_file(Path::new("staged.txt")).unwrap();
+        assert!(staged.contains(Status::INDEX_NEW));
+
+        assert_eq!(commit_file(&doc, "again"), CommitOutcome::Unchanged);
+    }
+
+    #[test]
+    fn skips_conflicted_repositories_and_plain_directories() {
+        let (dir, repo) = repo();
+        let doc = dir.path().join("REQ-20251203-app.md");
+        fs::write(&doc, "log\n").unwrap();
+
+        // Simulate an unfinished merge
+        let head = repo.head().unwrap().target().unwrap();
+        fs::write(repo.path().join("MERGE_HEAD"), format!("{head}\n")).unwrap();
+        assert!(matches!(
+            commit_file(&doc, "capture"),
+            CommitOutcome::Skipped { .. }
+        ));
+        assert_eq!(repo_status(&doc).unwrap().state, RepoState::InProgress);
+
+        let plain = tempfile::tempdir().unwrap();
+        let outside = plain.path().join("REQ-20251203-app.md");
+        fs::write(&outside, "log\n").unwrap();
+        assert!(matches!(
+            commit_file(&outside, "capture"),
+            CommitOutcome::Skipped { .. }
+        ));
+        assert!(matches!(repo_status(&outside), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn document_writes_commit_for_opted_in_projects() {
+        use crate::doc_store::FsDocStore;
+        use crate::models::{ItemDraft, NewProject, RequestLogDoc, SCHEMA_VERSION};
+        use crate::ports::{DocStore, SystemClock};
+        use std::sync::Arc;
+
+        let (dir, repo) = repo();
+        let config = tempfile::tempdir().unwrap();
+        let projects = LocalProjectStore::new(Some(config.path().to_path_buf()));
+        for (id, auto_commit) in [("app", Some(true)), ("other", None)] {
+            projects
+                .create(NewProject {
+                    id: Some(id.to_string()),
+                    name: id.to_string(),
+                    default_path: dir.path().display().to_string(),
+                    repo_url: None,
+                    enabled: true,
+                    auto_commit,
+                })
+                .unwrap();
+        }
+        let git = Arc::new(GitAutoCommit::new(projects));
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let sink = reports.clone();
+        git.on_commit(move |report| sink.lock().unwrap().push(report.clone()));
+        let store = FsDocStore::new().without_backups().with_git(git);
+
+        let now = chrono::Utc::now();
+        let doc = |project: &str| RequestLogDoc {
+            schema_version: SCHEMA_VERSION,
+            doc_id: format!("REQ-20251203-{project}"),
+            title: "Log".to_string(),
+            project_id: project.to_string(),
+            items: vec![],
+            items_index: vec![],
+            tags: vec![],
+            item_count: 0,
+            created_at: now,
+            updated_at: now,
+            extra_frontmatter: vec![],
+            preamble: String::new(),
+        };
+        let path = dir.path().join("REQ-20251203-app.md");
+        let path = path.to_str().unwrap();
+        store.write(path, &doc("app")).unwrap();
+        let draft = ItemDraft {
+            title: "Add dark mode toggle".to_string(),
+            item_type: "enhancement".to_string(),
+            domain: String::new(),
+            context: String::new(),
+            priority: "medium".to_string(),
+            status: "triage".to_string(),
+            tags: vec![],
+            notes: String::new(),
+        };
+        store.append(path, draft, &SystemClock).unwrap();
+
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(
+            head.message(),
+            Some("capture: REQ-20251203-app-01 Add dark mode toggle")
+        );
+        assert_eq!(
+            head.parent(0).unwrap().message(),
+            Some("create: REQ-20251203-app Log")
+        );
+        let status = reports.lock().unwrap()[1].status.clone().unwrap();
+        assert_eq!(status.state, RepoState::Clean);
+
+        // Projects without auto_commit are left alone
+        let other = dir.path().join("REQ-20251203-other.md");
+        store.write(other.to_str().unwrap(), &doc("other")).unwrap();
+        assert_eq!(
+            repo.head().unwrap().peel_to_commit().unwrap().id(),
+            head.id()
+        );
+        assert_eq!(reports.lock().unwrap().len(), 2);
+    }
+}
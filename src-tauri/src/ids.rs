@@ -0,0 +1,462 @@
+//! ID Generation & Validation
+//!
+//! Native port of the ID helpers in `src/core/validation`:
+//! - Document IDs: `REQ-YYYYMMDD-<project-slug>`
+//! - Item IDs: `REQ-YYYYMMDD-<project-slug>-XX`
+//! - Slugs for project and option IDs
+//! - Path segments safe to join under a project directory
+//!
+//! Matching follows JavaScript semantics where they differ from Rust's:
+//! `\s` and `trim()` use the ECMAScript whitespace set, digits are ASCII
+//! only, and years below 100 are rejected like `new Date(y, m, d)` does.
+//! `src/core/validation/id-vectors.json` is checked by both test suites.
+
+use std::sync::LazyLock;
+
+use chrono::{Datelike, NaiveDate};
+use regex::Regex;
+
+const DOC_ID_PREFIX: &str = "REQ";
+
+/// ECMAScript `WhiteSpace` + `LineTerminator` (what `\s` and `trim()` match).
+///
+/// Unlike Unicode `White_Space` it includes U+FEFF and excludes U+0085.
+const JS_WHITESPACE: &str = r"\t\n\x0B\x0C\r \u{A0}\u{1680}\u{2000}-\u{200A}\u{2028}\u{2029}\u{202F}\u{205F}\u{3000}\u{FEFF}";
+
+static DOC_ID_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^REQ-([0-9]{8})-([a-z0-9-]+)$").unwrap());
+static ITEM_ID_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^REQ-([0-9]{8})-([a-z0-9-]+)-([0-9]{2})$").unwrap());
+static WHITESPACE_UNDERSCORE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!("[{JS_WHITESPACE}_]+")).unwrap());
+static EDGE_WHITESPACE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!("^[{JS_WHITESPACE}]+|[{JS_WHITESPACE}]+$")).unwrap());
+static NON_SLUG_CHAR_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[^a-z0-9-]").unwrap());
+static MULTI_HYPHEN_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"-+").unwrap());
+static CONTROL_CHAR_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[\x00-\x1F\x7F]").unwrap());
+
+/// Errors raised when generating IDs from invalid input.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum IdError {
+    /// Document ID does not match `REQ-YYYYMMDD-<slug>`
+    #[error("Invalid document ID: \"{0}\"")]
+    InvalidDocId(String),
+    /// Project slug is empty after slugification
+    #[error("Invalid project slug: \"{0}\"")]
+    InvalidSlug(String),
+    /// Date year cannot be written as four digits
+    #[error("Document date must be between years 1000 and 9999, got: {0}")]
+    DateOutOfRange(NaiveDate),
+    /// Item number outside the two-digit range
+    #[error("Item number must be an integer between 1 and 99, got: {0}")]
+    ItemNumberOutOfRange(u32),
+}
+
+/// Parsed document ID structure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedDocId {
+    /// Parsed date from the document ID
+    pub date: NaiveDate,
+    /// Extracted project slug
+    pub project_slug: String,
+}
+
+/// Parsed item ID structure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedItemId {
+    /// Parent document ID (without item number)
+    pub doc_id: String,
+    /// Item number (1-based)
+    pub item_number: u32,
+    /// Parsed date from the item ID
+    pub date: NaiveDate,
+    /// Extracted project slug
+    pub project_slug: String,
+}
+
+/// Generates a document ID from a project slug and date.
+///
+/// The slug is normalized with [`slugify`]; callers pass the local date,
+/// like the TS `generateDocId`. Years outside 1000-9999 are rejected since
+/// they would not produce an eight-digit date.
+/// Example: `Capture App` + 2025-12-03 -> `REQ-20251203-capture-app`
+pub fn generate_doc_id(project_slug: &str, date: NaiveDate) -> Result<String, IdError> {
+    let slug = slugify(project_slug);
+    if slug.is_empty() {
+        return Err(IdError::InvalidSlug(project_slug.to_string()));
+    }
+
+    if !(1000..=9999).contains(&date.year()) {
+        return Err(IdError::DateOutOfRange(date));
+    }
+
+    Ok(format!("{DOC_ID_PREFIX}-{}-{slug}", date.format("%Y%m%d")))
+}
+
+/// Generates an item ID from a document ID and 1-based item number.
+///
+/// Example: `REQ-20251203-capture-app` + 1 -> `REQ-20251203-capture-app-01`
+pub fn generate_item_id(doc_id: &str, item_number: u32) -> Result<String, IdError> {
+    if !is_valid_doc_id(doc_id) {
+        return Err(IdError::InvalidDocId(doc_id.to_string()));
+    }
+
+    if !(1..=99).contains(&item_number) {
+        return Err(IdError::ItemNumberOutOfRange(item_number));
+    }
+
+    Ok(format!("{doc_id}-{item_number:02}"))
+}
+
+/// Parses a document ID into its date and project slug.
+///
+/// Returns `None` for malformed IDs and impossible calendar dates. Years
+/// 0000-0099 are invalid too: the TS `Date` constructor maps them to 19xx.
+pub fn parse_doc_id(doc_id: &str) -> Option<ParsedDocId> {
+    let captures = DOC_ID_PATTERN.captures(doc_id)?;
+    let date = NaiveDate::parse_from_str(&captures[1], "%Y%m%d").ok()?;
+    if date.year() < 100 {
+        return None;
+    }
+
+    Some(ParsedDocId {
+        date,
+        project_slug: captures[2].to_string(),
+    })
+}
+
+/// Parses an item ID into its document ID, item number, date and slug.
+pub fn parse_item_id(item_id: &str) -> Option<ParsedItemId> {
+    let captures = ITEM_ID_PATTERN.captures(item_id)?;
+    let doc_id = format!("{DOC_ID_PREFIX}-{}-{}", &captures[1], &captures[2]);
+    let parsed = parse_doc_id(&doc_id)?;
+    let item_number = captures[3].parse().ok()?;
+
+    Some(ParsedItemId {
+        doc_id,
+        item_number,
+        date: parsed.date,
+        project_slug: parsed.project_slug,
+    })
+}
+
+/// Validates a document ID format.
+pub fn is_valid_doc_id(doc_id: &str) -> bool {
+    parse_doc_id(doc_id).is_some()
+}
+
+/// Validates an item ID format.
+pub fn is_valid_item_id(item_id: &str) -> bool {
+    parse_item_id(item_id).is_some()
+}
+
+/// Determines the next item number as max existing number + 1.
+///
+/// IDs that fail to parse are ignored; returns 1 when none are valid.
+pub fn get_next_item_number<'a, I>(existing_ids: I) -> u32
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    existing_ids
+        .into_iter()
+        .filter_map(parse_item_id)
+        .map(|parsed| parsed.item_number)
+        .max()
+        .map_or(1, |max| max + 1)
+}
+
+/// Converts text to a URL-safe slug.
+///
+/// Lowercases, turns whitespace/underscores into hyphens, strips anything
+/// but `[a-z0-9-]`, collapses hyphen runs and trims edge hyphens.
+/// Example: `Mixed-CASE_Text 123` -> `mixed-case-text-123`
+pub fn slugify(text: &str) -> String {
+    let lowered = text.to_lowercase();
+    let trimmed = EDGE_WHITESPACE_RE.replace_all(&lowered, "");
+    let hyphenated = WHITESPACE_UNDERSCORE_RE.replace_all(&trimmed, "-");
+    let cleaned = NON_SLUG_CHAR_RE.replace_all(&hyphenated, "");
+    let collapsed = MULTI_HYPHEN_RE.replace_all(&cleaned, "-");
+    collapsed.trim_matches('-').to_string()
+}
+
+/// Sanitizes text for use as a single path segment.
+///
+/// Strips control characters, path separators and `..` before applying
+/// [`slugify`], so the result can never escape its parent directory.
+/// Example: `../etc/passwd` -> `etcpasswd`
+pub fn sanitize_path_segment(text: &str) -> String {
+    let cleaned = CONTROL_CHAR_RE.replace_all(text, "");
+    let cleaned = cleaned.replace(['/', '\\'], "").replace("..", "");
+    if cleaned == "." {
+        return String::new();
+    }
+    slugify(&cleaned)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use serde_json::Value;
+
+    use super::*;
+
+    /// Vectors exported by `src/core/validation/id-vectors.test.ts`.
+    const VECTORS: &str = include_str!("../../src/core/validation/id-vectors.json");
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+
+    fn vectors(key: &str) -> Vec<Value> {
+        let all: Value = serde_json::from_str(VECTORS).unwrap();
+        all[key].as_array().unwrap().clone()
+    }
+
+    fn text(value: &Value) -> &str {
+        value.as_str().unwrap()
+    }
+
+    #[test]
+    fn generates_and_parses_ids() {
+        let doc_id = generate_doc_id("Capture App", date("2025-12-03")).unwrap();
+        assert_eq!(doc_id, "REQ-20251203-capture-app");
+        assert_eq!(
+            generate_item_id(&doc_id, 7).unwrap(),
+            "REQ-20251203-capture-app-07"
+        );
+
+        let parsed = parse_item_id("REQ-20251203-capture-app-07").unwrap();
+        assert_eq!(parsed.doc_id, doc_id);
+        assert_eq!(parsed.item_number, 7);
+        assert_eq!(parsed.date, date("2025-12-03"));
+        assert_eq!(parsed.project_slug, "capture-app");
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert_eq!(
+            generate_doc_id("!@#", date("2025-12-03")),
+            Err(IdError::InvalidSlug("!@#".to_string()))
+        );
+        assert_eq!(
+            generate_doc_id("x", date("0999-12-31")),
+            Err(IdError::DateOutOfRange(date("0999-12-31")))
+        );
+        assert_eq!(
+            generate_item_id("REQ-20251203-x", 100),
+            Err(IdError::ItemNumberOutOfRange(100))
+        );
+        assert_eq!(
+            generate_item_id("INVALID", 1),
+            Err(IdError::InvalidDocId("INVALID".to_string()))
+        );
+    }
+
+    #[test]
+    fn follows_javascript_semantics() {
+        // U+0085 is Unicode whitespace but not ECMAScript whitespace; U+FEFF the reverse
+        assert_eq!(slugify("a\u{85}b"), "ab");
+        assert_eq!(slugify("\u{FEFF}a\u{FEFF}b\u{FEFF}"), "a-b");
+        // Non-ASCII digits are not `\d` in JavaScript
+        assert_eq!(parse_item_id("REQ-20251203-x-\u{661}\u{662}"), None);
+        // `new Date(50, 0, 1)` is 1950, so two-digit years never round-trip
+        assert_eq!(parse_doc_id("REQ-00500101-x"), None);
+        assert!(parse_doc_id("REQ-01000101-x").is_some());
+    }
+
+    #[test]
+    fn sanitizes_path_segments() {
+        assert_eq!(sanitize_path_segment("My Project"), "my-project");
+        assert_eq!(sanitize_path_segment("../etc/passwd"), "etcpasswd");
+        assert_eq!(sanitize_path_segment("project/../../bad"), "projectbad");
+        assert_eq!(sanitize_path_segment("."), "");
+        assert_eq!(sanitize_path_segment("nul\0byte"), "nulbyte");
+    }
+
+    // ========================================================================
+    // Shared vectors (TS parity)
+    // ========================================================================
+
+    #[test]
+    fn matches_ts_slugify_vectors() {
+        for vector in vectors("slugify") {
+            let input = text(&vector["input"]);
+            assert_eq!(slugify(input), text(&vector["output"]), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn matches_ts_sanitize_path_segment_vectors() {
+        for vector in vectors("sanitizePathSegment") {
+            let input = text(&vector["input"]);
+            assert_eq!(
+                sanitize_path_segment(input),
+                text(&vector["output"]),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn matches_ts_generate_doc_id_vectors() {
+        for vector in vectors("generateDocId") {
+            let slug = text(&vector["slug"]);
+            let actual = generate_doc_id(slug, date(text(&vector["date"]))).ok();
+            assert_eq!(actual.as_deref(), vector["output"].as_str(), "{slug:?}");
+        }
+    }
+
+    #[test]
+    fn matches_ts_generate_item_id_vectors() {
+        for vector in vectors("generateItemId") {
+            let doc_id = text(&vector["docId"]);
+            let number = vector["itemNumber"].as_u64().unwrap() as u32;
+            let actual = generate_item_id(doc_id, number).ok();
+            assert_eq!(
+                actual.as_deref(),
+                vector["output"].as_str(),
+                "{doc_id} {number}"
+            );
+        }
+    }
+
+    #[test]
+    fn matches_ts_parse_doc_id_vectors() {
+        for vector in vectors("parseDocId") {
+            let input = text(&vector["input"]);
+            let expected = &vector["output"];
+            match parse_doc_id(input) {
+                Some(parsed) => {
+                    assert_eq!(parsed.date, date(text(&expected["date"])), "{input}");
+                    assert_eq!(parsed.project_slug, text(&expected["projectSlug"]));
+                }
+                None => assert!(expected.is_null(), "{input} should parse"),
+            }
+        }
+    }
+
+    #[test]
+    fn matches_ts_parse_item_id_vectors() {
+        for vector in vectors("parseItemId") {
+            let input = text(&vector["input"]);
+            let expected = &vector["output"];
+            match parse_item_id(input) {
+                Some(parsed) => {
+                    assert_eq!(parsed.doc_id, text(&expected["docId"]), "{input}");
+                    assert_eq!(
+                        u64::from(parsed.item_number),
+                        expected["itemNumber"].as_u64().unwrap()
+                    );
+                    assert_eq!(parsed.date, date(text(&expected["date"])));
+                    assert_eq!(parsed.project_slug, text(&expected["projectSlug"]));
+                }
+                None => assert!(expected.is_null(), "{input} should parse"),
+            }
+        }
+    }
+
+    #[test]
+    fn matches_ts_get_next_item_number_vectors() {
+        for vector in vectors("getNextItemNumber") {
+            let ids: Vec<&str> = vector["ids"].as_array().unwrap().iter().map(text).collect();
+            assert_eq!(
+                u64::from(get_next_item_number(ids.iter().copied())),
+                vector["output"].as_u64().unwrap(),
+                "{ids:?}"
+            );
+        }
+    }
+
+    // ========================================================================
+    // Properties
+    // ========================================================================
+
+    fn slug_strategy() -> impl Strategy<Value = String> {
+        "[a-z0-9]{1,8}(-[a-z0-9]{1,8}){0,3}"
+    }
+
+    fn date_strategy() -> impl Strategy<Value = NaiveDate> {
+        (1000i32..=9999, 1u32..=366).prop_filter_map("day outside year", |(year, day)| {
+            NaiveDate::from_yo_opt(year, day)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn doc_id_round_trips(slug in slug_strategy(), date in date_strategy()) {
+            let doc_id = generate_doc_id(&slug, date).unwrap();
+            let parsed = parse_doc_id(&doc_id).unwrap();
+            prop_assert_eq!(parsed.date, date);
+            prop_assert_eq!(parsed.project_slug, slug);
+        }
+
+        #[test]
+        fn item_id_round_trips(
+            slug in slug_strategy(),
+            date in date_strategy(),
+            number in 1u32..=99,
+        ) {
+            let doc_id = generate_doc_id(&slug, date).unwrap();
+            let item_id = generate_item_id(&doc_id, number).unwrap();
+            let parsed = parse_item_id(&item_id).unwrap();
+            prop_assert_eq!(&parsed.doc_id, &doc_id);
+            prop_assert_eq!(parsed.item_number, number);
+            prop_assert_eq!(parsed.date, date);
+            prop_assert_eq!(parsed.project_slug, slug);
+        }
+
+        #[test]
+        fn generated_doc_ids_use_slugified_name(name in "\\PC{0,40}", date in date_strategy()) {
+            match generate_doc_id(&name, date) {
+                Ok(doc_id) => {
+                    let parsed = parse_doc_id(&doc_id).unwrap();
+                    prop_assert_eq!(parsed.project_slug, slugify(&name));
+                }
+                Err(error) => prop_assert_eq!(error, IdError::InvalidSlug(name.clone())),
+            }
+        }
+
+        #[test]
+        fn slugify_is_idempotent_and_url_safe(text in "\\PC{0,40}") {
+            let slug = slugify(&text);
+            prop_assert_eq!(slugify(&slug), slug.clone());
+            prop_assert!(slug.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-'));
+            prop_assert!(!slug.starts_with('-') && !slug.ends_with('-') && !slug.contains("--"));
+        }
+
+        #[test]
+        fn sanitized_segments_stay_in_place(text in "[./\\\\a-zA-Z0-9 \\x00-\\x1f]{0,40}") {
+            let segment = sanitize_path_segment(&text);
+            prop_assert!(!segment.contains(['/', '\\', '.']));
+            prop_assert_eq!(slugify(&segment), segment);
+        }
+
+        #[test]
+        fn parse_never_accepts_what_it_cannot_regenerate(id in "REQ-[0-9]{8}-[a-z0-9-]{1,12}(-[0-9]{2})?") {
+            if let Some(parsed) = parse_item_id(&id) {
+                prop_assert_eq!(
+                    format!("{}-{:02}", parsed.doc_id, parsed.item_number),
+                    id.clone()
+                );
+            }
+            if let Some(parsed) = parse_doc_id(&id) {
+                let suffix = format!("-{}", parsed.project_slug);
+                prop_assert!(id.ends_with(&suffix));
+                prop_assert_eq!(
+                    format!("REQ-{}{}", parsed.date.format("%Y%m%d"), suffix),
+                    id
+                );
+            }
+        }
+
+        #[test]
+        fn next_item_number_is_max_plus_one(numbers in prop::collection::vec(0u32..=99, 0..20)) {
+            let ids: Vec<String> = numbers
+                .iter()
+                .map(|n| format!("REQ-20251203-x-{n:02}"))
+                .collect();
+            let expected = numbers.iter().max().map_or(1, |max| max + 1);
+            prop_assert_eq!(get_next_item_number(ids.iter().map(String::as_str)), expected);
+        }
+    }
+}
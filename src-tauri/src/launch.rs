@@ -0,0 +1,145 @@
+//! Launch Arguments
+//!
+//! Command-line actions for the desktop app. A second launch forwards its
+//! arguments to the running instance (single-instance plugin), which
+//! focuses and acts on them:
+//! - `meatycapture <document.md>`: show the document in the viewer
+//! - `meatycapture --capture ["title"]`: open quick capture, title prefilled
+//! - `meatycapture meatycapture://...`: handle a deep link (see `deep_link`);
+//!   this is how the Linux desktop entry and Windows pass URLs
+//! - No arguments: bring the main window forward
+
+use std::path::Path;
+
+use crate::deep_link::DeepLink;
+use crate::doc_store::expand_path;
+
+/// Parsed launch request.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LaunchArgs {
+    /// Document to show in the viewer, absolute
+    pub doc_path: Option<String>,
+    /// Open the quick-capture window
+    pub capture: bool,
+    /// Title to prefill in quick capture
+    pub title: Option<String>,
+    /// Raw `meatycapture://` URL, parsed and validated when handled
+    pub deep_link: Option<String>,
+}
+
+impl LaunchArgs {
+    /// Parses arguments after the program name.
+    ///
+    /// Relative document paths resolve against `cwd` (the launching shell's
+    /// directory, not the running instance's). Unknown flags are ignored so
+    /// OS-added arguments (e.g. macOS `-psn_*`) do not break a launch.
+    pub fn parse<I, S>(args: I, cwd: &Path) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter().map(Into::into).peekable();
+        let mut flags_done = false;
+
+        while let Some(arg) = args.next() {
+            if !flags_done && arg == "--" {
+                flags_done = true;
+            } else if !flags_done && arg == "--capture" {
+                parsed.capture = true;
+                if let Some(title) = args.next_if(|next| !next.starts_with('-')) {
+                    parsed.title = non_empty(title);
+                }
+            } else if let Some(title) = arg.strip_prefix("--capture=").filter(|_| !flags_done) {
+                parsed.capture = true;
+                parsed.title = non_empty(title.to_string());
+            } else if !flags_done && arg.starts_with('-') {
+                log::debug!("Ignoring unknown launch argument {arg}");
+            } else if DeepLink::matches(&arg) {
+                parsed.deep_link.get_or_insert(arg);
+            } else if parsed.doc_path.is_none() && !arg.is_empty() {
+                parsed.doc_path = Some(cwd.join(expand_path(&arg)).display().to_string());
+            }
+        }
+        parsed
+    }
+
+    /// True when the launch only asks to focus the app.
+    pub fn is_empty(&self) -> bool {
+        self.doc_path.is_none() && !self.capture && self.deep_link.is_none()
+    }
+}
+
+fn non_empty(title: String) -> Option<String> {
+    let title = title.trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc_store::base_dir;
+
+    fn parse(args: &[&str]) -> LaunchArgs {
+        LaunchArgs::parse(args.iter().copied(), Path::new("/work"))
+    }
+
+    #[test]
+    fn no_arguments_only_focus() {
+        assert!(parse(&[]).is_empty());
+        assert!(parse(&["-psn_0_12345"]).is_empty());
+    }
+
+    #[test]
+    fn parses_capture_with_and_without_title() {
+        let with_title = parse(&["--capture", "Fix login redirect"]);
+        assert!(with_title.capture);
+        assert_eq!(with_title.title.as_deref(), Some("Fix login redirect"));
+
+        let inline = parse(&["--capture=Add dark mode"]);
+        assert_eq!(inline.title.as_deref(), Some("Add dark mode"));
+
+        let bare = parse(&["--capture"]);
+        assert!(bare.capture && bare.title.is_none());
+        assert!(parse(&["--capture", "  "]).title.is_none());
+
+        // A following flag is not taken as the title
+        let flagged = parse(&["--capture", "--verbose"]);
+        assert!(flagged.capture && flagged.title.is_none());
+    }
+
+    #[test]
+    fn resolves_document_paths_against_cwd() {
+        assert_eq!(
+            parse(&["notes/log.md"]).doc_path.as_deref(),
+            Some("/work/notes/log.md")
+        );
+        assert_eq!(
+            parse(&["/abs/log.md", "/ignored.md"]).doc_path,
+            Some("/abs/log.md".to_string())
+        );
+        assert_eq!(
+            parse(&["~/log.md"]).doc_path,
+            Some(base_dir().join("log.md").display().to_string())
+        );
+    }
+
+    #[test]
+    fn double_dash_ends_flags() {
+        let parsed = parse(&["--", "--capture"]);
+        assert!(!parsed.capture);
+        assert_eq!(parsed.doc_path.as_deref(), Some("/work/--capture"));
+
+        let both = parse(&["--capture", "Title", "log.md"]);
+        assert!(both.capture);
+        assert_eq!(both.doc_path.as_deref(), Some("/work/log.md"));
+    }
+
+    #[test]
+    fn keeps_deep_links_verbatim() {
+        let link = "meatycapture://capture?title=Fix%20it&tags=a,b";
+        let parsed = parse(&[link, "meatycapture://open?doc_id=REQ-20251203-app"]);
+        assert_eq!(parsed.deep_link.as_deref(), Some(link));
+        assert!(parsed.doc_path.is_none() && !parsed.is_empty());
+    }
+}
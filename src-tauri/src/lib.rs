@@ -1,21 +1,95 @@
+#[cfg(feature = "desktop")]
+mod app;
+pub mod atomic_write;
+pub mod backups;
+#[cfg(all(
+    feature = "desktop",
+    not(any(target_os = "android", target_os = "ios"))
+))]
+pub mod capture;
+#[cfg(feature = "cli")]
+pub mod cli;
+#[cfg(feature = "desktop")]
+pub mod commands;
+pub mod config_store;
+pub mod deep_link;
+pub mod diff;
+pub mod doc_index;
+pub mod doc_store;
+pub mod error;
+#[cfg(feature = "git")]
+pub mod git;
+pub mod ids;
+pub mod launch;
+pub mod lock;
+pub mod mcp;
+pub mod migrate;
+pub mod models;
+#[cfg(feature = "desktop")]
+pub mod navigation;
+pub mod path_guard;
+pub mod ports;
+pub mod scope;
+pub mod search;
+pub mod serializer;
+#[cfg(feature = "server")]
+pub mod server;
+#[cfg(all(
+    feature = "desktop",
+    not(any(target_os = "android", target_os = "ios"))
+))]
+pub mod shortcut;
+#[cfg(all(
+    feature = "desktop",
+    not(any(target_os = "android", target_os = "ios"))
+))]
+pub mod tray;
+pub mod watcher;
+pub mod window_state;
+#[cfg(all(
+    feature = "desktop",
+    not(any(target_os = "android", target_os = "ios"))
+))]
+pub mod windows;
+
 /**
  * MeatyCapture Library
  *
- * Shared library code for Tauri application.
- * Currently minimal as all logic is in the frontend.
+ * Shared library code for the Tauri application and the headless CLI:
+ * - models: Request-log domain types, JSON Schemas and TS bindings
+ * - serializer: Request-log markdown serialize/parse (TS-compatible)
+ * - ids: Document/item ID generation and validation
+ * - launch: Command-line actions forwarded to the running desktop instance
+ * - deep_link: `meatycapture://` capture/open links validated against the stores
+ * - ports: Store port traits (DocStore, ProjectStore, FieldCatalogStore, ConfigStore)
+ * - doc_store / config_store: Filesystem and ~/.meatycapture JSON implementations
+ * - migrate: Request-log schema_version detection and ordered migrations
+ * - diff: Unified line diffs for migration and backup previews
+ * - backups: Versioned, deduplicated document snapshots with retention
+ * - git: Per-project auto-commit of document writes (`git` feature)
+ * - atomic_write: Crash-safe temp-file + fsync + rename writes
+ * - lock: Cross-process advisory document locks
+ * - scope: Data dir + enabled project roots the webview may access
+ * - path_guard: Canonicalizing traversal/symlink checks for webview paths
+ * - doc_index: Persistent mtime/size-validated listing cache
+ * - search: Tantivy full-text index over all items
+ * - watcher: Emits doc-created/doc-updated/doc-deleted for project directories
+ * - window_state: Persisted per-window size, position, maximized state and monitor
+ * - server: Embedded localhost REST API (`server` feature, opt-in via config)
+ * - mcp: Model Context Protocol server over stdio (`meatycapture mcp`)
+ * - cli: `meatycapture-cli` commands (`cli` feature, no webview)
+ *
+ * Desktop only (`desktop` feature, default):
+ * - app: Builder setup, plugins, state and background services
+ * - tray: System tray menu and close-to-tray
+ * - navigation: Shows the main window and emits `navigate` to the webview
+ * - capture / shortcut: Quick-capture window and its global hotkey
+ * - windows: Capture/viewer/admin windows and geometry restore
+ * - commands: Tauri IPC handlers registered in `app`
  */
 
+#[cfg(feature = "desktop")]
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    let mut builder = tauri::Builder::default()
-        .plugin(tauri_plugin_fs::init());
-
-    #[cfg(not(any(target_os = "android", target_os = "ios")))]
-    {
-        builder = builder.plugin(tauri_plugin_shell::init());
-    }
-
-    builder
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+    app::run()
 }
@@ -0,0 +1,148 @@
+//! Document Locking
+//!
+//! Advisory cross-process locks around document read-modify-write:
+//! - One hidden `.{file_name}.lock` sidecar per document
+//! - OS-level exclusive lock (`flock` / `LockFileEx`) on the sidecar
+//! - Polling acquisition with a configurable timeout
+//!
+//! The OS releases the lock when the holder exits, so a crashed process
+//! never leaves a document permanently locked. The sidecar file itself is
+//! left in place; deleting it would race with other waiters.
+
+use std::ffi::OsString;
+use std::fs::{File, OpenOptions, TryLockError};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+
+/// Default time to wait for a lock held by another process.
+pub const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Interval between acquisition attempts while waiting.
+const POLL_INTERVAL: Duration = Duration::from_millis(25);
+
+/// Reads the lock timeout from `MEATYCAPTURE_LOCK_TIMEOUT_MS`, falling back
+/// to [`DEFAULT_LOCK_TIMEOUT`] when unset or invalid.
+pub fn lock_timeout_from_env() -> Duration {
+    std::env::var("MEATYCAPTURE_LOCK_TIMEOUT_MS")
+        .ok()
+        .and_then(|ms| ms.trim().parse::<u64>().ok())
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_LOCK_TIMEOUT)
+}
+
+/// Exclusive lock on a document, released on drop.
+#[derive(Debug)]
+pub struct DocLock {
+    // Held only to keep the OS lock alive; closing the handle unlocks
+    _file: File,
+}
+
+impl DocLock {
+    /// Acquires the lock for `path`, waiting up to `timeout`.
+    ///
+    /// Fails with [`Error::Locked`] if another holder keeps the lock for
+    /// the whole timeout. A zero timeout makes a single attempt.
+    pub fn acquire(path: &Path, timeout: Duration) -> Result<DocLock> {
+        let lock_path = lock_path_for(path);
+        if let Some(dir) = lock_path.parent().filter(|dir| !dir.exists()) {
+            std::fs::create_dir_all(dir).map_err(Error::io(format!(
+                "Failed to create directory {}",
+                dir.display()
+            )))?;
+        }
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&lock_path)
+            .map_err(Error::io(format!(
+                "Failed to open lock file {}",
+                lock_path.display()
+            )))?;
+
+        let deadline = Instant::now() + timeout;
+        loop {
+            match file.try_lock() {
+                Ok(()) => return Ok(DocLock { _file: file }),
+                Err(TryLockError::WouldBlock) if Instant::now() < deadline => {
+                    thread::sleep(POLL_INTERVAL.min(deadline - Instant::now()));
+                }
+                Err(TryLockError::WouldBlock) => {
+                    log::warn!(
+                        "Timed out after {}ms waiting for lock on {}",
+                        timeout.as_millis(),
+                        path.display()
+                    );
+                    return Err(Error::Locked {
+                        path: path.display().to_string(),
+                    });
+                }
+                Err(TryLockError::Error(source)) => {
+                    return Err(Error::Io {
+                        context: format!("Failed to lock {}", lock_path.display()),
+                        source,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Returns true for lock sidecars created by [`DocLock`].
+pub fn is_lock_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with('.') && name.ends_with(".lock"))
+}
+
+/// Builds `.{file_name}.lock` next to the document.
+fn lock_path_for(path: &Path) -> PathBuf {
+    let mut name = OsString::from(".");
+    name.push(path.file_name().unwrap_or_default());
+    name.push(".lock");
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn second_holder_times_out_until_first_releases() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("log.md");
+
+        let held = DocLock::acquire(&path, Duration::ZERO).unwrap();
+        let started = Instant::now();
+        let error = DocLock::acquire(&path, Duration::from_millis(100)).unwrap_err();
+
+        assert!(matches!(error, Error::Locked { .. }));
+        assert!(error.to_string().contains("locked by another process"));
+        assert!(started.elapsed() >= Duration::from_millis(100));
+
+        drop(held);
+        assert!(DocLock::acquire(&path, Duration::ZERO).is_ok());
+    }
+
+    #[test]
+    fn waiter_acquires_once_holder_releases() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("log.md");
+
+        let held = DocLock::acquire(&path, Duration::ZERO).unwrap();
+        let waiter = {
+            let path = path.clone();
+            thread::spawn(move || DocLock::acquire(&path, Duration::from_secs(5)).is_ok())
+        };
+        thread::sleep(Duration::from_millis(50));
+        drop(held);
+
+        assert!(waiter.join().unwrap());
+        assert!(is_lock_file(&lock_path_for(&path)));
+    }
+}
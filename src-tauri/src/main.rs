@@ -1,26 +1,20 @@
 // Prevents additional console window on Windows in release builds
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+use std::process::ExitCode;
+
 /**
  * MeatyCapture Desktop Entry Point
  *
- * Tauri application initialization with filesystem plugin support.
- * The frontend handles all business logic - this is just the desktop shell.
+ * Delegates to the library so plugins and native commands are registered
+ * in one place (`meatycapture_lib::run`). `meatycapture mcp` serves the
+ * Model Context Protocol on stdio instead of opening a window.
  */
 
-fn main() {
-    let mut builder = tauri::Builder::default()
-        // Register filesystem plugin for markdown file read/write
-        .plugin(tauri_plugin_fs::init());
-
-    // Register shell plugin for desktop platforms only
-    #[cfg(not(any(target_os = "android", target_os = "ios")))]
-    {
-        builder = builder.plugin(tauri_plugin_shell::init());
+fn main() -> ExitCode {
+    if std::env::args().nth(1).as_deref() == Some("mcp") {
+        return meatycapture_lib::mcp::main();
     }
-
-    // Run the application
-    builder
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+    meatycapture_lib::run();
+    ExitCode::SUCCESS
 }
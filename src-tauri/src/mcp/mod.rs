@@ -0,0 +1,500 @@
+//! MCP Server
+//!
+//! Model Context Protocol server for AI agents, so they can capture and
+//! triage requests through typed tool calls instead of scraping CLI output:
+//! - Transport: JSON-RPC 2.0 over stdio, one message per line
+//! - tools: capture_item, list_projects, list_documents, read_document,
+//!   search_items, update_item_status
+//! - resources: Every request-log of an enabled project as markdown
+//!   (`meatycapture://docs/{project_id}/{doc_id}`)
+//!
+//! Runs as `meatycapture mcp` (or `meatycapture-cli mcp`) and works on the
+//! files directly; the desktop app does not need to be running. Document
+//! paths from the agent go through `ProjectScopes` like the desktop
+//! commands, so only the data directory and enabled projects are reachable.
+
+mod resources;
+mod tools;
+
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use serde_json::{json, Value};
+
+use crate::backups::BackupHistory;
+use crate::config_store::{
+    config_dir, LocalConfigStore, LocalFieldCatalogStore, LocalProjectStore,
+};
+use crate::doc_store::{expand_path, FsDocStore};
+use crate::error::{Error, Result};
+use crate::lock::lock_timeout_from_env;
+use crate::models::{DocMeta, Project};
+use crate::path_guard::Access;
+use crate::ports::{Clock, DocStore, ProjectStore, SystemClock};
+use crate::scope::ProjectScopes;
+
+/// Protocol revision offered when the client asks for an unknown one.
+pub const PROTOCOL_VERSION: &str = "2025-06-18";
+
+/// Protocol revisions this server can speak.
+const SUPPORTED_VERSIONS: [&str; 3] = ["2025-06-18", "2025-03-26", "2024-11-05"];
+
+// JSON-RPC error codes
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+const RESOURCE_NOT_FOUND: i64 = -32002;
+
+/// JSON-RPC error returned instead of a result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        RpcError {
+            code,
+            message: message.into(),
+        }
+    }
+
+    fn invalid_params(message: impl Into<String>) -> Self {
+        Self::new(INVALID_PARAMS, message)
+    }
+}
+
+/// Stores behind the tools and resources.
+pub struct McpServer {
+    pub docs: FsDocStore,
+    pub projects: LocalProjectStore,
+    pub fields: LocalFieldCatalogStore,
+    pub config: LocalConfigStore,
+    pub scopes: ProjectScopes,
+    pub clock: Box<dyn Clock>,
+}
+
+impl McpServer {
+    /// Opens the stores in `config_dir` (defaults to [`config_dir()`]).
+    pub fn new(config_dir: Option<PathBuf>) -> Self {
+        let config_dir = config_dir.unwrap_or_else(self::config_dir);
+        let docs = FsDocStore::with_lock_timeout(lock_timeout_from_env())
+            .with_history(Arc::new(BackupHistory::open(&config_dir)));
+        #[cfg(feature = "git")]
+        let docs = docs.with_git(Arc::new(crate::git::GitAutoCommit::new(
+            LocalProjectStore::new(Some(config_dir.clone())),
+        )));
+        Self {
+            docs,
+            projects: LocalProjectStore::new(Some(config_dir.clone())),
+            fields: LocalFieldCatalogStore::new(Some(config_dir.clone())),
+            config: LocalConfigStore::new(Some(config_dir.clone())),
+            scopes: ProjectScopes::new(config_dir),
+            clock: Box::new(SystemClock),
+        }
+    }
+
+    /// Replaces the system clock (tests).
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Handles one JSON-RPC message.
+    ///
+    /// Returns the response to write, or `None` for notifications.
+    pub fn handle(&self, message: &str) -> Option<Value> {
+        let message: Value = match serde_json::from_str(message) {
+            Ok(message) => message,
+            Err(error) => {
+                return Some(error_response(
+                    Value::Null,
+                    RpcError::new(PARSE_ERROR, format!("Parse error: {error}")),
+                ))
+            }
+        };
+
+        let id = message.get("id").cloned();
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            // Responses to server requests are never expected, so ignore them
+            return id
+                .filter(|_| message.get("result").is_none())
+                .map(|id| error_response(id, RpcError::new(INVALID_REQUEST, "Invalid request")));
+        };
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+        let result = self.dispatch(method, params);
+        let id = id?;
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(error) => error_response(id, error),
+        })
+    }
+
+    fn dispatch(&self, method: &str, params: Value) -> std::result::Result<Value, RpcError> {
+        match method {
+            "initialize" => Ok(initialize(&params)),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({ "tools": tools::definitions() })),
+            "tools/call" => {
+                let name = params
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| RpcError::invalid_params("Missing tool name"))?;
+                let arguments = params.get("arguments").cloned().unwrap_or(json!({}));
+                tools::call(self, name, arguments)
+            }
+            "resources/list" => resources::list(self),
+            "resources/templates/list" => Ok(json!({ "resourceTemplates": [] })),
+            "resources/read" => {
+                let uri = params
+                    .get("uri")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| RpcError::invalid_params("Missing resource uri"))?;
+                resources::read(self, uri)
+            }
+            method if method.starts_with("notifications/") => Ok(Value::Null),
+            method => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("Method not found: {method}"),
+            )),
+        }
+    }
+
+    /// Resolves an agent-supplied document path for `access`.
+    ///
+    /// Scopes are re-synced first because `projects.json` may change while
+    /// the server runs.
+    fn check_path(&self, path: &str, access: Access) -> Result<String> {
+        self.scopes.sync(&self.projects)?;
+        self.scopes.check_str(path, access)
+    }
+
+    /// Documents of one project, or of every enabled project.
+    ///
+    /// Missing project directories contribute nothing.
+    fn project_docs(&self, project_id: Option<&str>) -> Result<Vec<(Project, DocMeta)>> {
+        let projects = match project_id {
+            Some(id) => vec![self
+                .projects
+                .get(id)?
+                .ok_or_else(|| Error::NotFound(format!("Project not found: {id}")))?],
+            None => self
+                .projects
+                .list()?
+                .into_iter()
+                .filter(|project| project.enabled)
+                .collect(),
+        };
+
+        let mut docs = Vec::new();
+        for project in projects {
+            let dir = expand_path(&project.default_path);
+            for meta in self.docs.list(&dir.display().to_string())? {
+                docs.push((project.clone(), meta));
+            }
+        }
+        Ok(docs)
+    }
+}
+
+/// Result of `initialize`: echoes a supported protocol version.
+fn initialize(params: &Value) -> Value {
+    let requested = params.get("protocolVersion").and_then(Value::as_str);
+    let version = requested
+        .filter(|version| SUPPORTED_VERSIONS.contains(version))
+        .unwrap_or(PROTOCOL_VERSION);
+
+    json!({
+        "protocolVersion": version,
+        "capabilities": { "tools": {}, "resources": {} },
+        "serverInfo": { "name": "meatycapture", "version": env!("CARGO_PKG_VERSION") },
+        "instructions": "Capture and triage request-log items (bugs, enhancements, ideas). \
+            Use list_projects to find project IDs, capture_item to log new items and \
+            search_items / update_item_status to triage them.",
+    })
+}
+
+fn error_response(id: Value, error: RpcError) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": error.code, "message": error.message },
+    })
+}
+
+/// Serves messages from `input` until EOF, writing one response per line.
+pub fn serve(server: &McpServer, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = server.handle(&line) {
+            serde_json::to_writer(&mut output, &response)?;
+            output.write_all(b"\n")?;
+            output.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// Entry point of `meatycapture mcp`: serves stdin/stdout until EOF.
+pub fn main() -> ExitCode {
+    let server = McpServer::new(None);
+    match serve(&server, io::stdin().lock(), io::stdout().lock()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("MCP server stopped: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::NewProject;
+    use chrono::{DateTime, TimeZone, Utc};
+    use tempfile::TempDir;
+
+    struct FixedClock;
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            Utc.with_ymd_and_hms(2025, 12, 3, 12, 0, 0).unwrap()
+        }
+    }
+
+    fn setup() -> (TempDir, McpServer) {
+        let temp = TempDir::new().unwrap();
+        let server = McpServer::new(Some(temp.path().join("config"))).with_clock(FixedClock);
+        server
+            .projects
+            .create(NewProject {
+                id: None,
+                name: "App".to_string(),
+                default_path: temp.path().join("docs").display().to_string(),
+                repo_url: None,
+                enabled: true,
+                auto_commit: None,
+            })
+            .unwrap();
+        (temp, server)
+    }
+
+    /// Sends a request and returns its `result` (panics on errors).
+    fn request(server: &McpServer, method: &str, params: Value) -> Value {
+        let message = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let response = server.handle(&message.to_string()).unwrap();
+        assert_eq!(response["id"], 1);
+        response
+            .get("result")
+            .cloned()
+            .unwrap_or_else(|| panic!("{method} failed: {response}"))
+    }
+
+    fn call(server: &McpServer, tool: &str, arguments: Value) -> Value {
+        request(
+            server,
+            "tools/call",
+            json!({ "name": tool, "arguments": arguments }),
+        )
+    }
+
+    #[test]
+    fn serves_initialize_and_tool_list_over_lines() {
+        let (_temp, server) = setup();
+        let input = [
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize",
+                    "params": { "protocolVersion": "2025-03-26", "capabilities": {} } }),
+            json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+            json!({ "jsonrpc": "2.0", "id": 2, "method": "tools/list" }),
+            json!({ "jsonrpc": "2.0", "id": 3, "method": "bogus" }),
+        ]
+        .map(|message| message.to_string())
+        .join("\n")
+            + "\nnot json\n";
+
+        let mut output = Vec::new();
+        serve(&server, input.as_bytes(), &mut output).unwrap();
+        let responses: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(responses.len(), 4, "notifications get no response");
+        assert_eq!(responses[0]["result"]["protocolVersion"], "2025-03-26");
+        let names: Vec<&str> = responses[1]["result"]["tools"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|tool| tool["name"].as_str().unwrap())
+            .collect();
+        assert!(names.contains(&"capture_item") && names.contains(&"update_item_status"));
+        assert_eq!(responses[2]["error"]["code"], METHOD_NOT_FOUND);
+        assert_eq!(responses[3]["error"]["code"], PARSE_ERROR);
+    }
+
+    #[test]
+    fn capture_search_and_triage() {
+        let (_temp, server) = setup();
+
+        let first = call(
+            &server,
+            "capture_item",
+            json!({ "project": "app", "title": "Dark mode", "type": "enhancement", "tags": ["ux"] }),
+        );
+        assert_eq!(first["isError"], false);
+        let first = &first["structuredContent"];
+        assert_eq!(first["created_document"], true);
+        assert_eq!(first["item"]["id"], "REQ-20251203-app-01");
+        assert_eq!(first["item"]["status"], "triage");
+
+        let second = call(
+            &server,
+            "capture_item",
+            json!({ "project": "app", "title": "Crash on save", "type": "bug", "notes": "Stack overflow" }),
+        );
+        let second = &second["structuredContent"];
+        assert_eq!(second["created_document"], false);
+        assert_eq!(second["item_count"], 2);
+        let doc_path = second["doc_path"].as_str().unwrap();
+
+        let found = call(&server, "search_items", json!({ "query": "STACK" }));
+        assert_eq!(found["structuredContent"]["total"], 1);
+        assert_eq!(
+            found["structuredContent"]["items"][0]["item"]["id"],
+            "REQ-20251203-app-02"
+        );
+
+        let rejected = call(
+            &server,
+            "update_item_status",
+            json!({ "doc_path": doc_path, "item_id": "REQ-20251203-app-02", "status": "shipped" }),
+        );
+        assert_eq!(rejected["isError"], true);
+        assert!(rejected["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("Valid statuses: triage"));
+
+        let updated = call(
+            &server,
+            "update_item_status",
+            json!({ "doc_path": doc_path, "item_id": "REQ-20251203-app-02", "status": "planned" }),
+        );
+        assert_eq!(updated["structuredContent"]["previous_status"], "triage");
+        let triage = call(&server, "search_items", json!({ "status": "triage" }));
+        assert_eq!(triage["structuredContent"]["total"], 1);
+
+        let documents = call(&server, "list_documents", json!({}));
+        assert_eq!(
+            documents["structuredContent"]["documents"][0]["project_id"],
+            "app"
+        );
+    }
+
+    #[test]
+    fn document_tools_reject_paths_outside_enabled_projects() {
+        let (temp, server) = setup();
+        let docs = temp.path().join("docs");
+        std::fs::create_dir_all(&docs).unwrap();
+        std::fs::write(docs.join("notes.txt"), "plain text").unwrap();
+        let escape = format!("{}/../../etc/passwd.md", docs.display());
+        let text = docs.join("notes.txt").display().to_string();
+
+        let cases = [
+            ("read_document", json!({ "doc_path": "/etc/passwd" })),
+            ("read_document", json!({ "doc_path": escape })),
+            (
+                "capture_item",
+                json!({ "doc_path": "/etc/passwd", "title": "Pwn", "type": "bug" }),
+            ),
+            (
+                "capture_item",
+                json!({ "doc_path": escape, "title": "Pwn", "type": "bug" }),
+            ),
+            (
+                "capture_item",
+                json!({ "doc_path": text, "title": "Pwn", "type": "bug" }),
+            ),
+            (
+                "update_item_status",
+                json!({ "doc_path": "/etc/passwd", "item_id": "x", "status": "planned" }),
+            ),
+            (
+                "update_item_status",
+                json!({ "doc_path": text, "item_id": "x", "status": "planned" }),
+            ),
+        ];
+        for (tool, arguments) in cases {
+            let result = call(&server, tool, arguments.clone());
+            let message = result["content"][0]["text"].as_str().unwrap();
+            assert_eq!(result["isError"], true, "{tool} {arguments}");
+            assert!(
+                message.contains("Access denied") || message.contains("Invalid path"),
+                "{tool}: {message}"
+            );
+        }
+        assert_eq!(
+            std::fs::read_to_string(docs.join("notes.txt")).unwrap(),
+            "plain text"
+        );
+    }
+
+    #[test]
+    fn capture_rejects_disabled_projects() {
+        let (_temp, server) = setup();
+        server
+            .projects
+            .update(
+                "app",
+                crate::models::ProjectUpdate {
+                    enabled: Some(false),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        let result = call(
+            &server,
+            "capture_item",
+            json!({ "project": "app", "title": "Dark mode", "type": "enhancement" }),
+        );
+        assert_eq!(result["isError"], true);
+        assert!(result["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("Project app is disabled"));
+    }
+
+    #[test]
+    fn resources_list_and_read_project_documents() {
+        let (_temp, server) = setup();
+        call(
+            &server,
+            "capture_item",
+            json!({ "project": "app", "title": "Dark mode", "type": "enhancement" }),
+        );
+
+        let listed = request(&server, "resources/list", json!({}));
+        let uri = listed["resources"][0]["uri"].as_str().unwrap();
+        assert_eq!(uri, "meatycapture://docs/app/REQ-20251203-app");
+
+        let read = request(&server, "resources/read", json!({ "uri": uri }));
+        let text = read["contents"][0]["text"].as_str().unwrap();
+        assert!(text.contains("Dark mode"));
+
+        let message = json!({ "jsonrpc": "2.0", "id": 1, "method": "resources/read",
+                              "params": { "uri": "meatycapture://docs/app/../../etc/passwd" } });
+        let response = server.handle(&message.to_string()).unwrap();
+        assert_eq!(response["error"]["code"], RESOURCE_NOT_FOUND);
+    }
+}
@@ -0,0 +1,75 @@
+//! MCP Resources
+//!
+//! Exposes each request-log of an enabled project as a markdown resource:
+//! - URI: `meatycapture://docs/{project_id}/{doc_id}`
+//! - Content: The file exactly as stored on disk
+//!
+//! Only documents found in project directories can be read, so a URI can
+//! never point the server at an arbitrary file.
+
+use std::fs;
+
+use serde_json::{json, Value};
+
+use crate::error::Error;
+use crate::mcp::{McpServer, RpcError, INTERNAL_ERROR, RESOURCE_NOT_FOUND};
+use crate::models::{DocMeta, Project};
+
+/// URI scheme and authority prefix of document resources.
+const URI_PREFIX: &str = "meatycapture://docs/";
+
+fn resource_uri(project: &Project, meta: &DocMeta) -> String {
+    format!("{URI_PREFIX}{}/{}", project.id, meta.doc_id)
+}
+
+fn store_error(error: Error) -> RpcError {
+    RpcError::new(INTERNAL_ERROR, error.to_string())
+}
+
+/// Result of `resources/list`.
+pub(super) fn list(server: &McpServer) -> Result<Value, RpcError> {
+    let resources: Vec<Value> = server
+        .project_docs(None)
+        .map_err(store_error)?
+        .into_iter()
+        .map(|(project, meta)| {
+            json!({
+                "uri": resource_uri(&project, &meta),
+                "name": meta.doc_id,
+                "title": meta.title,
+                "description": format!(
+                    "Request log of project {} ({} items)",
+                    project.name, meta.item_count
+                ),
+                "mimeType": "text/markdown",
+            })
+        })
+        .collect();
+    Ok(json!({ "resources": resources }))
+}
+
+/// Result of `resources/read`.
+pub(super) fn read(server: &McpServer, uri: &str) -> Result<Value, RpcError> {
+    let not_found = || RpcError::new(RESOURCE_NOT_FOUND, format!("Resource not found: {uri}"));
+    let (project_id, doc_id) = uri
+        .strip_prefix(URI_PREFIX)
+        .and_then(|rest| rest.split_once('/'))
+        .ok_or_else(not_found)?;
+
+    let docs = match server.project_docs(Some(project_id)) {
+        Ok(docs) => docs,
+        Err(Error::NotFound(_)) => return Err(not_found()),
+        Err(error) => return Err(store_error(error)),
+    };
+    let (_, meta) = docs
+        .into_iter()
+        .find(|(_, meta)| meta.doc_id == doc_id)
+        .ok_or_else(not_found)?;
+    let text = fs::read_to_string(&meta.path)
+        .map_err(Error::io(format!("Failed to read document {}", meta.path)))
+        .map_err(store_error)?;
+
+    Ok(json!({
+        "contents": [{ "uri": uri, "mimeType": "text/markdown", "text": text }]
+    }))
+}
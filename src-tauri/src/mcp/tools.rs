@@ -0,0 +1,446 @@
+//! MCP Tools
+//!
+//! Tool definitions (name, description, JSON Schema input) and handlers:
+//! - capture_item: Append to today's project document, creating it if needed
+//! - list_projects: Registered projects
+//! - list_documents: Document metadata for one or all enabled projects
+//! - read_document: Full document with items
+//! - search_items: Case-insensitive term search with status/type filters
+//! - update_item_status: Change an item's status (checked against the catalog)
+//!
+//! Failures inside a tool are returned as `isError` results so the agent
+//! can read the message and retry; only unknown tools and malformed
+//! arguments are protocol errors.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::doc_store::capture_to_daily_doc;
+use crate::error::{Error, Result};
+use crate::mcp::{McpServer, RpcError};
+use crate::models::{DocMeta, FieldName, ItemDraft, RequestLogDoc, RequestLogItem};
+use crate::path_guard::Access;
+use crate::ports::{ConfigStore, DocStore, FieldCatalogStore, ProjectStore};
+
+/// Default and maximum number of `search_items` results.
+const DEFAULT_SEARCH_LIMIT: usize = 20;
+const MAX_SEARCH_LIMIT: usize = 200;
+
+/// Tool list returned by `tools/list`.
+pub(super) fn definitions() -> Value {
+    json!([
+        {
+            "name": "capture_item",
+            "title": "Capture item",
+            "description": "Log a new request-log item (bug, enhancement, idea, task, question). \
+                Appends to doc_path when given, otherwise to today's document of the project \
+                (created on first capture). project defaults to the configured default_project.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "title": { "type": "string", "description": "Short summary" },
+                    "type": { "type": "string", "description": "enhancement, bug, idea, task or question" },
+                    "project": { "type": "string", "description": "Project ID (see list_projects)" },
+                    "doc_path": { "type": "string", "description": "Existing document to append to" },
+                    "priority": { "type": "string", "default": "medium" },
+                    "status": { "type": "string", "default": "triage" },
+                    "domain": { "type": "string" },
+                    "context": { "type": "string" },
+                    "tags": { "type": "array", "items": { "type": "string" } },
+                    "notes": { "type": "string", "description": "Problem/goal details (markdown)" }
+                },
+                "required": ["title", "type"]
+            }
+        },
+        {
+            "name": "list_projects",
+            "title": "List projects",
+            "description": "List registered projects with their document directories.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "include_disabled": { "type": "boolean", "default": false }
+                }
+            }
+        },
+        {
+            "name": "list_documents",
+            "title": "List documents",
+            "description": "List request-log documents (newest first) of one project, \
+                or of every enabled project.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "project": { "type": "string", "description": "Project ID" }
+                }
+            }
+        },
+        {
+            "name": "read_document",
+            "title": "Read document",
+            "description": "Read a request-log document with all of its items.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "doc_path": { "type": "string", "description": "Path from list_documents" }
+                },
+                "required": ["doc_path"]
+            }
+        },
+        {
+            "name": "search_items",
+            "title": "Search items",
+            "description": "Find items whose title, notes, context, tags or ID contain every \
+                query term (case-insensitive). Filter by project, status or type; an empty \
+                query lists all items matching the filters, newest first.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "query": { "type": "string" },
+                    "project": { "type": "string" },
+                    "status": { "type": "string" },
+                    "type": { "type": "string" },
+                    "limit": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": MAX_SEARCH_LIMIT,
+                        "default": DEFAULT_SEARCH_LIMIT
+                    }
+                }
+            }
+        },
+        {
+            "name": "update_item_status",
+            "title": "Update item status",
+            "description": "Set an item's status (e.g. triage, backlog, planned, in-progress, \
+                done, wontfix). The status must be an option of the document's project.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "doc_path": { "type": "string" },
+                    "item_id": { "type": "string" },
+                    "status": { "type": "string" }
+                },
+                "required": ["doc_path", "item_id", "status"]
+            }
+        }
+    ])
+}
+
+/// Runs a tool and wraps its output as a `tools/call` result.
+pub(super) fn call(
+    server: &McpServer,
+    name: &str,
+    arguments: Value,
+) -> std::result::Result<Value, RpcError> {
+    let result = match name {
+        "capture_item" => capture_item(server, parse_args(arguments)?),
+        "list_projects" => list_projects(server, parse_args(arguments)?),
+        "list_documents" => list_documents(server, parse_args(arguments)?),
+        "read_document" => read_document(server, parse_args(arguments)?),
+        "search_items" => search_items(server, parse_args(arguments)?),
+        "update_item_status" => update_item_status(server, parse_args(arguments)?),
+        _ => return Err(RpcError::invalid_params(format!("Unknown tool: {name}"))),
+    };
+
+    Ok(match result {
+        Ok(value) => json!({
+            "content": [{ "type": "text", "text": value.to_string() }],
+            "structuredContent": value,
+            "isError": false,
+        }),
+        Err(error) => json!({
+            "content": [{ "type": "text", "text": error.to_string() }],
+            "isError": true,
+        }),
+    })
+}
+
+fn parse_args<T: DeserializeOwned>(arguments: Value) -> std::result::Result<T, RpcError> {
+    serde_json::from_value(arguments)
+        .map_err(|error| RpcError::invalid_params(format!("Invalid arguments: {error}")))
+}
+
+fn to_value(value: impl Serialize) -> Result<Value> {
+    serde_json::to_value(value).map_err(Error::json("Failed to serialize tool result"))
+}
+
+// ============================================================================
+// capture_item
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+struct CaptureArgs {
+    title: String,
+    #[serde(rename = "type")]
+    item_type: String,
+    #[serde(default)]
+    project: Option<String>,
+    #[serde(default)]
+    doc_path: Option<String>,
+    #[serde(default = "default_priority")]
+    priority: String,
+    #[serde(default = "default_status")]
+    status: String,
+    #[serde(default)]
+    domain: String,
+    #[serde(default)]
+    context: String,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    notes: String,
+}
+
+fn default_priority() -> String {
+    "medium".to_string()
+}
+
+fn default_status() -> String {
+    "triage".to_string()
+}
+
+fn capture_item(server: &McpServer, args: CaptureArgs) -> Result<Value> {
+    if args.title.trim().is_empty() {
+        return Err(Error::Validation("title must not be empty".to_string()));
+    }
+    let draft = ItemDraft {
+        title: args.title,
+        item_type: args.item_type,
+        domain: args.domain,
+        context: args.context,
+        priority: args.priority,
+        status: args.status,
+        tags: args.tags,
+        notes: args.notes,
+    };
+
+    if let Some(doc_path) = args.doc_path {
+        let path = server.check_path(&doc_path, Access::Write)?;
+        let doc = server.docs.append(&path, draft, server.clock.as_ref())?;
+        return captured(doc, path, false);
+    }
+
+    let project_id = match args.project.filter(|id| !id.is_empty()) {
+        Some(id) => id,
+        None => server.config.get()?.default_project.ok_or_else(|| {
+            Error::Validation(
+                "project is required (no default_project configured); see list_projects"
+                    .to_string(),
+            )
+        })?,
+    };
+    let project = server
+        .projects
+        .get(&project_id)?
+        .ok_or_else(|| Error::NotFound(format!("Project not found: {project_id}")))?;
+    if !project.enabled {
+        return Err(Error::Validation(format!(
+            "Project {project_id} is disabled"
+        )));
+    }
+
+    let capture = capture_to_daily_doc(&server.docs, &project, draft, server.clock.as_ref())?;
+    captured(capture.doc, capture.path, capture.created)
+}
+
+/// `capture_item` result: the new item plus where it was written.
+fn captured(mut doc: RequestLogDoc, doc_path: String, created: bool) -> Result<Value> {
+    let item = doc.items.pop();
+    to_value(json!({
+        "doc_id": doc.doc_id,
+        "doc_path": doc_path,
+        "created_document": created,
+        "item_count": doc.item_count,
+        "item": item,
+    }))
+}
+
+// ============================================================================
+// list_projects / list_documents / read_document
+// ============================================================================
+
+#[derive(Debug, Default, Deserialize)]
+struct ListProjectsArgs {
+    #[serde(default)]
+    include_disabled: bool,
+}
+
+fn list_projects(server: &McpServer, args: ListProjectsArgs) -> Result<Value> {
+    let projects: Vec<_> = server
+        .projects
+        .list()?
+        .into_iter()
+        .filter(|project| args.include_disabled || project.enabled)
+        .collect();
+    let default_project = server.config.get()?.default_project;
+    to_value(json!({ "projects": projects, "default_project": default_project }))
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ListDocumentsArgs {
+    #[serde(default)]
+    project: Option<String>,
+}
+
+/// Document listing entry tagged with its project.
+#[derive(Debug, Serialize)]
+struct ProjectDoc {
+    project_id: String,
+    #[serde(flatten)]
+    meta: DocMeta,
+}
+
+fn list_documents(server: &McpServer, args: ListDocumentsArgs) -> Result<Value> {
+    let mut documents: Vec<ProjectDoc> = server
+        .project_docs(args.project.as_deref())?
+        .into_iter()
+        .map(|(project, meta)| ProjectDoc {
+            project_id: project.id,
+            meta,
+        })
+        .collect();
+    documents.sort_by_key(|doc| std::cmp::Reverse(doc.meta.updated_at));
+    to_value(json!({ "documents": documents }))
+}
+
+#[derive(Debug, Deserialize)]
+struct ReadDocumentArgs {
+    doc_path: String,
+}
+
+fn read_document(server: &McpServer, args: ReadDocumentArgs) -> Result<Value> {
+    let path = server.check_path(&args.doc_path, Access::Read)?;
+    to_value(server.docs.read(&path)?)
+}
+
+// ============================================================================
+// search_items
+// ============================================================================
+
+#[derive(Debug, Default, Deserialize)]
+struct SearchArgs {
+    #[serde(default)]
+    query: String,
+    #[serde(default)]
+    project: Option<String>,
+    #[serde(default)]
+    status: Option<String>,
+    #[serde(default, rename = "type")]
+    item_type: Option<String>,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+/// Matching item with the document it lives in.
+#[derive(Debug, Serialize)]
+struct ItemMatch {
+    doc_path: String,
+    doc_id: String,
+    project_id: String,
+    item: RequestLogItem,
+}
+
+/// Whether every lowercase `term` occurs in one of the item's text fields.
+fn matches_terms(item: &RequestLogItem, terms: &[String]) -> bool {
+    let haystacks = [
+        item.title.to_lowercase(),
+        item.notes.to_lowercase(),
+        item.context.to_lowercase(),
+        item.id.to_lowercase(),
+        item.tags.join(" ").to_lowercase(),
+    ];
+    terms
+        .iter()
+        .all(|term| haystacks.iter().any(|text| text.contains(term.as_str())))
+}
+
+/// Scans documents directly (the desktop app may hold the search index).
+fn search_items(server: &McpServer, args: SearchArgs) -> Result<Value> {
+    let limit = args
+        .limit
+        .unwrap_or(DEFAULT_SEARCH_LIMIT)
+        .clamp(1, MAX_SEARCH_LIMIT);
+    let terms: Vec<String> = args
+        .query
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect();
+    let same = |filter: &Option<String>, value: &str| {
+        filter
+            .as_deref()
+            .is_none_or(|filter| filter.eq_ignore_ascii_case(value))
+    };
+
+    let mut matches = Vec::new();
+    for (project, meta) in server.project_docs(args.project.as_deref())? {
+        let doc = match server.docs.read(&meta.path) {
+            Ok(doc) => doc,
+            Err(error) => {
+                log::warn!("Skipping document in search - {error}");
+                continue;
+            }
+        };
+        for item in doc.items {
+            if same(&args.status, &item.status)
+                && same(&args.item_type, &item.item_type)
+                && matches_terms(&item, &terms)
+            {
+                matches.push(ItemMatch {
+                    doc_path: meta.path.clone(),
+                    doc_id: doc.doc_id.clone(),
+                    project_id: project.id.clone(),
+                    item,
+                });
+            }
+        }
+    }
+
+    matches.sort_by_key(|m| std::cmp::Reverse(m.item.created_at));
+    let total = matches.len();
+    matches.truncate(limit);
+    to_value(json!({ "total": total, "items": matches }))
+}
+
+// ============================================================================
+// update_item_status
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+struct UpdateStatusArgs {
+    doc_path: String,
+    item_id: String,
+    status: String,
+}
+
+fn update_item_status(server: &McpServer, args: UpdateStatusArgs) -> Result<Value> {
+    let path = server.check_path(&args.doc_path, Access::Write)?;
+    let project_id = server.docs.read(&path)?.project_id;
+    let allowed: Vec<String> = server
+        .fields
+        .get_by_field(FieldName::Status, Some(&project_id))?
+        .into_iter()
+        .map(|option| option.value)
+        .collect();
+    if !allowed.contains(&args.status) {
+        return Err(Error::Validation(format!(
+            "Invalid status \"{}\". Valid statuses: {}",
+            args.status,
+            allowed.join(", ")
+        )));
+    }
+
+    let mut previous = String::new();
+    let doc = server
+        .docs
+        .update_item(&path, &args.item_id, server.clock.as_ref(), |item| {
+            previous = std::mem::replace(&mut item.status, args.status.clone())
+        })?;
+    let item = doc.items.into_iter().find(|item| item.id == args.item_id);
+    to_value(json!({
+        "doc_id": doc.doc_id,
+        "previous_status": previous,
+        "item": item,
+    }))
+}
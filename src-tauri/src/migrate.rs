@@ -0,0 +1,323 @@
+//! Request-Log Schema Migrations
+//!
+//! Upgrades request-log files to [`SCHEMA_VERSION`]:
+//! - Detects each file's `schema_version` (no key means version 1)
+//! - Applies the ordered [`MIGRATIONS`] one version at a time
+//! - Previews a rewrite as a unified diff (dry run)
+//! - Migrates every document in a project directory, backing each file up
+//!   before it is rewritten (see `FsDocStore::migrate`)
+
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::doc_store::{expand_path, FsDocStore};
+use crate::error::{Error, Result};
+use crate::models::SCHEMA_VERSION;
+use crate::serializer::{
+    aggregate_tags, parse, schema_version, serialize, update_items_index, ParseError,
+};
+
+/// One upgrade step from `from` to `from + 1`, applied to the file text.
+pub struct Migration {
+    /// Version this step upgrades from
+    pub from: u32,
+    /// Summary shown in migration reports
+    pub description: &'static str,
+    migrate: fn(&str) -> std::result::Result<String, ParseError>,
+}
+
+/// Every migration, ordered by `from`.
+pub const MIGRATIONS: &[Migration] = &[Migration {
+    from: 1,
+    description: "Add schema_version and rebuild item_count, tags and items_index from the items",
+    migrate: v1_to_v2,
+}];
+
+/// Version 1 files carry no version key, and hand edits could leave their
+/// summary fields out of step with the items.
+fn v1_to_v2(content: &str) -> std::result::Result<String, ParseError> {
+    let mut doc = parse(content)?;
+    doc.schema_version = 2;
+    doc.item_count = doc.items.len() as u64;
+    doc.tags = aggregate_tags(&doc.items);
+    doc.items_index = update_items_index(&doc.items);
+    Ok(serialize(&doc))
+}
+
+/// Request-log text upgraded to [`SCHEMA_VERSION`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Upgrade {
+    /// Version detected in the original text
+    pub from_version: u32,
+    /// Descriptions of the migrations applied, in order
+    pub applied: Vec<&'static str>,
+    /// Upgraded text (unchanged when already current)
+    pub content: String,
+}
+
+/// Applies every migration `content` needs to reach [`SCHEMA_VERSION`].
+///
+/// `path` is only used in error messages. Fails with [`Error::Validation`]
+/// for files written by a newer version of the app.
+pub fn upgrade(path: &Path, content: &str) -> Result<Upgrade> {
+    let parse_error = |source| Error::Parse {
+        path: path.display().to_string(),
+        source,
+    };
+    let from_version = schema_version(content).map_err(parse_error)?;
+    if from_version > SCHEMA_VERSION {
+        return Err(Error::Validation(format!(
+            "{} has schema_version {from_version}, newer than the supported {SCHEMA_VERSION}; \
+             update MeatyCapture to migrate it",
+            path.display()
+        )));
+    }
+
+    let mut version = from_version;
+    let mut content = content.to_string();
+    let mut applied = Vec::new();
+    while version < SCHEMA_VERSION {
+        let migration = MIGRATIONS
+            .iter()
+            .find(|migration| migration.from == version)
+            .ok_or_else(|| {
+                Error::Validation(format!("No migration from schema_version {version}"))
+            })?;
+        content = (migration.migrate)(&content).map_err(parse_error)?;
+
+        let migrated = schema_version(&content).map_err(parse_error)?;
+        if migrated != version + 1 {
+            return Err(Error::Validation(format!(
+                "Migration from schema_version {version} produced version {migrated}"
+            )));
+        }
+        applied.push(migration.description);
+        version = migrated;
+    }
+
+    Ok(Upgrade {
+        from_version,
+        applied,
+        content,
+    })
+}
+
+/// One document upgraded by [`migrate_documents`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DocMigration {
+    pub path: String,
+    pub from_version: u32,
+    pub to_version: u32,
+    /// Descriptions of the migrations applied, in order
+    pub applied: Vec<String>,
+    /// Unified diff of the rewrite
+    pub diff: String,
+    /// Backup of the original file (`None` in a dry run)
+    pub backup: Option<String>,
+}
+
+/// A markdown file [`migrate_documents`] could not upgrade.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MigrationFailure {
+    pub path: String,
+    pub error: String,
+}
+
+/// Result of migrating a project directory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MigrationReport {
+    pub directory: String,
+    /// Nothing was written
+    pub dry_run: bool,
+    /// Documents that were (or in a dry run would be) upgraded
+    pub migrated: Vec<DocMigration>,
+    /// Documents already at the current version
+    pub current: Vec<String>,
+    /// Request-log files that could not be upgraded
+    pub failed: Vec<MigrationFailure>,
+}
+
+/// Upgrades every request-log document in `directory` to
+/// [`SCHEMA_VERSION`].
+///
+/// Like `list`, only `.md` files directly in the directory are considered
+/// and markdown without frontmatter is ignored. A document that fails does
+/// not stop the others; it is reported in `failed`. With `dry_run` the
+/// report carries the diffs and no file is touched.
+pub fn migrate_documents(
+    store: &FsDocStore,
+    directory: &str,
+    dry_run: bool,
+) -> Result<MigrationReport> {
+    let dir = expand_path(directory);
+    let entries = fs::read_dir(&dir).map_err(Error::io(format!(
+        "Failed to list documents in {}",
+        dir.display()
+    )))?;
+
+    let mut paths: Vec<_> = entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_file()))
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    paths.sort();
+
+    let mut report = MigrationReport {
+        directory: dir.display().to_string(),
+        dry_run,
+        migrated: Vec::new(),
+        current: Vec::new(),
+        failed: Vec::new(),
+    };
+    for path in paths {
+        let path_str = path.display().to_string();
+        match store.migrate(&path_str, dry_run) {
+            Ok(Some(migration)) => report.migrated.push(migration),
+            Ok(None) => report.current.push(path_str),
+            Err(Error::Parse {
+                source: ParseError::MissingFrontmatter | ParseError::EmptyFrontmatter,
+                ..
+            }) => log::debug!("Skipping non request-log file {path_str}"),
+            Err(error) => report.failed.push(MigrationFailure {
+                path: path_str,
+                error: error.to_string(),
+            }),
+        }
+    }
+
+    log::info!(
+        "Migration of {}{}: {} migrated, {} current, {} failed",
+        report.directory,
+        if dry_run { " (dry run)" } else { "" },
+        report.migrated.len(),
+        report.current.len(),
+        report.failed.len()
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const V1: &str = "---
+type: request-log
+doc_id: REQ-20251203-app
+title: App Log
+project_id: app
+item_count: 5
+tags: [stale]
+items_index:
+created_at: 2025-12-03T10:00:00.000Z
+updated_at: 2025-12-03T10:00:00.000Z
+owner: alice
+---
+
+## REQ-20251203-app-01 - One
+
+**Type:** bug | **Domain:** api | **Priority:** low | **Status:** backlog
+**Tags:** api
+**Context:** ctx
+
+### Problem/Goal
+Notes
+";
+
+    fn write(dir: &Path, name: &str, content: &str) -> String {
+        let path = dir.join(name);
+        fs::write(&path, content).unwrap();
+        path.display().to_string()
+    }
+
+    #[test]
+    fn upgrade_rebuilds_summary_and_keeps_unknown_keys() {
+        let upgrade = upgrade(Path::new("doc.md"), V1).unwrap();
+
+        assert_eq!(upgrade.from_version, 1);
+        assert_eq!(upgrade.applied, vec![MIGRATIONS[0].description]);
+        let doc = parse(&upgrade.content).unwrap();
+        assert_eq!(doc.schema_version, SCHEMA_VERSION);
+        assert_eq!(doc.item_count, 1);
+        assert_eq!(doc.tags, vec!["api"]);
+        assert_eq!(doc.items_index.len(), 1);
+        assert_eq!(doc.extra_frontmatter, vec!["owner: alice"]);
+        assert!(upgrade
+            .content
+            .contains("type: request-log\nschema_version: 2\n"));
+    }
+
+    #[test]
+    fn upgrade_leaves_current_documents_alone() {
+        let current = upgrade(Path::new("doc.md"), V1).unwrap().content;
+        let again = upgrade(Path::new("doc.md"), &current).unwrap();
+
+        assert_eq!(again.from_version, SCHEMA_VERSION);
+        assert!(again.applied.is_empty());
+        assert_eq!(again.content, current);
+    }
+
+    #[test]
+    fn upgrade_rejects_newer_versions() {
+        let newer = V1.replace(
+            "type: request-log\n",
+            "type: request-log\nschema_version: 99\n",
+        );
+
+        assert!(matches!(
+            upgrade(Path::new("doc.md"), &newer),
+            Err(Error::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn dry_run_reports_diffs_without_writing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write(dir.path(), "a.md", V1);
+        write(dir.path(), "README.md", "# Not a request log\n");
+        let store = FsDocStore::new();
+
+        let report = migrate_documents(&store, dir.path().to_str().unwrap(), true).unwrap();
+
+        assert_eq!(report.migrated.len(), 1);
+        let migration = &report.migrated[0];
+        assert_eq!(migration.path, path);
+        assert_eq!((migration.from_version, migration.to_version), (1, 2));
+        assert!(migration.diff.contains("\n-item_count: 5\n"));
+        assert!(migration.diff.contains("\n+item_count: 1\n"));
+        assert!(migration.diff.contains("+schema_version: 2\n"));
+        assert_eq!(migration.backup, None);
+        assert!(report.failed.is_empty());
+        assert_eq!(fs::read_to_string(&path).unwrap(), V1);
+        assert!(!Path::new(&format!("{path}.bak")).exists());
+    }
+
+    #[test]
+    fn migrate_backs_up_and_rewrites_each_document() {
+        let dir = tempfile::tempdir().unwrap();
+        let old = write(dir.path(), "a.md", V1);
+        let broken = write(
+            dir.path(),
+            "b.md",
+            &V1.replace("doc_id: REQ-20251203-app\n", ""),
+        );
+        let store = FsDocStore::new().without_backups();
+        let directory = dir.path().to_str().unwrap();
+
+        let report = migrate_documents(&store, directory, false).unwrap();
+
+        assert_eq!(report.migrated.len(), 1);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].path, broken);
+        let backup = report.migrated[0].backup.clone().unwrap();
+        assert_eq!(fs::read_to_string(backup).unwrap(), V1);
+        let doc = parse(&fs::read_to_string(&old).unwrap()).unwrap();
+        assert_eq!(doc.schema_version, SCHEMA_VERSION);
+
+        let again = migrate_documents(&store, directory, false).unwrap();
+        assert!(again.migrated.is_empty());
+        assert_eq!(again.current, vec![old]);
+    }
+}
@@ -0,0 +1,846 @@
+//! Domain Models
+//!
+//! Native mirrors of the core domain types in `src/core/models`:
+//! - Project: Project configuration with paths and metadata
+//! - AppConfig: Application configuration (`config.json`)
+//! - FieldOption: Field catalog options (global/project scoped)
+//! - ItemDraft: Request log item being created
+//! - RequestLogItem: Persisted item in a request-log document
+//! - ItemIndexEntry: Frontmatter quick-reference entry
+//! - RequestLogDoc: Complete request-log document structure
+//! - DocMeta: Lightweight document listing entry
+//!
+//! Field names serialize exactly as the TypeScript interfaces so values can
+//! cross the IPC boundary unchanged. Timestamps use the `toISOString` format
+//! (millisecond precision, `Z` suffix) so JSON files stay byte-compatible
+//! with the ones the TS adapters write.
+//!
+//! The TS interfaces are derived from these types: JSON Schemas are checked
+//! in under `src-tauri/schemas/` and TypeScript bindings are exported to
+//! `src/core/models/generated/` by `cargo test --features bindings`.
+
+use chrono::{DateTime, Utc};
+use schemars::schema::RootSchema;
+use schemars::{schema_for, JsonSchema};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "bindings")]
+use ts_rs::TS;
+
+use crate::error::{Error, Result};
+
+/// Default field option values.
+///
+/// Built-in global options, in the order the TS `DEFAULT_FIELD_OPTIONS`
+/// object declares them.
+pub const DEFAULT_FIELD_OPTIONS: &[(FieldName, &[&str])] = &[
+    (
+        FieldName::Type,
+        &["enhancement", "bug", "idea", "task", "question"],
+    ),
+    (FieldName::Priority, &["low", "medium", "high", "critical"]),
+    (
+        FieldName::Status,
+        &[
+            "triage",
+            "backlog",
+            "planned",
+            "in-progress",
+            "done",
+            "wontfix",
+        ],
+    ),
+];
+
+/// Field names that support configurable options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    feature = "bindings",
+    derive(TS),
+    ts(export, export_to = "../../src/core/models/generated/")
+)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldName {
+    Type,
+    Domain,
+    Context,
+    Priority,
+    Status,
+    Tags,
+}
+
+impl FieldName {
+    /// All field names, in declaration order.
+    pub const ALL: [FieldName; 6] = [
+        FieldName::Type,
+        FieldName::Domain,
+        FieldName::Context,
+        FieldName::Priority,
+        FieldName::Status,
+        FieldName::Tags,
+    ];
+
+    /// Parses a wire name; `None` for unknown fields.
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|field| field.as_str() == value)
+    }
+
+    /// Returns the wire name (`type`, `domain`, ...).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FieldName::Type => "type",
+            FieldName::Domain => "domain",
+            FieldName::Context => "context",
+            FieldName::Priority => "priority",
+            FieldName::Status => "status",
+            FieldName::Tags => "tags",
+        }
+    }
+}
+
+/// Scope for field options.
+///
+/// Global options apply to all projects, project options to one project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    feature = "bindings",
+    derive(TS),
+    ts(export, export_to = "../../src/core/models/generated/")
+)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldScope {
+    Global,
+    Project,
+}
+
+/// Application configuration entity.
+///
+/// Stored in `~/.meatycapture/config.json`, shared with the CLI. Desktop-only
+/// settings are optional so files written by older clients still load, and
+/// keys this build does not know are kept in `extra` and written back.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    feature = "bindings",
+    derive(TS),
+    ts(
+        export,
+        export_to = "../../src/core/models/generated/",
+        optional_fields
+    )
+)]
+pub struct AppConfig {
+    /// Config file format version (semver)
+    pub version: String,
+    /// Default project ID for new documents
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_project: Option<String>,
+    /// API server URL for remote mode (e.g., `http://localhost:3737`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_url: Option<String>,
+    /// Desktop: hide to the tray instead of quitting when the window closes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub close_to_tray: Option<bool>,
+    /// Desktop: global shortcut for the quick-capture window (empty disables)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capture_shortcut: Option<String>,
+    /// Desktop: document the quick-capture window last appended to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_document: Option<String>,
+    /// Desktop: save `meatycapture://...&submit=1` links without asking
+    /// (by default the capture window opens prefilled for confirmation)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deep_link_auto_submit: Option<bool>,
+    /// Desktop: port of the embedded localhost API server (unset disables it)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub local_api_port: Option<u16>,
+    /// Desktop: bearer token required by the embedded API server (unset
+    /// keeps the server off)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub local_api_token: Option<String>,
+    /// Backup snapshots kept per document (`0` keeps all)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup_keep: Option<u32>,
+    /// Days after which backup snapshots are pruned (`0` never expires)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup_max_age_days: Option<u32>,
+    /// Timestamp when config was created
+    #[serde(with = "iso8601")]
+    #[schemars(with = "DateTime<Utc>")]
+    #[cfg_attr(feature = "bindings", ts(type = "string"))]
+    pub created_at: DateTime<Utc>,
+    /// Timestamp of last modification
+    #[serde(with = "iso8601")]
+    #[schemars(with = "DateTime<Utc>")]
+    #[cfg_attr(feature = "bindings", ts(type = "string"))]
+    pub updated_at: DateTime<Utc>,
+    /// Keys written by other clients, preserved on save
+    #[serde(flatten)]
+    #[cfg_attr(feature = "bindings", ts(skip))]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Quick-capture shortcut used when `capture_shortcut` is not set.
+pub const DEFAULT_CAPTURE_SHORTCUT: &str = "CommandOrControl+Shift+Space";
+
+impl AppConfig {
+    /// Whether closing the main window should hide it to the tray.
+    pub fn close_to_tray(&self) -> bool {
+        self.close_to_tray.unwrap_or(false)
+    }
+
+    /// Whether `submit=1` deep links save without confirmation.
+    pub fn deep_link_auto_submit(&self) -> bool {
+        self.deep_link_auto_submit.unwrap_or(false)
+    }
+
+    /// Quick-capture shortcut binding (empty when disabled).
+    pub fn capture_shortcut(&self) -> &str {
+        self.capture_shortcut
+            .as_deref()
+            .unwrap_or(DEFAULT_CAPTURE_SHORTCUT)
+    }
+}
+
+/// Partial config merged on update; `None` leaves a key unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct AppConfigUpdate {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_project: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub close_to_tray: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capture_shortcut: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_document: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deep_link_auto_submit: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub local_api_port: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub local_api_token: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup_keep: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup_max_age_days: Option<u32>,
+}
+
+/// Project configuration entity.
+///
+/// Represents a project that can have request-log documents.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    feature = "bindings",
+    derive(TS),
+    ts(
+        export,
+        export_to = "../../src/core/models/generated/",
+        optional_fields
+    )
+)]
+pub struct Project {
+    /// Unique identifier (slug format, e.g., `meatycapture`)
+    pub id: String,
+    /// Human-readable project name
+    pub name: String,
+    /// Default filesystem path for request-log files
+    pub default_path: String,
+    /// Optional repository URL for context
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo_url: Option<String>,
+    /// Whether the project is active and available for selection
+    pub enabled: bool,
+    /// Commit each document write to the enclosing git repository
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_commit: Option<bool>,
+    /// Timestamp when project was created
+    #[serde(with = "iso8601")]
+    #[schemars(with = "DateTime<Utc>")]
+    #[cfg_attr(feature = "bindings", ts(type = "string"))]
+    pub created_at: DateTime<Utc>,
+    /// Timestamp of last modification
+    #[serde(with = "iso8601")]
+    #[schemars(with = "DateTime<Utc>")]
+    #[cfg_attr(feature = "bindings", ts(type = "string"))]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Project data supplied on create (timestamps are generated).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct NewProject {
+    /// Explicit kebab-case ID; generated from `name` when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    pub default_path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo_url: Option<String>,
+    pub enabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_commit: Option<bool>,
+}
+
+/// Partial project data merged on update; `None` leaves a field unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectUpdate {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_commit: Option<bool>,
+}
+
+/// Field option entity.
+///
+/// A configurable option for dropdown/select fields, scoped globally or
+/// to a specific project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    feature = "bindings",
+    derive(TS),
+    ts(
+        export,
+        export_to = "../../src/core/models/generated/",
+        optional_fields
+    )
+)]
+pub struct FieldOption {
+    /// Unique identifier
+    pub id: String,
+    /// Which field this option belongs to
+    pub field: FieldName,
+    /// The option value (e.g., `enhancement`, `bug`)
+    pub value: String,
+    /// Whether this is a global or project-specific option
+    pub scope: FieldScope,
+    /// Required when scope is `project`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// Timestamp when option was created
+    #[serde(with = "iso8601")]
+    #[schemars(with = "DateTime<Utc>")]
+    #[cfg_attr(feature = "bindings", ts(type = "string"))]
+    pub created_at: DateTime<Utc>,
+}
+
+/// Field option data supplied on add (ID and timestamp are generated).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct NewFieldOption {
+    pub field: FieldName,
+    pub value: String,
+    pub scope: FieldScope,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+}
+
+/// Item draft entity.
+///
+/// Form data for an item before it is appended to a document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    feature = "bindings",
+    derive(TS),
+    ts(
+        export,
+        export_to = "../../src/core/models/generated/",
+        optional_fields
+    )
+)]
+pub struct ItemDraft {
+    /// Item title/summary
+    pub title: String,
+    /// Item type (enhancement, bug, idea, etc.)
+    #[serde(rename = "type")]
+    pub item_type: String,
+    /// Domain/area (web, api, mobile, etc.)
+    #[serde(default)]
+    pub domain: String,
+    /// Additional context information
+    #[serde(default)]
+    pub context: String,
+    /// Priority level (low, medium, high, critical)
+    pub priority: String,
+    /// Current status (triage, backlog, in-progress, etc.)
+    pub status: String,
+    /// Tag strings for categorization
+    pub tags: Vec<String>,
+    /// Freeform notes/description with problem/goal details
+    #[serde(default)]
+    pub notes: String,
+}
+
+/// Request log item entity.
+///
+/// Represents a persisted item within a request-log document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    feature = "bindings",
+    derive(TS),
+    ts(
+        export,
+        export_to = "../../src/core/models/generated/",
+        optional_fields
+    )
+)]
+pub struct RequestLogItem {
+    /// Unique item ID (e.g., `REQ-20251203-capture-app-01`)
+    pub id: String,
+    /// Item title/summary
+    pub title: String,
+    /// Item type (enhancement, bug, idea, etc.)
+    #[serde(rename = "type")]
+    pub item_type: String,
+    /// Domain/area (web, api, mobile, etc.)
+    pub domain: String,
+    /// Additional context information
+    pub context: String,
+    /// Priority level (low, medium, high, critical)
+    pub priority: String,
+    /// Current status (triage, backlog, in-progress, etc.)
+    pub status: String,
+    /// Tag strings for categorization
+    pub tags: Vec<String>,
+    /// Freeform notes/description with problem/goal details
+    pub notes: String,
+    /// Hand-written lines between the metadata and the notes heading
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    #[cfg_attr(feature = "bindings", ts(skip))]
+    pub extra: String,
+    /// Unparseable sections following the item in the file, verbatim
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    #[cfg_attr(feature = "bindings", ts(skip))]
+    pub trailing: String,
+    /// Timestamp when item was created
+    #[serde(with = "iso8601")]
+    #[schemars(with = "DateTime<Utc>")]
+    #[cfg_attr(feature = "bindings", ts(type = "string"))]
+    pub created_at: DateTime<Utc>,
+}
+
+impl RequestLogItem {
+    /// Builds a persisted item from a draft, assigning its ID and timestamp.
+    pub fn from_draft(draft: ItemDraft, id: String, created_at: DateTime<Utc>) -> Self {
+        Self {
+            id,
+            title: draft.title,
+            item_type: draft.item_type,
+            domain: draft.domain,
+            context: draft.context,
+            priority: draft.priority,
+            status: draft.status,
+            tags: draft.tags,
+            notes: draft.notes,
+            extra: String::new(),
+            trailing: String::new(),
+            created_at,
+        }
+    }
+}
+
+/// Item index entry.
+///
+/// Quick reference entry in frontmatter for fast lookup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    feature = "bindings",
+    derive(TS),
+    ts(
+        export,
+        export_to = "../../src/core/models/generated/",
+        optional_fields
+    )
+)]
+pub struct ItemIndexEntry {
+    /// Item ID reference
+    pub id: String,
+    /// Item type for filtering
+    #[serde(rename = "type")]
+    pub item_type: String,
+    /// Item title for display
+    pub title: String,
+}
+
+/// Request-log format version written by this build; `migrate` upgrades
+/// older files.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Version of files written before `schema_version` existed (no key).
+pub const LEGACY_SCHEMA_VERSION: u32 = 1;
+
+fn current_schema_version() -> u32 {
+    SCHEMA_VERSION
+}
+
+/// Request log document entity.
+///
+/// Represents a complete request-log markdown document containing
+/// multiple items and aggregated metadata.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    feature = "bindings",
+    derive(TS),
+    ts(
+        export,
+        export_to = "../../src/core/models/generated/",
+        optional_fields
+    )
+)]
+pub struct RequestLogDoc {
+    /// Request-log format version (defaults to the current version)
+    #[serde(default = "current_schema_version")]
+    #[cfg_attr(feature = "bindings", ts(optional, as = "Option<u32>"))]
+    pub schema_version: u32,
+    /// Document ID (e.g., `REQ-20251203-capture-app`)
+    pub doc_id: String,
+    /// Document title
+    pub title: String,
+    /// Associated project ID
+    pub project_id: String,
+    /// All items in the document
+    pub items: Vec<RequestLogItem>,
+    /// Quick reference index for frontmatter
+    pub items_index: Vec<ItemIndexEntry>,
+    /// Aggregated unique tags from all items (sorted)
+    pub tags: Vec<String>,
+    /// Total number of items in document
+    #[cfg_attr(feature = "bindings", ts(type = "number"))]
+    pub item_count: u64,
+    /// Timestamp when document was created
+    #[serde(with = "iso8601")]
+    #[schemars(with = "DateTime<Utc>")]
+    #[cfg_attr(feature = "bindings", ts(type = "string"))]
+    pub created_at: DateTime<Utc>,
+    /// Timestamp of last modification
+    #[serde(with = "iso8601")]
+    #[schemars(with = "DateTime<Utc>")]
+    #[cfg_attr(feature = "bindings", ts(type = "string"))]
+    pub updated_at: DateTime<Utc>,
+    /// Unknown frontmatter entries, raw and in file order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "bindings", ts(skip))]
+    pub extra_frontmatter: Vec<String>,
+    /// Body text before the first item, verbatim
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    #[cfg_attr(feature = "bindings", ts(skip))]
+    pub preamble: String,
+}
+
+/// Document metadata for listing operations.
+///
+/// Lightweight representation without full item details.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    feature = "bindings",
+    derive(TS),
+    ts(
+        export,
+        export_to = "../../src/core/models/generated/",
+        optional_fields
+    )
+)]
+pub struct DocMeta {
+    /// Filesystem path to the document
+    pub path: String,
+    /// Document identifier (e.g., `REQ-20251203-capture-app`)
+    pub doc_id: String,
+    /// Document title
+    pub title: String,
+    /// Total number of items in the document
+    #[cfg_attr(feature = "bindings", ts(type = "number"))]
+    pub item_count: u64,
+    /// Timestamp of last modification
+    #[serde(with = "iso8601")]
+    #[schemars(with = "DateTime<Utc>")]
+    #[cfg_attr(feature = "bindings", ts(type = "string"))]
+    pub updated_at: DateTime<Utc>,
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+/// A domain model that can be decoded from untrusted JSON.
+///
+/// Native equivalent of the TS type guards (`isProject`, `isItemDraft`, ...):
+/// serde enforces the field types, `validate` the rules it cannot express.
+pub trait Model: DeserializeOwned + JsonSchema {
+    /// Checks cross-field rules after decoding.
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Decodes and validates a JSON value.
+    fn from_value(value: serde_json::Value) -> Result<Self> {
+        let model: Self = serde_json::from_value(value)
+            .map_err(|e| Error::Validation(format!("Invalid {}: {e}", Self::schema_name())))?;
+        model.validate()?;
+        Ok(model)
+    }
+}
+
+impl Model for AppConfig {}
+
+impl Model for Project {}
+
+impl Model for FieldOption {
+    /// Project-scoped options must name their project (`isFieldOption`).
+    fn validate(&self) -> Result<()> {
+        if self.scope == FieldScope::Project && self.project_id.is_none() {
+            return Err(Error::Validation(format!(
+                "Invalid FieldOption: project_id is required for project-scoped option {}",
+                self.id
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// `domain`, `context` and `notes` default to empty strings when absent.
+impl Model for ItemDraft {}
+
+impl Model for RequestLogItem {}
+
+impl Model for ItemIndexEntry {}
+
+impl Model for RequestLogDoc {}
+
+impl Model for DocMeta {}
+
+// ============================================================================
+// JSON Schema
+// ============================================================================
+
+/// JSON Schemas of the shared domain models, keyed by type name.
+///
+/// Checked in under `src-tauri/schemas/` for non-Rust consumers.
+pub fn json_schemas() -> Vec<(&'static str, RootSchema)> {
+    vec![
+        ("AppConfig", schema_for!(AppConfig)),
+        ("Project", schema_for!(Project)),
+        ("FieldOption", schema_for!(FieldOption)),
+        ("ItemDraft", schema_for!(ItemDraft)),
+        ("RequestLogItem", schema_for!(RequestLogItem)),
+        ("ItemIndexEntry", schema_for!(ItemIndexEntry)),
+        ("RequestLogDoc", schema_for!(RequestLogDoc)),
+        ("DocMeta", schema_for!(DocMeta)),
+    ]
+}
+
+/// Serde adapter writing timestamps like `Date.prototype.toISOString`.
+///
+/// Deserialization accepts any RFC 3339 timestamp.
+pub mod iso8601 {
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::serializer::to_iso_string;
+
+    pub fn serialize<S: Serializer>(
+        date: &DateTime<Utc>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_iso_string(date))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DateTime<Utc>, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        DateTime::parse_from_rfc3339(&value)
+            .map(|date| date.with_timezone(&Utc))
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::*;
+
+    fn project_json() -> serde_json::Value {
+        json!({
+            "id": "test-project",
+            "name": "Test Project",
+            "default_path": "/path/to/project",
+            "enabled": true,
+            "created_at": "2025-12-03T10:00:00.000Z",
+            "updated_at": "2025-12-03T10:00:00.000Z",
+        })
+    }
+
+    fn draft_json() -> serde_json::Value {
+        json!({
+            "title": "Test Item",
+            "type": "enhancement",
+            "domain": "web",
+            "context": "testing",
+            "priority": "medium",
+            "status": "triage",
+            "tags": ["test", "sample"],
+            "notes": "Test notes",
+        })
+    }
+
+    #[test]
+    fn project_from_value_accepts_valid_project() {
+        let project = Project::from_value(project_json()).unwrap();
+        assert_eq!(project.id, "test-project");
+        assert_eq!(project.repo_url, None);
+
+        let mut value = project_json();
+        value["repo_url"] = json!("https://github.com/test/repo");
+        let project = Project::from_value(value).unwrap();
+        assert_eq!(
+            project.repo_url.as_deref(),
+            Some("https://github.com/test/repo")
+        );
+    }
+
+    #[test]
+    fn project_from_value_rejects_wrong_types() {
+        for (key, bad) in [
+            ("id", json!(123)),
+            ("enabled", json!("true")),
+            ("created_at", json!("not a date")),
+            ("repo_url", json!(42)),
+        ] {
+            let mut value = project_json();
+            value[key] = bad;
+            let err = Project::from_value(value).unwrap_err();
+            assert!(matches!(err, Error::Validation(_)), "{key}: {err}");
+            assert!(err.to_string().starts_with("Invalid Project:"));
+        }
+
+        let mut value = project_json();
+        value.as_object_mut().unwrap().remove("name");
+        assert!(Project::from_value(value).is_err());
+        assert!(Project::from_value(json!(null)).is_err());
+        assert!(Project::from_value(json!("project")).is_err());
+    }
+
+    #[test]
+    fn field_option_requires_project_id_for_project_scope() {
+        let global = json!({
+            "id": "opt-1",
+            "field": "type",
+            "value": "enhancement",
+            "scope": "global",
+            "created_at": "2025-12-03T10:00:00.000Z",
+        });
+        assert!(FieldOption::from_value(global.clone()).is_ok());
+
+        let mut scoped = global.clone();
+        scoped["scope"] = json!("project");
+        let err = FieldOption::from_value(scoped.clone()).unwrap_err();
+        assert!(err.to_string().contains("project_id is required"));
+
+        scoped["project_id"] = json!("test-project");
+        assert!(FieldOption::from_value(scoped).is_ok());
+
+        let mut bad_field = global;
+        bad_field["field"] = json!("invalid");
+        assert!(FieldOption::from_value(bad_field).is_err());
+    }
+
+    #[test]
+    fn item_draft_from_value() {
+        let draft = ItemDraft::from_value(draft_json()).unwrap();
+        assert_eq!(draft.item_type, "enhancement");
+
+        let mut value = draft_json();
+        value["tags"] = json!(["valid", 123]);
+        assert!(ItemDraft::from_value(value).is_err());
+
+        let mut value = draft_json();
+        value["tags"] = json!("not-an-array");
+        assert!(ItemDraft::from_value(value).is_err());
+
+        let mut value = draft_json();
+        value.as_object_mut().unwrap().remove("notes");
+        assert_eq!(ItemDraft::from_value(value).unwrap().notes, "");
+    }
+
+    #[test]
+    fn request_log_doc_from_value_validates_items() {
+        let mut item = draft_json();
+        item["id"] = json!("REQ-20251203-test-project-01");
+        item["created_at"] = json!("2025-12-03T10:00:00.000Z");
+        let mut doc = json!({
+            "doc_id": "REQ-20251203-test-project",
+            "title": "Test Document",
+            "project_id": "test-project",
+            "items": [item],
+            "items_index": [{
+                "id": "REQ-20251203-test-project-01",
+                "type": "enhancement",
+                "title": "Test Item",
+            }],
+            "tags": ["sample", "test"],
+            "item_count": 1,
+            "created_at": "2025-12-03T10:00:00.000Z",
+            "updated_at": "2025-12-03T10:00:00.000Z",
+        });
+        assert_eq!(
+            RequestLogDoc::from_value(doc.clone()).unwrap().item_count,
+            1
+        );
+
+        doc["items"][0].as_object_mut().unwrap().remove("id");
+        assert!(RequestLogDoc::from_value(doc).is_err());
+    }
+
+    #[test]
+    fn schemas_describe_wire_format() {
+        let schemas = json_schemas();
+        let (_, project) = schemas.iter().find(|(name, _)| *name == "Project").unwrap();
+        let project = serde_json::to_value(project).unwrap();
+        assert_eq!(
+            project["properties"]["created_at"]["format"],
+            json!("date-time")
+        );
+        let required = project["required"].as_array().unwrap();
+        assert!(required.contains(&json!("enabled")));
+        assert!(!required.contains(&json!("repo_url")));
+
+        let (_, draft) = schemas
+            .iter()
+            .find(|(name, _)| *name == "ItemDraft")
+            .unwrap();
+        let draft = serde_json::to_value(draft).unwrap();
+        assert!(draft["properties"]["type"].is_object());
+        assert!(draft["properties"].get("item_type").is_none());
+    }
+
+    /// Fails when `src-tauri/schemas/` is stale; regenerate with
+    /// `UPDATE_SCHEMAS=1 cargo test schemas_are_up_to_date`.
+    #[test]
+    fn schemas_are_up_to_date() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("schemas");
+        let update = std::env::var_os("UPDATE_SCHEMAS").is_some();
+        for (name, schema) in json_schemas() {
+            let path = dir.join(format!("{name}.schema.json"));
+            let expected = serde_json::to_string_pretty(&schema).unwrap() + "\n";
+            if update {
+                fs::create_dir_all(&dir).unwrap();
+                fs::write(&path, &expected).unwrap();
+                continue;
+            }
+            let actual = fs::read_to_string(&path).unwrap_or_default();
+            assert!(
+                actual == expected,
+                "{} is out of date, run UPDATE_SCHEMAS=1 cargo test schemas_are_up_to_date",
+                path.display()
+            );
+        }
+    }
+}
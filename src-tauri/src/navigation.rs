@@ -0,0 +1,102 @@
+//! Native Navigation
+//!
+//! Brings the main window forward and tells the webview which view to show.
+//! Used by native entry points (tray menu, forwarded launch arguments, deep
+//! links) that live outside the webview.
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+use crate::deep_link::CapturePrefill;
+
+/// Label of the main application window (the default in `tauri.conf.json`).
+pub const MAIN_WINDOW: &str = "main";
+
+/// Event the webview listens to for navigation requests.
+pub const NAVIGATE_EVENT: &str = "navigate";
+
+/// Top-level views of the main window (matches `View` in `App.tsx`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum View {
+    Wizard,
+    Viewer,
+    Admin,
+}
+
+/// Payload of the `navigate` event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Navigate {
+    pub view: View,
+    /// Project to preselect in the capture wizard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// Document to expand in the viewer, or to append to in the wizard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub doc_path: Option<String>,
+    /// Item fields to prefill in the wizard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draft: Option<CapturePrefill>,
+}
+
+impl Navigate {
+    /// Navigates to `view` without a project preselection.
+    pub fn to(view: View) -> Self {
+        Self {
+            view,
+            project_id: None,
+            doc_path: None,
+            draft: None,
+        }
+    }
+}
+
+/// Shows, restores and focuses the main window.
+pub fn show_main_window<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<()> {
+    if let Some(window) = app.get_webview_window(MAIN_WINDOW) {
+        window.show()?;
+        window.unminimize()?;
+        window.set_focus()?;
+    }
+    Ok(())
+}
+
+/// Brings the main window forward and switches it to the requested view.
+pub fn navigate<R: Runtime>(app: &AppHandle<R>, request: Navigate) {
+    if let Err(error) = show_main_window(app) {
+        log::warn!("Failed to show main window: {error}");
+    }
+    if let Err(error) = app.emit_to(MAIN_WINDOW, NAVIGATE_EVENT, &request) {
+        log::warn!("Failed to emit {NAVIGATE_EVENT}: {error}");
+    }
+}
+
+/// Event the webview listens to for native success/error toasts.
+pub const NOTICE_EVENT: &str = "notice";
+
+/// Toast level (matches `ToastType` in the webview).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NoticeKind {
+    Success,
+    Error,
+}
+
+/// Payload of the `notice` event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Notice {
+    #[serde(rename = "type")]
+    pub kind: NoticeKind,
+    pub message: String,
+}
+
+/// Shows a toast in the main window for an action taken natively.
+pub fn notify<R: Runtime>(app: &AppHandle<R>, kind: NoticeKind, message: impl Into<String>) {
+    let notice = Notice {
+        kind,
+        message: message.into(),
+    };
+    if let Err(error) = app.emit_to(MAIN_WINDOW, NOTICE_EVENT, &notice) {
+        log::warn!("Failed to emit {NOTICE_EVENT}: {error}");
+    }
+}
@@ -0,0 +1,312 @@
+//! Path Guard
+//!
+//! Central validation for paths received from the webview before any
+//! native command touches the filesystem:
+//! - `~` / `~/` expansion matching the TypeScript `expandPath`
+//! - Rejects empty and relative paths, NUL bytes and any `..` component
+//! - Resolves symlinks (the longest existing ancestor is canonicalized) and
+//!   rejects dangling links that would redirect a new file
+//! - Requires the resolved path to lie inside an allowed root
+//! - Document writes must target a `.md` file
+
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use crate::doc_store::expand_path;
+use crate::error::{Error, Result};
+
+/// What a command is about to do with a path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    /// List or scan a directory
+    Directory,
+    /// Read a file or probe its state
+    Read,
+    /// Create or modify a request-log document (`.md` only)
+    Write,
+}
+
+/// Validates `path` for `access` and returns its resolved form.
+///
+/// `roots` are the allowed directories; they are resolved the same way, so
+/// a symlinked project folder still matches its own documents.
+pub fn check(path: &str, access: Access, roots: &[PathBuf]) -> Result<PathBuf> {
+    let resolved = resolve(path)?;
+    let inside = roots
+        .iter()
+        .filter_map(|root| canonicalize_lenient(root).ok())
+        .any(|root| resolved.starts_with(root));
+    if !inside {
+        return Err(Error::OutOfScope {
+            path: path.to_string(),
+        });
+    }
+
+    if access == Access::Write {
+        if resolved.extension().is_none_or(|ext| ext != "md") {
+            return Err(invalid(path, "documents must be .md files"));
+        }
+        if resolved.is_dir() {
+            return Err(invalid(path, "is a directory"));
+        }
+    }
+    Ok(resolved)
+}
+
+/// Expands, validates and canonicalizes a path without checking roots.
+pub fn resolve(path: &str) -> Result<PathBuf> {
+    if path.is_empty() {
+        return Err(invalid(path, "path is empty"));
+    }
+    if path.contains('\0') {
+        return Err(invalid(path, "contains a NUL byte"));
+    }
+
+    let expanded = expand_path(path);
+    if !expanded.is_absolute() {
+        return Err(invalid(path, "must be absolute or start with ~/"));
+    }
+    if expanded
+        .components()
+        .any(|component| component == Component::ParentDir)
+    {
+        return Err(invalid(path, "must not contain '..'"));
+    }
+    canonicalize_lenient(&expanded).map_err(|reason| invalid(path, reason))
+}
+
+/// Canonicalizes the longest existing ancestor of `path` and appends the
+/// missing components, which cannot be symlinks because they do not exist.
+fn canonicalize_lenient(path: &Path) -> std::result::Result<PathBuf, &'static str> {
+    let mut missing: Vec<OsString> = Vec::new();
+    let mut current = path;
+    loop {
+        if let Ok(canonical) = fs::canonicalize(current) {
+            let mut resolved = canonical;
+            resolved.extend(missing.iter().rev());
+            return Ok(resolved);
+        }
+        // Exists but cannot be resolved: a dangling or looping symlink
+        if fs::symlink_metadata(current).is_ok() {
+            return Err("contains a symlink that cannot be resolved");
+        }
+        match (current.parent(), current.file_name()) {
+            (Some(parent), Some(name)) => {
+                missing.push(name.to_os_string());
+                current = parent;
+            }
+            _ => return Err("no part of the path exists"),
+        }
+    }
+}
+
+fn invalid(path: &str, reason: &str) -> Error {
+    Error::InvalidPath {
+        path: path.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc_store::base_dir;
+    use tempfile::TempDir;
+
+    /// Temp dir with a `project` root and a `secret` sibling outside it.
+    fn layout() -> (TempDir, PathBuf, PathBuf) {
+        let temp = TempDir::new().unwrap();
+        let project = temp.path().join("project");
+        let secret = temp.path().join("secret");
+        fs::create_dir_all(&project).unwrap();
+        fs::create_dir_all(&secret).unwrap();
+        fs::write(project.join("log.md"), "---\n---\n").unwrap();
+        fs::write(secret.join("id_rsa"), "key").unwrap();
+        (temp, project, secret)
+    }
+
+    fn path(path: &Path) -> String {
+        path.display().to_string()
+    }
+
+    fn is_out_of_scope(result: Result<PathBuf>) -> bool {
+        matches!(result, Err(Error::OutOfScope { .. }))
+    }
+
+    fn is_invalid(result: Result<PathBuf>) -> bool {
+        matches!(result, Err(Error::InvalidPath { .. }))
+    }
+
+    #[test]
+    fn allows_documents_and_new_files_inside_roots() {
+        let (_temp, project, _secret) = layout();
+        let roots = [project.clone()];
+        let canonical = fs::canonicalize(&project).unwrap();
+
+        assert_eq!(
+            check(&path(&project.join("log.md")), Access::Write, &roots).unwrap(),
+            canonical.join("log.md")
+        );
+        assert_eq!(
+            check(
+                &path(&project.join("new/sub/next.md")),
+                Access::Write,
+                &roots
+            )
+            .unwrap(),
+            canonical.join("new/sub/next.md")
+        );
+        assert!(check(&path(&project), Access::Directory, &roots).is_ok());
+        assert!(check(
+            &format!("{}/./log.md", path(&project)),
+            Access::Read,
+            &roots
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn rejects_parent_dir_escapes() {
+        let (_temp, project, secret) = layout();
+        let roots = [project.clone()];
+
+        for hostile in [
+            format!("{}/../secret/id_rsa", path(&project)),
+            format!("{}/log.md/../../secret/id_rsa", path(&project)),
+            format!("{}/sub/../log.md", path(&project)),
+            format!("{}/..", path(&project)),
+            "~/../../etc/passwd".to_string(),
+        ] {
+            assert!(
+                is_invalid(check(&hostile, Access::Read, &roots)),
+                "{hostile}"
+            );
+        }
+        assert!(is_out_of_scope(check(
+            &path(&secret.join("id_rsa")),
+            Access::Read,
+            &roots
+        )));
+    }
+
+    #[test]
+    fn rejects_relative_empty_and_nul_paths() {
+        let (_temp, project, _secret) = layout();
+        let roots = [project.clone()];
+
+        for hostile in [
+            "",
+            "log.md",
+            "./log.md",
+            "~root/.ssh/authorized_keys",
+            "project\0/log.md",
+        ] {
+            assert!(
+                is_invalid(check(hostile, Access::Read, &roots)),
+                "{hostile:?}"
+            );
+        }
+        let nul = format!("{}/log.md\0.txt", path(&project));
+        assert!(is_invalid(check(&nul, Access::Write, &roots)));
+    }
+
+    #[test]
+    fn rejects_sibling_directories_sharing_a_prefix() {
+        let (temp, project, _secret) = layout();
+        let evil = temp.path().join("project-evil");
+        fs::create_dir_all(&evil).unwrap();
+
+        assert!(is_out_of_scope(check(
+            &path(&evil.join("log.md")),
+            Access::Write,
+            &[project]
+        )));
+    }
+
+    #[test]
+    fn document_writes_require_markdown_files() {
+        let (_temp, project, _secret) = layout();
+        let roots = [project.clone()];
+
+        for target in ["notes.txt", "log.md.lock", "log", ".bashrc", "log.MD.exe"] {
+            let target = path(&project.join(target));
+            assert!(
+                is_invalid(check(&target, Access::Write, &roots)),
+                "{target}"
+            );
+            assert!(check(&target, Access::Read, &roots).is_ok(), "{target}");
+        }
+        fs::create_dir_all(project.join("dir.md")).unwrap();
+        assert!(is_invalid(check(
+            &path(&project.join("dir.md")),
+            Access::Write,
+            &roots
+        )));
+    }
+
+    #[test]
+    fn expands_tilde_like_expand_path() {
+        let home = base_dir();
+        let roots = [home.join("notes")];
+        let expected = canonicalize_lenient(&home).unwrap().join("notes/log.md");
+
+        assert_eq!(
+            check("~/notes/log.md", Access::Write, &roots).unwrap(),
+            expected
+        );
+        assert!(is_out_of_scope(check("~/.bashrc", Access::Read, &roots)));
+        assert!(is_out_of_scope(check("~", Access::Directory, &roots)));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn resolves_symlinks_before_checking_roots() {
+        use std::os::unix::fs::symlink;
+
+        let (_temp, project, secret) = layout();
+        let roots = [project.clone()];
+        symlink(&secret, project.join("escape")).unwrap();
+        symlink(secret.join("id_rsa"), project.join("key.md")).unwrap();
+        symlink(secret.join("missing.md"), project.join("dangling.md")).unwrap();
+        symlink(project.join("loop.md"), project.join("loop.md")).unwrap();
+
+        assert!(is_out_of_scope(check(
+            &path(&project.join("escape/id_rsa")),
+            Access::Read,
+            &roots
+        )));
+        assert!(is_out_of_scope(check(
+            &path(&project.join("escape/new.md")),
+            Access::Write,
+            &roots
+        )));
+        assert!(is_out_of_scope(check(
+            &path(&project.join("key.md")),
+            Access::Write,
+            &roots
+        )));
+        for link in ["dangling.md", "loop.md"] {
+            let link = path(&project.join(link));
+            assert!(is_invalid(check(&link, Access::Write, &roots)), "{link}");
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinked_roots_match_their_own_documents() {
+        use std::os::unix::fs::symlink;
+
+        let (temp, project, _secret) = layout();
+        let link = temp.path().join("link");
+        symlink(&project, &link).unwrap();
+
+        assert!(check(
+            &path(&link.join("log.md")),
+            Access::Write,
+            std::slice::from_ref(&link)
+        )
+        .is_ok());
+        assert!(check(&path(&project.join("log.md")), Access::Write, &[link]).is_ok());
+    }
+}
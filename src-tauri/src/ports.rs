@@ -0,0 +1,113 @@
+//! Storage Port Interfaces
+//!
+//! Native counterparts of `src/core/ports`:
+//! - Clock: Time abstraction for deterministic testing
+//! - ConfigStore: Application configuration (`config.json`)
+//! - ProjectStore: Project CRUD operations
+//! - FieldCatalogStore: Field option catalog management (global + project-scoped)
+//! - DocStore: Request-log document read/write/append operations
+//!
+//! Implementations live alongside (config_store, doc_store).
+
+use chrono::{DateTime, Utc};
+
+use crate::error::Result;
+use crate::models::{
+    AppConfig, AppConfigUpdate, DocMeta, FieldName, FieldOption, ItemDraft, NewFieldOption,
+    NewProject, Project, ProjectUpdate, RequestLogDoc,
+};
+
+/// Clock abstraction for time-dependent operations.
+pub trait Clock: Send + Sync {
+    /// Returns the current date/time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Clock backed by the system time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Application configuration store.
+pub trait ConfigStore: Send + Sync {
+    /// Gets the configuration, or defaults if the file does not exist yet.
+    fn get(&self) -> Result<AppConfig>;
+
+    /// Merges updates into the configuration and bumps `updated_at`.
+    fn update(&self, updates: AppConfigUpdate) -> Result<AppConfig>;
+
+    /// Checks whether the configuration file exists.
+    fn exists(&self) -> bool;
+}
+
+/// Project store.
+///
+/// Manages project entities and their lifecycle.
+pub trait ProjectStore: Send + Sync {
+    /// Lists all projects (enabled and disabled).
+    fn list(&self) -> Result<Vec<Project>>;
+
+    /// Gets a single project by ID.
+    fn get(&self, id: &str) -> Result<Option<Project>>;
+
+    /// Creates a project, generating its slug ID and timestamps.
+    fn create(&self, project: NewProject) -> Result<Project>;
+
+    /// Merges updates into an existing project and bumps `updated_at`.
+    fn update(&self, id: &str, updates: ProjectUpdate) -> Result<Project>;
+
+    /// Deletes a project. Does not cascade to field options or documents.
+    fn delete(&self, id: &str) -> Result<()>;
+}
+
+/// Field catalog store.
+///
+/// Effective options for a project = global options + project additions.
+pub trait FieldCatalogStore: Send + Sync {
+    /// Gets all global field options.
+    fn get_global(&self) -> Result<Vec<FieldOption>>;
+
+    /// Gets global plus project-specific options for a project.
+    fn get_for_project(&self, project_id: &str) -> Result<Vec<FieldOption>>;
+
+    /// Gets options for one field; includes project options when a
+    /// project ID is given, otherwise global options only.
+    fn get_by_field(&self, field: FieldName, project_id: Option<&str>) -> Result<Vec<FieldOption>>;
+
+    /// Adds a global or project-scoped option, generating ID and timestamp.
+    fn add_option(&self, option: NewFieldOption) -> Result<FieldOption>;
+
+    /// Removes an option by ID from whichever scope holds it.
+    fn remove_option(&self, id: &str) -> Result<()>;
+}
+
+/// Request-log document store.
+///
+/// Handles request-log markdown file operations with automatic tag
+/// aggregation and index management. Paths may use `~` shortcuts.
+pub trait DocStore: Send + Sync {
+    /// Lists all request-log documents in a directory, sorted by
+    /// `updated_at` descending. Missing directories yield an empty list.
+    fn list(&self, directory: &str) -> Result<Vec<DocMeta>>;
+
+    /// Reads and parses a request-log document.
+    fn read(&self, path: &str) -> Result<RequestLogDoc>;
+
+    /// Writes/overwrites a complete document, creating a backup first.
+    fn write(&self, path: &str, doc: &RequestLogDoc) -> Result<()>;
+
+    /// Appends a new item, regenerating ID, count, tags, index and
+    /// `updated_at`. Returns the updated document.
+    fn append(&self, path: &str, item: ItemDraft, clock: &dyn Clock) -> Result<RequestLogDoc>;
+
+    /// Copies a file to `{path}.bak`, overwriting any previous backup.
+    fn backup(&self, path: &str) -> Result<String>;
+
+    /// Checks whether a path exists and is writable (or can be created).
+    fn is_writable(&self, path: &str) -> bool;
+}
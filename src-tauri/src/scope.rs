@@ -0,0 +1,324 @@
+//! Filesystem Scopes
+//!
+//! Directory roots the desktop webview may touch:
+//! - The data directory (`~/.meatycapture`), always
+//! - The `default_path` of every enabled project, granted as projects are
+//!   loaded, created or re-pointed and revoked when disabled or deleted
+//!
+//! `ProjectScopes` is the only gate: the webview has no plugin-fs access,
+//! and native commands check every path against it through `path_guard`.
+//! `sync` reports which roots were granted or revoked (see
+//! `app::sync_project_scopes`).
+//!
+//! A project folder becomes a root, so `check_project_root` refuses folders
+//! that would expose too much: filesystem roots, and the home or data
+//! directory or anything containing them.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use crate::doc_store::enabled_project_dirs;
+use crate::error::{Error, Result};
+use crate::path_guard::{self, Access};
+use crate::ports::ProjectStore;
+
+/// Roots added and removed by a `ProjectScopes::sync`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ScopeChange {
+    /// Newly allowed project roots
+    pub granted: Vec<PathBuf>,
+    /// Project roots that are no longer allowed and do not overlap any
+    /// remaining root (or the data directory)
+    pub revoked: Vec<PathBuf>,
+}
+
+impl ScopeChange {
+    /// True when no roots were granted or revoked.
+    pub fn is_empty(&self) -> bool {
+        self.granted.is_empty() && self.revoked.is_empty()
+    }
+}
+
+/// Allowed filesystem roots: the data directory plus enabled project paths.
+#[derive(Debug)]
+pub struct ProjectScopes {
+    data_dir: PathBuf,
+    roots: Mutex<BTreeSet<PathBuf>>,
+}
+
+impl ProjectScopes {
+    /// Creates a scope that only allows `data_dir` until the first `sync`.
+    pub fn new(data_dir: PathBuf) -> Self {
+        Self {
+            data_dir: normalize(&data_dir),
+            roots: Mutex::new(BTreeSet::new()),
+        }
+    }
+
+    /// The always-allowed data directory.
+    pub fn data_dir(&self) -> &Path {
+        &self.data_dir
+    }
+
+    /// All allowed roots, data directory first.
+    pub fn roots(&self) -> Vec<PathBuf> {
+        std::iter::once(self.data_dir.clone())
+            .chain(self.project_roots().iter().cloned())
+            .collect()
+    }
+
+    /// Replaces the project roots with the enabled projects' `default_path`s.
+    pub fn sync(&self, projects: &dyn ProjectStore) -> Result<ScopeChange> {
+        let wanted: BTreeSet<PathBuf> = enabled_project_dirs(projects)?
+            .iter()
+            .map(|dir| normalize(dir))
+            .collect();
+        let mut roots = self.project_roots();
+
+        let granted = wanted.difference(&roots).cloned().collect();
+        let revoked = roots
+            .difference(&wanted)
+            .filter(|root| {
+                !overlaps(root, &self.data_dir) && !wanted.iter().any(|kept| overlaps(root, kept))
+            })
+            .cloned()
+            .collect();
+        *roots = wanted;
+
+        Ok(ScopeChange { granted, revoked })
+    }
+
+    /// Validates a webview path for `access` against the allowed roots and
+    /// returns its resolved form (see `path_guard::check`).
+    pub fn check(&self, path: &str, access: Access) -> Result<PathBuf> {
+        path_guard::check(path, access, &self.roots())
+    }
+
+    /// `check` for the `&str`-based stores: the resolved path as a string,
+    /// so callers open exactly the file that was checked. Resolved paths
+    /// that are not valid UTF-8 are rejected rather than mangled.
+    pub fn check_str(&self, path: &str, access: Access) -> Result<String> {
+        let resolved = self.check(path, access)?;
+        resolved
+            .into_os_string()
+            .into_string()
+            .map_err(|_| Error::InvalidPath {
+                path: path.to_string(),
+                reason: "resolves to a path that is not valid UTF-8".to_string(),
+            })
+    }
+
+    /// Validates a project `default_path` before it is saved: it must not be
+    /// a filesystem root, the home or data directory, or contain either.
+    pub fn check_project_root(&self, path: &str) -> Result<()> {
+        let home = dirs::home_dir();
+        let protected: Vec<&Path> = home
+            .iter()
+            .map(PathBuf::as_path)
+            .chain([self.data_dir.as_path()])
+            .collect();
+        check_project_root(path, &protected)
+    }
+
+    fn project_roots(&self) -> MutexGuard<'_, BTreeSet<PathBuf>> {
+        self.roots.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Rejects `path` if it is a filesystem root or equal to or above any of
+/// the `protected` directories.
+fn check_project_root(path: &str, protected: &[&Path]) -> Result<()> {
+    let resolved = path_guard::resolve(path)?;
+    let invalid = |reason: &str| Error::InvalidPath {
+        path: path.to_string(),
+        reason: reason.to_string(),
+    };
+
+    if resolved.parent().is_none() {
+        return Err(invalid("a filesystem root cannot be a project folder"));
+    }
+    for dir in protected {
+        let dir = fs::canonicalize(dir).unwrap_or_else(|_| normalize(dir));
+        if dir.starts_with(&resolved) {
+            return Err(invalid(
+                "a project folder cannot be the home or data directory, or contain them",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Lexically resolves `.` and `..` so project roots compare reliably.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// True if one path contains the other.
+fn overlaps(a: &Path, b: &Path) -> bool {
+    a.starts_with(b) || b.starts_with(a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config_store::LocalProjectStore;
+    use crate::models::{NewProject, ProjectUpdate};
+    use tempfile::TempDir;
+
+    fn new_project(name: &str, path: &Path) -> NewProject {
+        NewProject {
+            id: None,
+            name: name.to_string(),
+            default_path: path.display().to_string(),
+            repo_url: None,
+            enabled: true,
+            auto_commit: None,
+        }
+    }
+
+    fn allows(scopes: &ProjectScopes, path: &Path) -> bool {
+        scopes
+            .check(&path.display().to_string(), Access::Read)
+            .is_ok()
+    }
+
+    #[test]
+    fn only_data_dir_is_allowed_before_sync() {
+        let temp = TempDir::new().unwrap();
+        let scopes = ProjectScopes::new(temp.path().join(".meatycapture"));
+        let ssh = temp.path().join(".ssh/config").display().to_string();
+
+        assert!(allows(
+            &scopes,
+            &temp.path().join(".meatycapture/projects.json")
+        ));
+        assert!(!allows(&scopes, &temp.path().join(".bashrc")));
+        assert!(matches!(
+            scopes.check(&ssh, Access::Read),
+            Err(Error::OutOfScope { path }) if path == ssh
+        ));
+    }
+
+    #[test]
+    fn sync_grants_and_revokes_project_roots() {
+        let temp = TempDir::new().unwrap();
+        let projects = LocalProjectStore::new(Some(temp.path().join("config")));
+        let first = temp.path().join("first");
+        let second = temp.path().join("second");
+        let scopes = ProjectScopes::new(temp.path().join("config"));
+
+        let project = projects.create(new_project("Alpha", &first)).unwrap();
+        let change = scopes.sync(&projects).unwrap();
+        assert_eq!(change.granted, vec![first.clone()]);
+        assert!(change.revoked.is_empty());
+        assert!(allows(&scopes, &first.join("log.md")));
+        assert!(scopes.sync(&projects).unwrap().is_empty());
+
+        // Re-pointing swaps the root
+        projects
+            .update(
+                &project.id,
+                ProjectUpdate {
+                    default_path: Some(second.display().to_string()),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let change = scopes.sync(&projects).unwrap();
+        assert_eq!(change.granted, vec![second.clone()]);
+        assert_eq!(change.revoked, vec![first.clone()]);
+        assert!(!allows(&scopes, &first.join("log.md")));
+
+        // Disabling revokes it
+        projects
+            .update(
+                &project.id,
+                ProjectUpdate {
+                    enabled: Some(false),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let change = scopes.sync(&projects).unwrap();
+        assert_eq!(change.revoked, vec![second.clone()]);
+        assert_eq!(scopes.roots(), vec![temp.path().join("config")]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn check_str_returns_the_resolved_path() {
+        use std::os::unix::fs::symlink;
+
+        let temp = TempDir::new().unwrap();
+        let data = temp.path().join(".meatycapture");
+        std::fs::create_dir_all(data.join("docs")).unwrap();
+        symlink(data.join("docs"), data.join("latest")).unwrap();
+        let scopes = ProjectScopes::new(data.clone());
+
+        let resolved = scopes
+            .check_str(
+                &data.join("latest/log.md").display().to_string(),
+                Access::Write,
+            )
+            .unwrap();
+        let expected = std::fs::canonicalize(data.join("docs")).unwrap();
+        assert_eq!(resolved, expected.join("log.md").display().to_string());
+    }
+
+    #[test]
+    fn project_roots_must_not_cover_home_or_data_dir() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path().join("home");
+        let data = home.join(".meatycapture");
+        std::fs::create_dir_all(&data).unwrap();
+        let protected = [home.as_path(), data.as_path()];
+        let check = |path: &Path| check_project_root(&path.display().to_string(), &protected);
+
+        for rejected in [temp.path(), home.as_path(), data.as_path(), Path::new("/")] {
+            assert!(
+                matches!(check(rejected), Err(Error::InvalidPath { .. })),
+                "{}",
+                rejected.display()
+            );
+        }
+        assert!(check(&home.join("notes")).is_ok());
+        assert!(check(&data.join("projects/app")).is_ok());
+        assert!(check(&temp.path().join("elsewhere/app")).is_ok());
+    }
+
+    #[test]
+    fn revoke_skips_roots_overlapping_remaining_ones() {
+        let temp = TempDir::new().unwrap();
+        let projects = LocalProjectStore::new(Some(temp.path().join("config")));
+        let outer = temp.path().join("notes");
+        let inner = outer.join("app");
+        let scopes = ProjectScopes::new(temp.path().join("config"));
+
+        let parent = projects.create(new_project("Notes", &outer)).unwrap();
+        let child = projects.create(new_project("App", &inner)).unwrap();
+        scopes.sync(&projects).unwrap();
+
+        // Forbidding either would also cut off the other project
+        projects.delete(&child.id).unwrap();
+        assert!(scopes.sync(&projects).unwrap().revoked.is_empty());
+        projects.create(new_project("App", &inner)).unwrap();
+        scopes.sync(&projects).unwrap();
+        projects.delete(&parent.id).unwrap();
+        let change = scopes.sync(&projects).unwrap();
+        assert!(change.revoked.is_empty());
+        assert!(allows(&scopes, &inner.join("log.md")));
+        assert!(!allows(&scopes, &outer.join("log.md")));
+    }
+}
@@ -0,0 +1,656 @@
+//! Full-Text Search
+//!
+//! Tantivy index over every request-log item across enabled projects:
+//! - Free text matches titles, notes, context, tags and IDs (BM25 ranking,
+//!   title matches boosted)
+//! - Phrase queries (`"dark mode"`) and boolean syntax (`AND`, `OR`, `-term`)
+//! - Field prefixes: `tag:`, `status:`, `type:`, `priority:`, `domain:`,
+//!   `project:`, `id:`, `doc:`, `title:`, `notes:`, `context:`
+//! - Highlighted snippets returned as text + UTF-16 ranges (safe to render
+//!   in the webview without injecting HTML)
+//!
+//! Each item is one index document tagged with its file path and mtime, so
+//! a document can be re-indexed in place after a write and the whole index
+//! reconciled against disk cheaply on startup.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::UNIX_EPOCH;
+
+use serde::Serialize;
+use tantivy::collector::{Count, DocSetCollector, TopDocs};
+use tantivy::directory::MmapDirectory;
+use tantivy::query::{AllQuery, QueryParser};
+use tantivy::schema::{
+    Field, IndexRecordOption, Schema, TextFieldIndexing, TextOptions, Value, STORED, TEXT,
+};
+use tantivy::snippet::{Snippet, SnippetGenerator};
+use tantivy::tokenizer::{LowerCaser, RawTokenizer, TextAnalyzer};
+use tantivy::{Index, IndexReader, IndexWriter, ReloadPolicy, TantivyDocument, Term};
+
+use crate::config_store::config_dir;
+use crate::doc_store::FsDocStore;
+use crate::error::{Error, Result};
+use crate::models::RequestLogDoc;
+use crate::ports::DocStore;
+
+/// Bump when the schema changes; a fresh index is built in a new directory.
+const SCHEMA_VERSION: u32 = 1;
+
+/// Tokenizer for keyword fields: whole value, case-insensitive.
+const KEYWORD_TOKENIZER: &str = "keyword_lower";
+
+/// Writer heap budget (tantivy's per-thread minimum is 15MB).
+const WRITER_MEMORY: usize = 20_000_000;
+
+/// Maximum snippet length in characters.
+const SNIPPET_CHARS: usize = 160;
+
+/// Default search index location inside the config directory.
+pub fn default_search_dir() -> PathBuf {
+    config_dir()
+        .join("search-index")
+        .join(format!("v{SCHEMA_VERSION}"))
+}
+
+/// Highlighted range in UTF-16 code units (JavaScript string indices).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Highlight {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Text excerpt around the matched terms.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SearchSnippet {
+    /// Field the excerpt was taken from (`notes` or `context`)
+    pub field: &'static str,
+    pub text: String,
+    pub highlights: Vec<Highlight>,
+}
+
+/// One matching item.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SearchHit {
+    /// Relevance score (higher is better)
+    pub score: f32,
+    pub item_id: String,
+    pub doc_id: String,
+    pub doc_path: String,
+    pub project_id: String,
+    pub title: String,
+    /// Matched terms within `title`
+    pub title_highlights: Vec<Highlight>,
+    #[serde(rename = "type")]
+    pub item_type: String,
+    pub status: String,
+    pub priority: String,
+    pub tags: Vec<String>,
+    pub snippet: Option<SearchSnippet>,
+}
+
+/// Ranked search results.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SearchResults {
+    /// Total number of matching items (may exceed `hits.len()`)
+    pub total: usize,
+    pub hits: Vec<SearchHit>,
+}
+
+/// Counts from reconciling the index with disk.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct SyncStats {
+    /// Documents (re)indexed
+    pub indexed: usize,
+    /// Documents already up to date
+    pub unchanged: usize,
+    /// Documents dropped because the file or its project is gone
+    pub removed: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Fields {
+    doc_path: Field,
+    mtime_ns: Field,
+    doc_id: Field,
+    project: Field,
+    item_id: Field,
+    title: Field,
+    notes: Field,
+    context: Field,
+    tag: Field,
+    item_type: Field,
+    status: Field,
+    priority: Field,
+    domain: Field,
+    created_at: Field,
+}
+
+fn build_schema() -> (Schema, Fields) {
+    let keyword = TextOptions::default().set_stored().set_indexing_options(
+        TextFieldIndexing::default()
+            .set_tokenizer(KEYWORD_TOKENIZER)
+            .set_index_option(IndexRecordOption::Basic),
+    );
+    // Exact-match path key (case-sensitive) used to replace a document
+    let path_key = TextOptions::default().set_stored().set_indexing_options(
+        TextFieldIndexing::default()
+            .set_tokenizer("raw")
+            .set_index_option(IndexRecordOption::Basic),
+    );
+
+    let mut builder = Schema::builder();
+    let fields = Fields {
+        doc_path: builder.add_text_field("path", path_key),
+        mtime_ns: builder.add_u64_field("mtime_ns", STORED),
+        doc_id: builder.add_text_field("doc", keyword.clone()),
+        project: builder.add_text_field("project", keyword.clone()),
+        item_id: builder.add_text_field("id", keyword.clone()),
+        title: builder.add_text_field("title", TEXT | STORED),
+        notes: builder.add_text_field("notes", TEXT | STORED),
+        context: builder.add_text_field("context", TEXT | STORED),
+        tag: builder.add_text_field("tag", keyword.clone()),
+        item_type: builder.add_text_field("type", keyword.clone()),
+        status: builder.add_text_field("status", keyword.clone()),
+        priority: builder.add_text_field("priority", keyword.clone()),
+        domain: builder.add_text_field("domain", keyword),
+        created_at: builder.add_text_field("created_at", STORED),
+    };
+    (builder.build(), fields)
+}
+
+/// Persistent full-text index of request-log items.
+pub struct SearchIndex {
+    index: Index,
+    reader: IndexReader,
+    writer: Mutex<IndexWriter>,
+    fields: Fields,
+}
+
+impl std::fmt::Debug for SearchIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SearchIndex").finish_non_exhaustive()
+    }
+}
+
+impl SearchIndex {
+    /// Opens (or creates) the index stored in `dir`.
+    ///
+    /// Only one process can hold the index writer; a second opener fails.
+    pub fn open(dir: &Path) -> Result<Self> {
+        fs::create_dir_all(dir).map_err(Error::io(format!(
+            "Failed to create directory {}",
+            dir.display()
+        )))?;
+        let context = format!("Failed to open search index {}", dir.display());
+        let directory = MmapDirectory::open(dir).map_err(|error| Error::Search {
+            context: context.clone(),
+            source: error.into(),
+        })?;
+
+        let (schema, fields) = build_schema();
+        let index =
+            Index::open_or_create(directory, schema).map_err(Error::search(context.clone()))?;
+        index.tokenizers().register(
+            KEYWORD_TOKENIZER,
+            TextAnalyzer::builder(RawTokenizer::default())
+                .filter(LowerCaser)
+                .build(),
+        );
+
+        let writer = index
+            .writer_with_num_threads(1, WRITER_MEMORY)
+            .map_err(Error::search(context.clone()))?;
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into()
+            .map_err(Error::search(context))?;
+
+        Ok(Self {
+            index,
+            reader,
+            writer: Mutex::new(writer),
+            fields,
+        })
+    }
+
+    /// Replaces all indexed items of the document at `path`.
+    pub fn index_document(&self, path: &Path, doc: &RequestLogDoc) -> Result<()> {
+        let mut writer = self.writer();
+        self.replace(&mut writer, path, Some(doc))?;
+        self.commit(&mut writer)
+    }
+
+    /// Removes all indexed items of the document at `path`.
+    pub fn remove_document(&self, path: &Path) -> Result<()> {
+        let mut writer = self.writer();
+        self.replace(&mut writer, path, None)?;
+        self.commit(&mut writer)
+    }
+
+    /// Brings the index in line with the request-logs in `dirs`.
+    ///
+    /// Incremental by default: only files whose mtime differs from the
+    /// indexed one are re-read, and documents outside `dirs` are dropped.
+    /// With `rebuild`, the index is cleared and every document re-read.
+    pub fn sync_dirs(
+        &self,
+        dirs: &[PathBuf],
+        store: &FsDocStore,
+        rebuild: bool,
+    ) -> Result<SyncStats> {
+        let indexed = if rebuild {
+            HashMap::new()
+        } else {
+            self.indexed_stamps()?
+        };
+
+        let mut writer = self.writer();
+        if rebuild {
+            writer
+                .delete_all_documents()
+                .map_err(Error::search("Failed to clear search index"))?;
+        }
+
+        let mut stats = SyncStats::default();
+        let mut present = HashSet::new();
+        for dir in dirs {
+            let Ok(entries) = fs::read_dir(dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let is_file = entry.file_type().is_ok_and(|t| t.is_file());
+                if !is_file || path.extension().is_none_or(|ext| ext != "md") {
+                    continue;
+                }
+                let key = path.display().to_string();
+                let stamp = file_stamp(&path);
+                present.insert(key.clone());
+
+                if indexed.get(&key).is_some_and(|indexed| *indexed == stamp) {
+                    stats.unchanged += 1;
+                    continue;
+                }
+                // Files that are not request-logs are simply not indexed
+                match store.read(&key) {
+                    Ok(doc) => {
+                        self.replace(&mut writer, &path, Some(&doc))?;
+                        stats.indexed += 1;
+                    }
+                    Err(error) => {
+                        log::debug!("Not indexing {key}: {error}");
+                        if indexed.contains_key(&key) {
+                            self.replace(&mut writer, &path, None)?;
+                        }
+                    }
+                }
+            }
+        }
+
+        for path in indexed.keys().filter(|path| !present.contains(*path)) {
+            self.replace(&mut writer, Path::new(path), None)?;
+            stats.removed += 1;
+        }
+
+        self.commit(&mut writer)?;
+        log::info!(
+            "Search index synced: {} indexed, {} unchanged, {} removed",
+            stats.indexed,
+            stats.unchanged,
+            stats.removed
+        );
+        Ok(stats)
+    }
+
+    /// Runs `query` and returns up to `limit` ranked hits with snippets.
+    ///
+    /// Malformed syntax is handled leniently (the parseable part is used).
+    pub fn search(&self, query: &str, limit: usize) -> Result<SearchResults> {
+        if query.trim().is_empty() || limit == 0 {
+            return Ok(SearchResults {
+                total: 0,
+                hits: Vec::new(),
+            });
+        }
+
+        let fields = self.fields;
+        let mut parser = QueryParser::for_index(
+            &self.index,
+            vec![
+                fields.title,
+                fields.notes,
+                fields.context,
+                fields.tag,
+                fields.item_id,
+            ],
+        );
+        parser.set_conjunction_by_default();
+        parser.set_field_boost(fields.title, 2.0);
+        let (query, errors) = parser.parse_query_lenient(query);
+        for error in errors {
+            log::debug!("Search query issue: {error}");
+        }
+
+        let searcher = self.reader.searcher();
+        let (top, total) = searcher
+            .search(&query, &(TopDocs::with_limit(limit), Count))
+            .map_err(Error::search("Search failed"))?;
+
+        let snippets = |field: Field, max_chars: usize| -> Result<SnippetGenerator> {
+            let mut generator = SnippetGenerator::create(&searcher, &*query, field)
+                .map_err(Error::search("Failed to build snippets"))?;
+            generator.set_max_num_chars(max_chars);
+            Ok(generator)
+        };
+        let title_snippets = snippets(fields.title, usize::MAX)?;
+        let notes_snippets = snippets(fields.notes, SNIPPET_CHARS)?;
+        let context_snippets = snippets(fields.context, SNIPPET_CHARS)?;
+
+        let mut hits = Vec::with_capacity(top.len());
+        for (score, address) in top {
+            let doc: TantivyDocument = searcher
+                .doc(address)
+                .map_err(Error::search("Failed to load search hit"))?;
+
+            let title = text(&doc, fields.title);
+            let title_highlights = to_utf16(&title, &title_snippets.snippet_from_doc(&doc));
+            let snippet = [("notes", &notes_snippets), ("context", &context_snippets)]
+                .into_iter()
+                .map(|(name, generator)| (name, generator.snippet_from_doc(&doc)))
+                .find(|(_, snippet)| !snippet.highlighted().is_empty())
+                .map(|(field, snippet)| SearchSnippet {
+                    field,
+                    text: snippet.fragment().to_string(),
+                    highlights: to_utf16(snippet.fragment(), &snippet),
+                });
+
+            hits.push(SearchHit {
+                score,
+                item_id: text(&doc, fields.item_id),
+                doc_id: text(&doc, fields.doc_id),
+                doc_path: text(&doc, fields.doc_path),
+                project_id: text(&doc, fields.project),
+                title,
+                title_highlights,
+                item_type: text(&doc, fields.item_type),
+                status: text(&doc, fields.status),
+                priority: text(&doc, fields.priority),
+                tags: doc
+                    .get_all(fields.tag)
+                    .filter_map(|value| value.as_str().map(str::to_string))
+                    .collect(),
+                snippet,
+            });
+        }
+
+        Ok(SearchResults { total, hits })
+    }
+
+    fn writer(&self) -> MutexGuard<'_, IndexWriter> {
+        self.writer.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Deletes the document's items and, if given, adds the new ones.
+    fn replace(
+        &self,
+        writer: &mut IndexWriter,
+        path: &Path,
+        doc: Option<&RequestLogDoc>,
+    ) -> Result<()> {
+        let fields = self.fields;
+        let key = path.display().to_string();
+        writer.delete_term(Term::from_field_text(fields.doc_path, &key));
+
+        let Some(doc) = doc else {
+            return Ok(());
+        };
+        let stamp = file_stamp(path);
+        for item in &doc.items {
+            let mut entry = TantivyDocument::default();
+            entry.add_text(fields.doc_path, &key);
+            entry.add_u64(fields.mtime_ns, stamp);
+            entry.add_text(fields.doc_id, &doc.doc_id);
+            entry.add_text(fields.project, &doc.project_id);
+            entry.add_text(fields.item_id, &item.id);
+            entry.add_text(fields.title, &item.title);
+            entry.add_text(fields.notes, &item.notes);
+            entry.add_text(fields.context, &item.context);
+            for tag in &item.tags {
+                entry.add_text(fields.tag, tag);
+            }
+            entry.add_text(fields.item_type, &item.item_type);
+            entry.add_text(fields.status, &item.status);
+            entry.add_text(fields.priority, &item.priority);
+            entry.add_text(fields.domain, &item.domain);
+            entry.add_text(
+                fields.created_at,
+                crate::serializer::to_iso_string(&item.created_at),
+            );
+            writer
+                .add_document(entry)
+                .map_err(Error::search(format!("Failed to index {key}")))?;
+        }
+        Ok(())
+    }
+
+    fn commit(&self, writer: &mut IndexWriter) -> Result<()> {
+        writer
+            .commit()
+            .map_err(Error::search("Failed to commit search index"))?;
+        self.reader
+            .reload()
+            .map_err(Error::search("Failed to reload search index"))
+    }
+
+    /// Maps each indexed document path to the mtime it was indexed at.
+    fn indexed_stamps(&self) -> Result<HashMap<String, u64>> {
+        let searcher = self.reader.searcher();
+        let addresses = searcher
+            .search(&AllQuery, &DocSetCollector)
+            .map_err(Error::search("Failed to scan search index"))?;
+
+        let mut stamps = HashMap::new();
+        for address in addresses {
+            let doc: TantivyDocument = searcher
+                .doc(address)
+                .map_err(Error::search("Failed to scan search index"))?;
+            let stamp = doc
+                .get_first(self.fields.mtime_ns)
+                .and_then(|value| value.as_u64())
+                .unwrap_or_default();
+            stamps.insert(text(&doc, self.fields.doc_path), stamp);
+        }
+        Ok(stamps)
+    }
+}
+
+fn text(doc: &TantivyDocument, field: Field) -> String {
+    doc.get_first(field)
+        .and_then(|value| value.as_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn file_stamp(path: &Path) -> u64 {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |age| age.as_nanos() as u64)
+}
+
+/// Converts snippet byte ranges into UTF-16 offsets within `text`.
+fn to_utf16(text: &str, snippet: &Snippet) -> Vec<Highlight> {
+    let offset = |byte: usize| text[..byte].encode_utf16().count();
+    snippet
+        .highlighted()
+        .iter()
+        .map(|range| Highlight {
+            start: offset(range.start),
+            end: offset(range.end),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    use crate::models::{RequestLogItem, SCHEMA_VERSION};
+
+    fn item(n: u32, title: &str, status: &str, tags: &[&str], notes: &str) -> RequestLogItem {
+        RequestLogItem {
+            id: format!("REQ-20251203-app-{n:02}"),
+            title: title.to_string(),
+            item_type: "enhancement".to_string(),
+            domain: "web".to_string(),
+            context: "settings page".to_string(),
+            priority: "medium".to_string(),
+            status: status.to_string(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            notes: notes.to_string(),
+            extra: String::new(),
+            trailing: String::new(),
+            created_at: Utc.with_ymd_and_hms(2025, 12, 3, 0, 0, 0).unwrap(),
+        }
+    }
+
+    fn doc(items: Vec<RequestLogItem>) -> RequestLogDoc {
+        let at = Utc.with_ymd_and_hms(2025, 12, 3, 10, 0, 0).unwrap();
+        RequestLogDoc {
+            schema_version: SCHEMA_VERSION,
+            doc_id: "REQ-20251203-app".to_string(),
+            title: "App Log".to_string(),
+            project_id: "app".to_string(),
+            item_count: items.len() as u64,
+            items,
+            items_index: vec![],
+            tags: vec![],
+            created_at: at,
+            updated_at: at,
+            extra_frontmatter: vec![],
+            preamble: String::new(),
+        }
+    }
+
+    fn fixture() -> (tempfile::TempDir, SearchIndex, PathBuf) {
+        let root = tempfile::tempdir().unwrap();
+        let index = SearchIndex::open(&root.path().join("index")).unwrap();
+        let path = root.path().join("docs").join("REQ-20251203-app.md");
+        let doc = doc(vec![
+            item(
+                1,
+                "Add dark mode toggle",
+                "triage",
+                &["ux", "theme"],
+                "Users want a dark mode for night work.",
+            ),
+            item(
+                2,
+                "Dark sidebar contrast",
+                "backlog",
+                &["ux"],
+                "The mode switch is dark and hard to read.",
+            ),
+            item(
+                3,
+                "Export to CSV",
+                "triage",
+                &["data"],
+                "Allow exporting the catalog.",
+            ),
+        ]);
+        FsDocStore::new()
+            .write(path.to_str().unwrap(), &doc)
+            .unwrap();
+        index.index_document(&path, &doc).unwrap();
+        (root, index, path)
+    }
+
+    fn ids(results: &SearchResults) -> Vec<&str> {
+        results
+            .hits
+            .iter()
+            .map(|hit| hit.item_id.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn supports_phrases_and_field_prefixes() {
+        let (_root, index, _) = fixture();
+
+        let phrase = index.search("\"dark mode\"", 10).unwrap();
+        assert_eq!(ids(&phrase), vec!["REQ-20251203-app-01"]);
+
+        let fielded = index.search("tag:UX status:triage", 10).unwrap();
+        assert_eq!(ids(&fielded), vec!["REQ-20251203-app-01"]);
+
+        let by_id = index.search("id:REQ-20251203-app-03", 10).unwrap();
+        assert_eq!(by_id.total, 1);
+        assert_eq!(by_id.hits[0].title, "Export to CSV");
+    }
+
+    #[test]
+    fn ranks_title_matches_first_and_highlights() {
+        let (_root, index, _) = fixture();
+
+        // Both items mention "mode" in notes; only the first has it in the title
+        let ranked = index.search("mode", 10).unwrap();
+        assert_eq!(
+            ids(&ranked),
+            vec!["REQ-20251203-app-01", "REQ-20251203-app-02"]
+        );
+        assert!(ranked.hits[0].score > ranked.hits[1].score);
+
+        let results = index.search("dark", 10).unwrap();
+        let hit = results
+            .hits
+            .iter()
+            .find(|hit| hit.item_id == "REQ-20251203-app-01")
+            .unwrap();
+        assert_eq!(hit.title_highlights, vec![Highlight { start: 4, end: 8 }]);
+
+        let snippet = hit.snippet.as_ref().unwrap();
+        assert_eq!(snippet.field, "notes");
+        let first = snippet.highlights[0];
+        let highlighted = String::from_utf16(
+            &snippet.text.encode_utf16().collect::<Vec<_>>()[first.start..first.end],
+        )
+        .unwrap();
+        assert_eq!(highlighted, "dark");
+    }
+
+    #[test]
+    fn updates_incrementally_and_reconciles_with_disk() {
+        let (root, index, path) = fixture();
+        let store = FsDocStore::new();
+        let dirs = vec![root.path().join("docs")];
+
+        let unchanged = index.sync_dirs(&dirs, &store, false).unwrap();
+        assert_eq!(unchanged.unchanged, 1);
+        assert_eq!(unchanged.indexed, 0);
+
+        let mut updated = store.read(path.to_str().unwrap()).unwrap();
+        updated.items.truncate(1);
+        updated.items[0].title = "Add light theme".to_string();
+        store.write(path.to_str().unwrap(), &updated).unwrap();
+        // Written outside the index: reconciliation picks up the new mtime
+        let stats = index.sync_dirs(&dirs, &store, false).unwrap();
+        assert_eq!(stats.indexed, 1);
+        assert_eq!(index.search("toggle", 10).unwrap().total, 0);
+        assert_eq!(index.search("light", 10).unwrap().total, 1);
+
+        fs::remove_file(&path).unwrap();
+        let stats = index.sync_dirs(&dirs, &store, false).unwrap();
+        assert_eq!(stats.removed, 1);
+        assert_eq!(index.search("light", 10).unwrap().total, 0);
+
+        let rebuilt = index.sync_dirs(&dirs, &store, true).unwrap();
+        assert_eq!(rebuilt, SyncStats::default());
+    }
+}
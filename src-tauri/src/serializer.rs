@@ -0,0 +1,1006 @@
+//! Request-Log Markdown Serializer
+//!
+//! Native port of `src/core/serializer`. Handles:
+//! - Writing RequestLogDoc to markdown format with YAML frontmatter
+//! - Parsing markdown files back to RequestLogDoc
+//! - Tag aggregation (unique sorted list from all items)
+//! - Items index generation
+//!
+//! Output is byte-for-byte identical to the TypeScript serializer so files
+//! written by the desktop app, CLI and server remain interchangeable.
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::LazyLock;
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+use regex::Regex;
+
+use crate::models::{ItemIndexEntry, RequestLogDoc, RequestLogItem, LEGACY_SCHEMA_VERSION};
+
+/// Errors raised while parsing request-log markdown.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseError {
+    /// Frontmatter delimiters are missing or malformed
+    #[error("Invalid request-log format: missing or malformed YAML frontmatter delimiters")]
+    MissingFrontmatter,
+    /// Frontmatter block exists but is empty
+    #[error("Invalid request-log format: unable to extract frontmatter content")]
+    EmptyFrontmatter,
+    /// A required frontmatter field is absent or has the wrong type
+    #[error("Missing or invalid required field: {0}")]
+    MissingField(&'static str),
+    /// A frontmatter field that must be an array is not one
+    #[error("Invalid field type: {0} must be an array")]
+    NotAnArray(&'static str),
+}
+
+static FRONTMATTER_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)\A---\s*\n(.*?)\n---\s*\n(.*)\z").unwrap());
+static ITEM_HEADER_SPLIT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)^## (REQ-[^\n]+)$").unwrap());
+static ITEM_HEADER_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(REQ-[^\s]+)\s*-\s*(.+)$").unwrap());
+static METADATA_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"\*\*Type:\*\*[^\S\n]*([^|\n]+)\|[^\S\n]*\*\*Domain:\*\*[^\S\n]*([^|\n]+)\|[^\S\n]*\*\*Priority:\*\*[^\S\n]*([^|\n]+)\|[^\S\n]*\*\*Status:\*\*[^\S\n]*([^\n]+)",
+    )
+    .unwrap()
+});
+static TAGS_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)^[^\S\n]*\*\*Tags:\*\*[^\S\n]*([^\n]*)").unwrap());
+static CONTEXT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)^[^\S\n]*\*\*Context:\*\*[^\S\n]*([^\n]*)").unwrap());
+static NOTES_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?ms)^###[^\S\n]*Problem/Goal[^\S\n]*(?:\n|\z)(.*)").unwrap());
+static TRAILING_SEPARATOR_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\n*---\s*\z").unwrap());
+
+/// Frontmatter keys written by [`serialize`]; any other key is kept verbatim.
+const FRONTMATTER_KEYS: &[&str] = &[
+    "type",
+    "schema_version",
+    "doc_id",
+    "title",
+    "project_id",
+    "item_count",
+    "tags",
+    "items_index",
+    "created_at",
+    "updated_at",
+];
+
+static ID_DATE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"REQ-(\d{8})-").unwrap());
+static DIGITS_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\d+$").unwrap());
+
+/// Serializes a RequestLogDoc to markdown format with YAML frontmatter.
+///
+/// Output format:
+/// ```text
+/// ---
+/// type: request-log
+/// schema_version: 2
+/// doc_id: REQ-20251203-capture-app
+/// title: Capture App Request Log
+/// project_id: capture-app
+/// item_count: 2
+/// tags: [api, enhancement, ux]
+/// items_index:
+///   - id: REQ-20251203-capture-app-01
+///     type: enhancement
+///     title: Add dark mode toggle
+/// created_at: 2025-12-03T10:00:00.000Z
+/// updated_at: 2025-12-03T14:30:00.000Z
+/// ---
+///
+/// ## REQ-20251203-capture-app-01 - Add dark mode toggle
+/// ...
+/// ```
+///
+/// Unknown frontmatter entries, the preamble and free-form item sections
+/// kept by [`parse`] are written back in place, so hand-edited files survive
+/// a rewrite. Without them the output matches the TypeScript serializer.
+pub fn serialize(doc: &RequestLogDoc) -> String {
+    let frontmatter = serialize_frontmatter(doc);
+    let mut items_sections = doc
+        .items
+        .iter()
+        .map(serialize_item)
+        .collect::<Vec<_>>()
+        .join("\n\n---\n\n");
+    if !doc.preamble.is_empty() {
+        items_sections = if doc.items.is_empty() {
+            doc.preamble.clone()
+        } else {
+            format!("{}\n\n{items_sections}", doc.preamble)
+        };
+    }
+
+    format!("{frontmatter}\n\n{items_sections}\n")
+}
+
+/// Parses a markdown string with YAML frontmatter into a RequestLogDoc.
+///
+/// Handles frontmatter extraction, item section parsing, date
+/// deserialization and required field validation. Item sections with a
+/// malformed header or missing metadata line are not returned as items,
+/// matching the TypeScript parser, but their text is kept for [`serialize`]
+/// along with unknown frontmatter keys and other free-form content.
+pub fn parse(content: &str) -> Result<RequestLogDoc, ParseError> {
+    let (yaml_content, body) = extract_frontmatter(content)?;
+    let frontmatter = parse_yaml(yaml_content);
+
+    let schema_version = version_field(&frontmatter)?;
+    let doc_id = match frontmatter.get("doc_id") {
+        Some(YamlValue::Str(s)) if !s.is_empty() => s.clone(),
+        _ => return Err(ParseError::MissingField("doc_id")),
+    };
+    let title = match frontmatter.get("title") {
+        Some(YamlValue::Str(s)) if !s.is_empty() => s.clone(),
+        _ => return Err(ParseError::MissingField("title")),
+    };
+    let project_id = match frontmatter.get("project_id") {
+        Some(YamlValue::Str(s)) if !s.is_empty() => s.clone(),
+        _ => return Err(ParseError::MissingField("project_id")),
+    };
+    let item_count = match frontmatter.get("item_count") {
+        Some(YamlValue::Int(n)) => *n,
+        _ => return Err(ParseError::MissingField("item_count")),
+    };
+    let tags = match frontmatter.get("tags") {
+        None => Vec::new(),
+        Some(v) if v.is_falsy() => Vec::new(),
+        Some(YamlValue::List(list)) => list.clone(),
+        Some(_) => return Err(ParseError::NotAnArray("tags")),
+    };
+    let items_index = match frontmatter.get("items_index") {
+        None => Vec::new(),
+        Some(v) if v.is_falsy() => Vec::new(),
+        Some(YamlValue::Maps(entries)) => entries
+            .iter()
+            .map(|entry| ItemIndexEntry {
+                id: entry.get("id").cloned().unwrap_or_default(),
+                item_type: entry.get("type").cloned().unwrap_or_default(),
+                title: entry.get("title").cloned().unwrap_or_default(),
+            })
+            .collect(),
+        Some(YamlValue::List(list)) if list.is_empty() => Vec::new(),
+        Some(_) => return Err(ParseError::NotAnArray("items_index")),
+    };
+    let created_at = frontmatter
+        .get("created_at")
+        .and_then(YamlValue::as_date)
+        .ok_or(ParseError::MissingField("created_at"))?;
+    let updated_at = frontmatter
+        .get("updated_at")
+        .and_then(YamlValue::as_date)
+        .ok_or(ParseError::MissingField("updated_at"))?;
+
+    let extra_frontmatter = extra_frontmatter(yaml_content);
+    let ParsedBody { preamble, items } = parse_body(body);
+
+    Ok(RequestLogDoc {
+        schema_version,
+        doc_id,
+        title,
+        project_id,
+        items,
+        items_index,
+        tags,
+        item_count,
+        created_at,
+        updated_at,
+        extra_frontmatter,
+        preamble,
+    })
+}
+
+/// Reads only the `schema_version` of a request-log file.
+///
+/// Unlike [`parse`] this does not validate the rest of the document, so it
+/// works on files in formats this build cannot otherwise read.
+pub fn schema_version(content: &str) -> Result<u32, ParseError> {
+    let (yaml_content, _) = extract_frontmatter(content)?;
+    version_field(&parse_yaml(yaml_content))
+}
+
+/// Aggregates tags from all items in a document.
+///
+/// Returns a unique, alphabetically sorted list used to refresh the
+/// document-level `tags` field.
+pub fn aggregate_tags(items: &[RequestLogItem]) -> Vec<String> {
+    items
+        .iter()
+        .flat_map(|item| item.tags.iter().cloned())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Creates the frontmatter items index from an items slice.
+pub fn update_items_index(items: &[RequestLogItem]) -> Vec<ItemIndexEntry> {
+    items
+        .iter()
+        .map(|item| ItemIndexEntry {
+            id: item.id.clone(),
+            item_type: item.item_type.clone(),
+            title: item.title.clone(),
+        })
+        .collect()
+}
+
+/// Formats a timestamp the way JavaScript's `Date.prototype.toISOString` does.
+pub fn to_iso_string(date: &DateTime<Utc>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+// ============================================================================
+// Internal Helper Functions
+// ============================================================================
+
+/// Value produced by the minimal frontmatter YAML parser.
+#[derive(Debug, Clone, PartialEq)]
+enum YamlValue {
+    Str(String),
+    Int(u64),
+    Bool(bool),
+    /// Bracket notation array: `[a, b]`
+    List(Vec<String>),
+    /// Indented list of `key: value` maps (items_index)
+    Maps(Vec<HashMap<String, String>>),
+}
+
+impl YamlValue {
+    /// Mirrors JavaScript falsiness for the `x || []` fallbacks in the TS parser.
+    fn is_falsy(&self) -> bool {
+        matches!(self, YamlValue::Str(s) if s.is_empty())
+            || matches!(self, YamlValue::Int(0) | YamlValue::Bool(false))
+    }
+
+    fn as_date(&self) -> Option<DateTime<Utc>> {
+        match self {
+            YamlValue::Str(s) => parse_date(s),
+            _ => None,
+        }
+    }
+}
+
+fn serialize_frontmatter(doc: &RequestLogDoc) -> String {
+    let mut lines = vec!["---".to_string(), "type: request-log".to_string()];
+    // Legacy files have no version key; keep them byte-identical
+    if doc.schema_version != LEGACY_SCHEMA_VERSION {
+        lines.push(format!("schema_version: {}", doc.schema_version));
+    }
+    lines.extend([
+        format!("doc_id: {}", doc.doc_id),
+        format!("title: {}", doc.title),
+        format!("project_id: {}", doc.project_id),
+        format!("item_count: {}", doc.item_count),
+        format!("tags: [{}]", doc.tags.join(", ")),
+        "items_index:".to_string(),
+    ]);
+
+    for entry in &doc.items_index {
+        lines.push(format!("  - id: {}", entry.id));
+        lines.push(format!("    type: {}", entry.item_type));
+        lines.push(format!("    title: {}", entry.title));
+    }
+
+    lines.push(format!("created_at: {}", to_iso_string(&doc.created_at)));
+    lines.push(format!("updated_at: {}", to_iso_string(&doc.updated_at)));
+    lines.extend(doc.extra_frontmatter.iter().cloned());
+    lines.push("---".to_string());
+
+    lines.join("\n")
+}
+
+fn serialize_item(item: &RequestLogItem) -> String {
+    let mut lines = vec![
+        format!("## {} - {}", item.id, item.title),
+        String::new(),
+        format!(
+            "**Type:** {} | **Domain:** {} | **Priority:** {} | **Status:** {}",
+            item.item_type, item.domain, item.priority, item.status
+        ),
+        format!("**Tags:** {}", item.tags.join(", ")),
+        format!("**Context:** {}", item.context),
+        String::new(),
+    ];
+    if !item.extra.is_empty() {
+        lines.push(item.extra.clone());
+        lines.push(String::new());
+    }
+    lines.push("### Problem/Goal".to_string());
+    lines.push(item.notes.clone());
+
+    let section = lines.join("\n");
+    if item.trailing.is_empty() {
+        section
+    } else {
+        format!("{section}\n\n---\n\n{}", item.trailing)
+    }
+}
+
+/// `schema_version` from parsed frontmatter; absent means legacy.
+fn version_field(frontmatter: &HashMap<String, YamlValue>) -> Result<u32, ParseError> {
+    match frontmatter.get("schema_version") {
+        None => Ok(LEGACY_SCHEMA_VERSION),
+        Some(YamlValue::Int(n)) => u32::try_from(*n)
+            .ok()
+            .filter(|n| *n >= LEGACY_SCHEMA_VERSION)
+            .ok_or(ParseError::MissingField("schema_version")),
+        Some(_) => Err(ParseError::MissingField("schema_version")),
+    }
+}
+
+fn extract_frontmatter(content: &str) -> Result<(&str, &str), ParseError> {
+    let captures = FRONTMATTER_RE
+        .captures(content)
+        .ok_or(ParseError::MissingFrontmatter)?;
+
+    let yaml_content = captures.get(1).map_or("", |m| m.as_str());
+    let body = captures.get(2).map_or("", |m| m.as_str());
+
+    if yaml_content.is_empty() {
+        return Err(ParseError::EmptyFrontmatter);
+    }
+
+    Ok((yaml_content, body))
+}
+
+/// Simple YAML parser for frontmatter.
+///
+/// Supports `key: value` pairs, bracket arrays and the indented list used by
+/// `items_index`. Deliberately mirrors the TS `parseYaml` rather than the
+/// YAML spec so both implementations accept exactly the same files.
+fn parse_yaml(yaml_content: &str) -> HashMap<String, YamlValue> {
+    let mut result = HashMap::new();
+    let lines: Vec<&str> = yaml_content.split('\n').collect();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i].trim();
+
+        // Skip empty lines and comments
+        if line.is_empty() || line.starts_with('#') {
+            i += 1;
+            continue;
+        }
+
+        let Some(colon_index) = line.find(':') else {
+            i += 1;
+            continue;
+        };
+
+        let key = line[..colon_index].trim().to_string();
+        let value_str = line[colon_index + 1..].trim();
+
+        // Array in bracket notation
+        if value_str.len() >= 2 && value_str.starts_with('[') && value_str.ends_with(']') {
+            let items = value_str[1..value_str.len() - 1]
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(String::from)
+                .collect();
+            result.insert(key, YamlValue::List(items));
+            i += 1;
+            continue;
+        }
+
+        // Nested list (items_index)
+        let next_line = lines.get(i + 1).copied().unwrap_or("");
+        if value_str.is_empty() && !next_line.is_empty() && next_line.trim().starts_with('-') {
+            let mut list_items: Vec<HashMap<String, String>> = Vec::new();
+            i += 1;
+
+            while i < lines.len() {
+                let list_line = lines[i];
+                if list_line.is_empty()
+                    || (!list_line.trim().starts_with('-') && !list_line.starts_with("  "))
+                {
+                    break;
+                }
+
+                // Start of new list item; the TS parser drops the inline
+                // `- id: ...` property, we keep it.
+                let prop_line = match list_line.trim().strip_prefix('-') {
+                    Some(rest) => {
+                        list_items.push(HashMap::new());
+                        rest.trim()
+                    }
+                    None => list_line.trim(),
+                };
+
+                if let (Some(last_item), Some(prop_colon)) =
+                    (list_items.last_mut(), prop_line.find(':'))
+                {
+                    let prop_key = prop_line[..prop_colon].trim().to_string();
+                    let prop_value = prop_line[prop_colon + 1..].trim().to_string();
+                    last_item.insert(prop_key, prop_value);
+                }
+
+                i += 1;
+            }
+
+            result.insert(key, YamlValue::Maps(list_items));
+            continue;
+        }
+
+        let value = if DIGITS_RE.is_match(value_str) {
+            value_str
+                .parse::<u64>()
+                .map_or_else(|_| YamlValue::Str(value_str.to_string()), YamlValue::Int)
+        } else if value_str == "true" || value_str == "false" {
+            YamlValue::Bool(value_str == "true")
+        } else {
+            YamlValue::Str(value_str.to_string())
+        };
+        result.insert(key, value);
+        i += 1;
+    }
+
+    result
+}
+
+/// Item body sections and the text around them.
+struct ParsedBody {
+    /// Text before the first item, verbatim
+    preamble: String,
+    items: Vec<RequestLogItem>,
+}
+
+/// Parses item sections from the markdown body.
+///
+/// Each item starts with a `## {id} - {title}` header. Unlike the TS regexes,
+/// metadata, Tags and Context captures never cross a line break, so an
+/// empty `**Tags:** ` line no longer swallows the following Context line.
+/// Tags, Context and the `### Problem/Goal` heading must start a line, so
+/// markers quoted inside another field are not picked up.
+/// Together these keep serialize -> parse a fixed point (see `fuzz/`).
+///
+/// Nothing is dropped: text before the first item becomes the preamble and
+/// sections with a malformed header or missing metadata line are kept
+/// verbatim in the `trailing` text of the item before them.
+fn parse_body(body: &str) -> ParsedBody {
+    let headers: Vec<_> = ITEM_HEADER_SPLIT_RE.captures_iter(body).collect();
+    let mut preamble = None;
+    let mut items: Vec<RequestLogItem> = Vec::with_capacity(headers.len());
+    // Start of the unparseable sections seen since the last item
+    let mut skipped_from = None;
+
+    for (index, captures) in headers.iter().enumerate() {
+        let (Some(whole), Some(header_raw)) = (captures.get(0), captures.get(1)) else {
+            continue;
+        };
+        let section_end = headers
+            .get(index + 1)
+            .and_then(|next| next.get(0))
+            .map_or(body.len(), |m| m.start());
+        let section = &body[whole.end()..section_end];
+        let has_next = section_end < body.len();
+
+        let Some(item) = parse_item(header_raw.as_str().trim(), section, has_next) else {
+            skipped_from.get_or_insert(whole.start());
+            continue;
+        };
+
+        match items.last_mut() {
+            None => preamble = Some(trim_blank_lines(&body[..whole.start()])),
+            Some(last) => {
+                if let Some(from) = skipped_from {
+                    // The separator line before this item is written again
+                    let skipped = body[from..whole.start()].trim();
+                    last.trailing = match skipped.strip_suffix("---") {
+                        Some(rest) if rest.ends_with('\n') => rest.trim_end(),
+                        _ => skipped,
+                    }
+                    .to_string();
+                }
+            }
+        }
+        skipped_from = None;
+        items.push(item);
+    }
+
+    match (items.last_mut(), skipped_from) {
+        (None, _) => preamble = Some(trim_blank_lines(body)),
+        (Some(last), Some(from)) => last.trailing = body[from..].trim().to_string(),
+        (Some(_), None) => {}
+    }
+
+    ParsedBody {
+        preamble: preamble.unwrap_or_default(),
+        items,
+    }
+}
+
+/// Parses one item section; `None` when the header or metadata is malformed.
+fn parse_item(header: &str, section: &str, has_next: bool) -> Option<RequestLogItem> {
+    let content = section.trim();
+    // Where `content` starts, so extra lines keep their indentation
+    let offset = section.len() - section.trim_start().len();
+
+    let header_match = ITEM_HEADER_RE.captures(header)?;
+    let id = header_match[1].to_string();
+    let title = header_match[2].to_string();
+
+    let metadata = METADATA_RE.captures(content)?;
+    let item_type = metadata[1].trim().to_string();
+    let domain = metadata[2].trim().to_string();
+    let priority = metadata[3].trim().to_string();
+    let status = metadata[4].trim().to_string();
+
+    let tags_match = TAGS_RE.captures(content);
+    let tags = tags_match
+        .as_ref()
+        .map(|c| {
+            c[1].split(',')
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let context_match = CONTEXT_RE.captures(content);
+    let context = context_match
+        .as_ref()
+        .map(|c| c[1].trim().to_string())
+        .unwrap_or_default();
+
+    // Everything after "### Problem/Goal", minus the separator written
+    // between items. The last item has none, so a closing `---` there
+    // belongs to the notes.
+    let notes_match = NOTES_RE.captures(content);
+    let raw_notes = notes_match
+        .as_ref()
+        .map_or("", |c| c.get(1).map_or("", |m| m.as_str()))
+        .trim();
+    let notes = if has_next {
+        TRAILING_SEPARATOR_RE
+            .replacen(raw_notes, 1, "")
+            .trim()
+            .to_string()
+    } else {
+        raw_notes.to_string()
+    };
+
+    // Lines before the notes that are not a known field
+    let head_end = notes_match
+        .as_ref()
+        .and_then(|c| c.get(0))
+        .map_or(content.len(), |m| m.start());
+    let known_starts: Vec<usize> = [Some(&metadata), tags_match.as_ref(), context_match.as_ref()]
+        .into_iter()
+        .flatten()
+        .filter_map(|c| c.get(0).map(|m| offset + m.start()))
+        .collect();
+    let extra = extra_lines(&section[..offset + head_end], &known_starts);
+
+    let created_at = ID_DATE_RE
+        .captures(&id)
+        .and_then(|c| parse_date_from_id(&c[1]))
+        .unwrap_or_else(Utc::now);
+
+    Some(RequestLogItem {
+        id,
+        title,
+        item_type,
+        domain,
+        context,
+        priority,
+        status,
+        tags,
+        notes,
+        extra,
+        trailing: String::new(),
+        created_at,
+    })
+}
+
+/// Drops blank lines around `text`, keeping the indentation of the first
+/// line so it cannot turn into an item header.
+fn trim_blank_lines(text: &str) -> String {
+    let text = text.trim_end();
+    let first = text
+        .find(|c: char| !c.is_whitespace())
+        .unwrap_or(text.len());
+    let line_start = text[..first].rfind('\n').map_or(0, |i| i + 1);
+    text[line_start..].to_string()
+}
+
+/// Joins the lines of `text` that contain none of `known_starts`, without
+/// leading or trailing blank lines.
+fn extra_lines(text: &str, known_starts: &[usize]) -> String {
+    let mut offset = 0;
+    let mut lines = Vec::new();
+    for line in text.split_inclusive('\n') {
+        let range = offset..offset + line.len();
+        offset = range.end;
+        if !known_starts.iter().any(|start| range.contains(start)) {
+            lines.push(line.trim_end_matches(['\n', '\r']));
+        }
+    }
+
+    let first = lines.iter().position(|line| !line.trim().is_empty());
+    let last = lines.iter().rposition(|line| !line.trim().is_empty());
+    match (first, last) {
+        (Some(first), Some(last)) => lines[first..=last].join("\n"),
+        _ => String::new(),
+    }
+}
+
+/// Raw frontmatter entries whose key is not one the serializer writes.
+///
+/// Lines are grouped the way [`parse_yaml`] reads them: a key line plus
+/// its nested list, or a single line. Comments are kept as entries of
+/// their own, blank and `---` lines are dropped.
+fn extra_frontmatter(yaml_content: &str) -> Vec<String> {
+    // Once known keys and blank lines are gone a list can run into the
+    // lines after it; group again so the result matches the next parse.
+    let kept = unknown_entries(yaml_content).join("\n");
+    unknown_entries(&kept)
+}
+
+fn unknown_entries(yaml_content: &str) -> Vec<String> {
+    let lines: Vec<&str> = yaml_content
+        .split('\n')
+        .map(|line| line.trim_end_matches('\r'))
+        .collect();
+    let mut entries = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i].trim();
+        // A delimiter would end the frontmatter when written back
+        if line.is_empty() || lines[i].trim_end() == "---" {
+            i += 1;
+            continue;
+        }
+
+        let (key, value) = match line.split_once(':') {
+            Some((key, value)) if !line.starts_with('#') => (key.trim(), value.trim()),
+            _ => ("", line),
+        };
+        let mut end = i + 1;
+        let next_line = lines.get(end).copied().unwrap_or("");
+        if !key.is_empty() && value.is_empty() && next_line.trim().starts_with('-') {
+            while lines.get(end).is_some_and(|list_line| {
+                !list_line.is_empty()
+                    && (list_line.trim().starts_with('-') || list_line.starts_with("  "))
+            }) {
+                end += 1;
+            }
+        }
+
+        if !FRONTMATTER_KEYS.contains(&key) {
+            entries.push(lines[i..end].join("\n"));
+        }
+        i = end;
+    }
+
+    entries
+}
+
+/// Parses an ISO 8601 frontmatter timestamp.
+///
+/// Accepts full RFC 3339 timestamps and the date-only / offset-less forms
+/// that `new Date()` understands; offset-less values are treated as UTC.
+/// Years outside 0000-9999 are rejected since they cannot be written back
+/// in the four-digit form.
+fn parse_date(date_str: &str) -> Option<DateTime<Utc>> {
+    let date = if let Ok(date) = DateTime::parse_from_rfc3339(date_str) {
+        date.with_timezone(&Utc)
+    } else if let Ok(date) = NaiveDateTime::parse_from_str(date_str, "%Y-%m-%dT%H:%M:%S%.f") {
+        date.and_utc()
+    } else {
+        NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
+            .ok()?
+            .and_hms_opt(0, 0, 0)?
+            .and_utc()
+    };
+    (0..=9999).contains(&date.year()).then_some(date)
+}
+
+/// Parses the `YYYYMMDD` date embedded in an item ID as UTC midnight.
+fn parse_date_from_id(date_str: &str) -> Option<DateTime<Utc>> {
+    NaiveDate::parse_from_str(date_str, "%Y%m%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date| date.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    use crate::models::SCHEMA_VERSION;
+
+    fn test_item(id: &str, title: &str, tags: &[&str]) -> RequestLogItem {
+        RequestLogItem {
+            id: id.to_string(),
+            title: title.to_string(),
+            item_type: "enhancement".to_string(),
+            domain: "web".to_string(),
+            context: "Test context".to_string(),
+            priority: "medium".to_string(),
+            status: "triage".to_string(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            notes: "Test notes describing the problem or goal.".to_string(),
+            extra: String::new(),
+            trailing: String::new(),
+            created_at: Utc.with_ymd_and_hms(2025, 12, 3, 0, 0, 0).unwrap(),
+        }
+    }
+
+    fn test_doc() -> RequestLogDoc {
+        let items = vec![
+            test_item(
+                "REQ-20251203-test-project-01",
+                "First Test Item",
+                &["test", "example"],
+            ),
+            test_item(
+                "REQ-20251203-test-project-02",
+                "Second Test Item",
+                &["example", "feature"],
+            ),
+        ];
+        RequestLogDoc {
+            schema_version: SCHEMA_VERSION,
+            doc_id: "REQ-20251203-test-project".to_string(),
+            title: "Test Request Log".to_string(),
+            project_id: "test-project".to_string(),
+            tags: aggregate_tags(&items),
+            items_index: update_items_index(&items),
+            item_count: items.len() as u64,
+            items,
+            created_at: Utc.with_ymd_and_hms(2025, 12, 3, 10, 0, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2025, 12, 3, 14, 30, 0).unwrap(),
+            extra_frontmatter: vec![],
+            preamble: String::new(),
+        }
+    }
+
+    #[test]
+    fn serialize_matches_typescript_output() {
+        let expected = "---
+type: request-log
+schema_version: 2
+doc_id: REQ-20251203-test-project
+title: Test Request Log
+project_id: test-project
+item_count: 2
+tags: [example, feature, test]
+items_index:
+  - id: REQ-20251203-test-project-01
+    type: enhancement
+    title: First Test Item
+  - id: REQ-20251203-test-project-02
+    type: enhancement
+    title: Second Test Item
+created_at: 2025-12-03T10:00:00.000Z
+updated_at: 2025-12-03T14:30:00.000Z
+---
+
+## REQ-20251203-test-project-01 - First Test Item
+
+**Type:** enhancement | **Domain:** web | **Priority:** medium | **Status:** triage
+**Tags:** test, example
+**Context:** Test context
+
+### Problem/Goal
+Test notes describing the problem or goal.
+
+---
+
+## REQ-20251203-test-project-02 - Second Test Item
+
+**Type:** enhancement | **Domain:** web | **Priority:** medium | **Status:** triage
+**Tags:** example, feature
+**Context:** Test context
+
+### Problem/Goal
+Test notes describing the problem or goal.
+";
+        assert_eq!(serialize(&test_doc()), expected);
+    }
+
+    #[test]
+    fn serialize_empty_document() {
+        let doc = RequestLogDoc {
+            items: vec![],
+            items_index: vec![],
+            tags: vec![],
+            item_count: 0,
+            ..test_doc()
+        };
+        let markdown = serialize(&doc);
+
+        assert!(markdown.contains("tags: []\nitems_index:\ncreated_at:"));
+        assert!(markdown.ends_with("---\n\n\n"));
+    }
+
+    #[test]
+    fn roundtrip_preserves_document() {
+        let doc = test_doc();
+        assert_eq!(parse(&serialize(&doc)).unwrap(), doc);
+    }
+
+    #[test]
+    fn parse_empty_tags_does_not_swallow_context() {
+        let mut doc = test_doc();
+        doc.items[0].tags.clear();
+        doc.items[0].context.clear();
+
+        let parsed = parse(&serialize(&doc)).unwrap();
+
+        assert!(parsed.items[0].tags.is_empty());
+        assert_eq!(parsed.items[0].context, "");
+        assert_eq!(parsed.items[0].notes, doc.items[0].notes);
+    }
+
+    #[test]
+    fn roundtrip_keeps_markers_inside_fields() {
+        let mut doc = test_doc();
+        doc.items[0].tags = vec!["### Problem/Goal".to_string()];
+        doc.items[0].domain = "web **Tags:** x".to_string();
+        doc.items[0].context = "see **Tags:** above".to_string();
+        doc.items[1].notes = "Closing rule\n---".to_string();
+
+        assert_eq!(parse(&serialize(&doc)).unwrap(), doc);
+    }
+
+    #[test]
+    fn parse_rejects_dates_that_cannot_be_written_back() {
+        let markdown = serialize(&test_doc()).replace(
+            "updated_at: 2025-12-03T14:30:00.000Z",
+            "updated_at: -2025-12-03T00:00:00",
+        );
+
+        assert!(matches!(
+            parse(&markdown),
+            Err(ParseError::MissingField("updated_at"))
+        ));
+    }
+
+    #[test]
+    fn parse_skips_items_without_metadata() {
+        let markdown = "---
+doc_id: REQ-20251203-test-project
+title: Test
+project_id: test-project
+item_count: 2
+tags: []
+items_index:
+created_at: 2025-12-03T10:00:00Z
+updated_at: 2025-12-03T10:00:00Z
+---
+
+## REQ-20251203-test-project-01 - No metadata
+
+Just text
+
+---
+
+## REQ-20251203-test-project-02 - Valid
+
+**Type:** bug | **Domain:** api | **Priority:** high | **Status:** backlog
+**Tags:** urgent
+**Context:** ctx
+
+### Problem/Goal
+Line one
+
+Line two
+";
+        let doc = parse(markdown).unwrap();
+
+        assert_eq!(doc.items.len(), 1);
+        assert_eq!(doc.items[0].id, "REQ-20251203-test-project-02");
+        assert_eq!(doc.items[0].item_type, "bug");
+        assert_eq!(doc.items[0].tags, vec!["urgent"]);
+        assert_eq!(doc.items[0].notes, "Line one\n\nLine two");
+        assert!(doc.items_index.is_empty());
+    }
+
+    #[test]
+    fn roundtrip_preserves_hand_edited_content() {
+        let markdown = "---
+type: request-log
+doc_id: REQ-20251203-test-project
+title: Test
+project_id: test-project
+item_count: 2
+tags: [api, urgent]
+items_index:
+  - id: REQ-20251203-test-project-01
+    type: bug
+    title: First
+  - id: REQ-20251203-test-project-02
+    type: bug
+    title: Second
+created_at: 2025-12-03T10:00:00.000Z
+updated_at: 2025-12-03T10:00:00.000Z
+owner: alice
+# reviewed weekly
+links:
+  - https://example.com/board
+---
+
+Intro written by hand.
+
+## REQ-20251203-test-project-01 - First
+
+**Type:** bug | **Domain:** api | **Priority:** high | **Status:** backlog
+**Tags:** api
+**Context:** ctx
+
+**Estimate:** 3d
+
+### Problem/Goal
+Line one
+
+#### Acceptance Criteria
+- works
+
+---
+
+## REQ-draft - Not triaged yet
+
+Kept as written.
+
+---
+
+## REQ-20251203-test-project-02 - Second
+
+**Type:** bug | **Domain:** api | **Priority:** high | **Status:** backlog
+**Tags:** urgent
+**Context:** 
+
+### Problem/Goal
+Line two
+";
+        let doc = parse(markdown).unwrap();
+
+        assert_eq!(
+            doc.extra_frontmatter,
+            vec![
+                "owner: alice",
+                "# reviewed weekly",
+                "links:\n  - https://example.com/board"
+            ]
+        );
+        assert_eq!(doc.preamble, "Intro written by hand.");
+        assert_eq!(doc.items.len(), 2);
+        assert_eq!(doc.items[0].extra, "**Estimate:** 3d");
+        assert_eq!(
+            doc.items[0].notes,
+            "Line one\n\n#### Acceptance Criteria\n- works"
+        );
+        assert_eq!(
+            doc.items[0].trailing,
+            "## REQ-draft - Not triaged yet\n\nKept as written."
+        );
+        assert!(doc.items[1].extra.is_empty());
+        assert_eq!(serialize(&doc), markdown);
+    }
+
+    #[test]
+    fn parse_rejects_missing_fields() {
+        assert_eq!(parse("no frontmatter"), Err(ParseError::MissingFrontmatter));
+        assert_eq!(
+            parse("---\ntitle: x\n---\n"),
+            Err(ParseError::MissingField("doc_id"))
+        );
+        assert_eq!(
+            parse("---\ndoc_id: a\ntitle: b\nproject_id: c\nitem_count: x\n---\n"),
+            Err(ParseError::MissingField("item_count"))
+        );
+        assert_eq!(
+            parse("---\ndoc_id: a\ntitle: b\nproject_id: c\nitem_count: 1\ntags: x\n---\n"),
+            Err(ParseError::NotAnArray("tags"))
+        );
+    }
+}
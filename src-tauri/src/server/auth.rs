@@ -0,0 +1,147 @@
+//! Bearer Token Authentication
+//!
+//! Port of `src/server/middleware/auth.ts`:
+//! - `Authorization: Bearer {token}` must match, compared in constant time
+//!   (the server does not start without a token)
+//! - Failures return 401 `{ error: "Unauthorized", message }` with `WWW-Authenticate: Bearer`
+//! - Every route, `/health` included, also requires a localhost `Host` so web
+//!   pages cannot reach the server through DNS rebinding (403 otherwise)
+
+use axum::extract::{Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde_json::json;
+
+use crate::server::{ApiError, ApiState};
+
+/// Checks an `Authorization` header value against the configured token.
+///
+/// Returns the 401 message on failure.
+pub fn check_bearer(header: Option<&str>, token: &str) -> Result<(), &'static str> {
+    let header = header.ok_or("Missing Authorization header")?;
+    let provided = header
+        .split_once(char::is_whitespace)
+        .filter(|(scheme, _)| scheme.eq_ignore_ascii_case("bearer"))
+        .map(|(_, provided)| provided.trim_start())
+        .filter(|provided| !provided.is_empty())
+        .ok_or("Invalid Authorization header format. Expected: Bearer {token}")?;
+
+    if constant_time_eq(provided.as_bytes(), token.as_bytes()) {
+        Ok(())
+    } else {
+        Err("Invalid bearer token")
+    }
+}
+
+/// Compares two byte strings without short-circuiting on the first mismatch.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// True if a `Host` header names this machine: `localhost`, `127.0.0.1`
+/// or `[::1]`, with or without a port.
+pub fn is_local_host(host: Option<&str>) -> bool {
+    let Some(host) = host else {
+        return false;
+    };
+    let name = match host.rsplit_once(':') {
+        Some((name, port)) if !name.ends_with(':') && port.bytes().all(|b| b.is_ascii_digit()) => {
+            name
+        }
+        _ => host,
+    };
+    ["localhost", "127.0.0.1", "[::1]"]
+        .iter()
+        .any(|local| name.eq_ignore_ascii_case(local))
+}
+
+/// Middleware rejecting requests whose `Host` is not localhost.
+pub async fn require_local_host(request: Request, next: Next) -> Response {
+    let host = request
+        .headers()
+        .get(header::HOST)
+        .and_then(|value| value.to_str().ok());
+
+    if is_local_host(host) {
+        next.run(request).await
+    } else {
+        ApiError::forbidden("Host not allowed; use 127.0.0.1 or localhost").into_response()
+    }
+}
+
+/// Middleware rejecting `/api` requests without a valid bearer token.
+pub async fn require_token(
+    State(state): State<ApiState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let header = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+
+    match check_bearer(header, &state.token) {
+        Ok(()) => next.run(request).await,
+        Err(message) => (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+            Json(json!({ "error": "Unauthorized", "message": message })),
+        )
+            .into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bearer_header_must_match_token() {
+        assert_eq!(check_bearer(Some("Bearer s3cret"), "s3cret"), Ok(()));
+        assert_eq!(check_bearer(Some("bearer   s3cret"), "s3cret"), Ok(()));
+        assert_eq!(
+            check_bearer(None, "s3cret"),
+            Err("Missing Authorization header")
+        );
+        assert_eq!(
+            check_bearer(Some("Basic s3cret"), "s3cret"),
+            Err("Invalid Authorization header format. Expected: Bearer {token}")
+        );
+        assert_eq!(
+            check_bearer(Some("Bearer"), "s3cret"),
+            Err("Invalid Authorization header format. Expected: Bearer {token}")
+        );
+        assert_eq!(
+            check_bearer(Some("Bearer s3cre"), "s3cret"),
+            Err("Invalid bearer token")
+        );
+    }
+
+    #[test]
+    fn host_must_be_localhost() {
+        for host in [
+            "localhost",
+            "localhost:3737",
+            "127.0.0.1:3737",
+            "LOCALHOST",
+            "[::1]:3737",
+            "[::1]",
+        ] {
+            assert!(is_local_host(Some(host)), "{host}");
+        }
+        for host in [
+            "evil.example",
+            "localhost.evil.example:3737",
+            "127.0.0.1.nip.io",
+            "",
+        ] {
+            assert!(!is_local_host(Some(host)), "{host}");
+        }
+        assert!(!is_local_host(None));
+    }
+}
@@ -0,0 +1,141 @@
+//! Document Routes
+//!
+//! `/api/docs` handlers (see `src/server/routes/docs.ts`):
+//! - GET    /api/docs?directory={path}           - List documents
+//! - GET    /api/docs/{doc_id}?path={path}       - Read document
+//! - POST   /api/docs/{doc_id}?path={path}       - Write document
+//! - PATCH  /api/docs/{doc_id}/items?path={path} - Append item
+//! - POST   /api/docs/{doc_id}/backup?path={path} - Create backup
+//! - HEAD   /api/docs/{doc_id}?path={path}       - Check writability
+//!
+//! `directory` and `path` go through `ProjectScopes` first: paths outside
+//! the data directory and enabled projects get 403, traversal and non-`.md`
+//! write targets 400. Handlers then use the resolved path.
+
+use std::collections::HashMap;
+
+use axum::body::Bytes;
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::Json;
+use serde_json::{json, Value};
+
+use crate::models::{DocMeta, ItemDraft, RequestLogDoc};
+use crate::path_guard::Access;
+use crate::ports::SystemClock;
+use crate::server::{guarded_param, parse_body, ApiError, ApiResult, ApiState};
+
+type QueryMap = Query<HashMap<String, String>>;
+
+/// Rewords a store "not found" as `Document not found: {path}`.
+fn doc_not_found(path: &str) -> impl FnOnce(crate::error::Error) -> ApiError + '_ {
+    move |error| match ApiError::from(error) {
+        api if api.status == StatusCode::NOT_FOUND => {
+            ApiError::not_found(format!("Document not found: {path}"))
+        }
+        api => api,
+    }
+}
+
+/// GET /api/docs?directory={path}
+pub async fn list(
+    State(state): State<ApiState>,
+    Query(query): QueryMap,
+) -> ApiResult<Json<Vec<DocMeta>>> {
+    let directory = guarded_param(&state, &query, "directory", Access::Directory)?;
+    Ok(Json(state.stores.docs().list(&directory)?))
+}
+
+/// GET /api/docs/{doc_id}?path={path}
+pub async fn read(
+    State(state): State<ApiState>,
+    Query(query): QueryMap,
+) -> ApiResult<Json<RequestLogDoc>> {
+    let path = guarded_param(&state, &query, "path", Access::Read)?;
+    let doc = state
+        .stores
+        .docs()
+        .read(&path)
+        .map_err(doc_not_found(&path))?;
+    Ok(Json(doc))
+}
+
+/// POST /api/docs/{doc_id}?path={path}
+///
+/// The body's `doc_id` must match the URL.
+pub async fn write(
+    State(state): State<ApiState>,
+    Path(doc_id): Path<String>,
+    Query(query): QueryMap,
+    headers: HeaderMap,
+    body: Bytes,
+) -> ApiResult<Json<Value>> {
+    let path = guarded_param(&state, &query, "path", Access::Write)?;
+    let doc: RequestLogDoc = parse_body(&headers, &body)?;
+    if doc.doc_id != doc_id {
+        return Err(ApiError::validation(
+            format!(
+                "doc_id mismatch: URL has '{doc_id}' but body has '{}'",
+                doc.doc_id
+            ),
+            None,
+        ));
+    }
+
+    state.stores.docs().write(&path, &doc)?;
+    Ok(Json(json!({
+        "success": true,
+        "doc_id": doc.doc_id,
+        "path": path,
+    })))
+}
+
+/// PATCH /api/docs/{doc_id}/items?path={path}
+pub async fn append_item(
+    State(state): State<ApiState>,
+    Query(query): QueryMap,
+    headers: HeaderMap,
+    body: Bytes,
+) -> ApiResult<Json<RequestLogDoc>> {
+    let path = guarded_param(&state, &query, "path", Access::Write)?;
+    let item: ItemDraft = parse_body(&headers, &body)?;
+    let doc = state
+        .stores
+        .docs()
+        .append(&path, item, &SystemClock)
+        .map_err(doc_not_found(&path))?;
+    Ok(Json(doc))
+}
+
+/// POST /api/docs/{doc_id}/backup?path={path}
+pub async fn backup(
+    State(state): State<ApiState>,
+    Query(query): QueryMap,
+) -> ApiResult<Json<Value>> {
+    let path = guarded_param(&state, &query, "path", Access::Write)?;
+    let backup_path = state
+        .stores
+        .docs()
+        .backup(&path)
+        .map_err(doc_not_found(&path))?;
+    Ok(Json(json!({ "success": true, "backup_path": backup_path })))
+}
+
+/// HEAD /api/docs/{doc_id}?path={path}
+///
+/// 200 when the path is writable, 403 otherwise (including out of scope).
+pub async fn check_writable(
+    State(state): State<ApiState>,
+    Query(query): QueryMap,
+) -> ApiResult<StatusCode> {
+    let writable = match guarded_param(&state, &query, "path", Access::Read) {
+        Ok(path) => state.stores.docs().is_writable(&path),
+        Err(error) if error.status == StatusCode::BAD_REQUEST => return Err(error),
+        Err(_) => false,
+    };
+    Ok(if writable {
+        StatusCode::OK
+    } else {
+        StatusCode::FORBIDDEN
+    })
+}
@@ -0,0 +1,186 @@
+//! API Errors
+//!
+//! Maps crate errors to the JSON error bodies of
+//! `src/server/middleware/error-handler.ts`:
+//! - 400 `ValidationError` (with optional field `details`)
+//! - 403 `Forbidden`, 404 `NotFound`, 409 `Conflict`
+//! - 500 `InternalServerError`
+
+use std::io::ErrorKind;
+
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::error::Error;
+
+/// Result alias for route handlers.
+pub type ApiResult<T> = std::result::Result<T, ApiError>;
+
+/// Error response body (`{ error, message, details? }`).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ApiError {
+    #[serde(skip)]
+    pub status: StatusCode,
+    pub error: &'static str,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<Value>,
+}
+
+impl ApiError {
+    /// 400 with `details` naming the offending fields.
+    pub fn validation(message: impl Into<String>, details: Option<Value>) -> Self {
+        ApiError {
+            status: StatusCode::BAD_REQUEST,
+            error: "ValidationError",
+            message: message.into(),
+            details,
+        }
+    }
+
+    /// 400 for a required query parameter that is missing or blank.
+    pub fn missing_param(name: &str) -> Self {
+        Self::validation(
+            "Missing required query parameter",
+            Some(json!({ "fields": { name: "Required query parameter" } })),
+        )
+    }
+
+    /// 404 for a document, project or option that does not exist.
+    pub fn not_found(message: impl Into<String>) -> Self {
+        ApiError {
+            status: StatusCode::NOT_FOUND,
+            error: "NotFound",
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    /// 403 for a request the server refuses to handle.
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::with_status(StatusCode::FORBIDDEN, "Forbidden", message.into())
+    }
+
+    fn with_status(status: StatusCode, error: &'static str, message: String) -> Self {
+        ApiError {
+            status,
+            error,
+            message,
+            details: None,
+        }
+    }
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        let message = error.to_string();
+        match &error {
+            Error::NotFound(_) => ApiError::not_found(message),
+            // The stores report duplicates as validation errors
+            Error::Validation(text) if text.contains("already exists") => {
+                ApiError::with_status(StatusCode::CONFLICT, "Conflict", message)
+            }
+            Error::Validation(_) | Error::Id(_) | Error::InvalidPath { .. } => {
+                ApiError::validation(message, None)
+            }
+            Error::OutOfScope { .. } => {
+                ApiError::with_status(StatusCode::FORBIDDEN, "Forbidden", message)
+            }
+            Error::Locked { .. } => {
+                ApiError::with_status(StatusCode::CONFLICT, "Conflict", message)
+            }
+            Error::Io { source, .. } => match source.kind() {
+                ErrorKind::NotFound => ApiError::not_found(message),
+                ErrorKind::PermissionDenied => {
+                    ApiError::with_status(StatusCode::FORBIDDEN, "Forbidden", message)
+                }
+                ErrorKind::AlreadyExists => {
+                    ApiError::with_status(StatusCode::CONFLICT, "Conflict", message)
+                }
+                _ => ApiError::with_status(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "InternalServerError",
+                    message,
+                ),
+            },
+            _ => ApiError::with_status(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalServerError",
+                message,
+            ),
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        if self.status.is_server_error() {
+            log::error!("API request failed: {}", self.message);
+        }
+        (self.status, Json(&self)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn maps_store_errors_to_status_codes() {
+        let cases = [
+            (Error::NotFound("Project not found: x".into()), 404),
+            (
+                Error::Validation("Project with ID \"x\" already exists".into()),
+                409,
+            ),
+            (Error::Validation("Invalid project ID format".into()), 400),
+            (
+                Error::io("Failed to read document /a.md")(io::Error::from(ErrorKind::NotFound)),
+                404,
+            ),
+            (
+                Error::io("Failed to write /a.md")(io::Error::from(ErrorKind::PermissionDenied)),
+                403,
+            ),
+            (
+                Error::Locked {
+                    path: "/a.md".into(),
+                },
+                409,
+            ),
+            (
+                Error::OutOfScope {
+                    path: "/etc/passwd".into(),
+                },
+                403,
+            ),
+            (
+                Error::InvalidPath {
+                    path: "/a/../b.md".into(),
+                    reason: "parent directory segments are not allowed".into(),
+                },
+                400,
+            ),
+        ];
+        for (error, status) in cases {
+            let message = error.to_string();
+            let api = ApiError::from(error);
+            assert_eq!(api.status.as_u16(), status, "{message}");
+            assert_eq!(api.message, message);
+        }
+
+        let body = serde_json::to_value(ApiError::missing_param("path")).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "error": "ValidationError",
+                "message": "Missing required query parameter",
+                "details": { "fields": { "path": "Required query parameter" } },
+            })
+        );
+    }
+}
@@ -0,0 +1,72 @@
+//! Field Catalog Routes
+//!
+//! `/api/fields` handlers (see `src/server/routes/fields.ts`):
+//! - GET    /api/fields/global                          - Global options
+//! - GET    /api/fields/project/{id}                    - Effective options for a project
+//! - GET    /api/fields/by-field/{field}?project_id={id} - Options for one field
+//! - POST   /api/fields                                 - Add option (201)
+//! - DELETE /api/fields/{id}                            - Remove option (204)
+
+use std::collections::HashMap;
+
+use axum::body::Bytes;
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::Json;
+use serde_json::json;
+
+use crate::models::{FieldName, FieldOption, NewFieldOption};
+use crate::server::{parse_body, ApiError, ApiResult, ApiState};
+
+/// GET /api/fields/global
+pub async fn get_global(State(state): State<ApiState>) -> ApiResult<Json<Vec<FieldOption>>> {
+    Ok(Json(state.stores.fields().get_global()?))
+}
+
+/// GET /api/fields/project/{id}
+pub async fn get_for_project(
+    State(state): State<ApiState>,
+    Path(project_id): Path<String>,
+) -> ApiResult<Json<Vec<FieldOption>>> {
+    Ok(Json(state.stores.fields().get_for_project(&project_id)?))
+}
+
+/// GET /api/fields/by-field/{field}?project_id={id}
+pub async fn get_by_field(
+    State(state): State<ApiState>,
+    Path(field): Path<String>,
+    Query(query): Query<HashMap<String, String>>,
+) -> ApiResult<Json<Vec<FieldOption>>> {
+    let field = FieldName::parse(&field).ok_or_else(|| {
+        let names: Vec<&str> = FieldName::ALL.iter().map(FieldName::as_str).collect();
+        ApiError::validation(
+            "Validation failed",
+            Some(json!({ "fields": { "field": format!("Must be one of: {}", names.join(", ")) } })),
+        )
+    })?;
+    let project_id = query
+        .get("project_id")
+        .map(String::as_str)
+        .filter(|id| !id.is_empty());
+    Ok(Json(state.stores.fields().get_by_field(field, project_id)?))
+}
+
+/// POST /api/fields
+pub async fn add_option(
+    State(state): State<ApiState>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> ApiResult<(StatusCode, Json<FieldOption>)> {
+    let option: NewFieldOption = parse_body(&headers, &body)?;
+    let added = state.stores.fields().add_option(option)?;
+    Ok((StatusCode::CREATED, Json(added)))
+}
+
+/// DELETE /api/fields/{id}
+pub async fn remove_option(
+    State(state): State<ApiState>,
+    Path(id): Path<String>,
+) -> ApiResult<StatusCode> {
+    state.stores.fields().remove_option(&id)?;
+    Ok(StatusCode::NO_CONTENT)
+}
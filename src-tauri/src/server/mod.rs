@@ -0,0 +1,495 @@
+//! Local API Server
+//!
+//! Embedded HTTP server serving the REST API of `src/server` from inside the
+//! desktop app, so tools on the same machine can post items without running
+//! the Bun server or Docker:
+//! - GET /health: Status and uptime (never requires a token)
+//! - /api/docs, /api/projects, /api/fields: Same routes, bodies and status codes
+//! - Bearer-token auth on `/api` routes (see `auth`)
+//! - Requests whose `Host` is not localhost are rejected (DNS rebinding)
+//! - Document paths confined to the data directory and enabled project
+//!   folders, as for the desktop commands (see `scope`)
+//!
+//! Opt-in: starts only when `local_api_port` is set in `config.json` (or
+//! `MEATYCAPTURE_LOCAL_API_PORT`) and a token is configured. Binds to
+//! 127.0.0.1 only.
+
+mod auth;
+mod docs;
+mod error;
+mod fields;
+mod projects;
+
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, SocketAddr, TcpListener};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Instant;
+
+use axum::extract::State;
+use axum::http::{header, HeaderMap, Method, Uri};
+use axum::routing::{delete, get, patch, post};
+use axum::{middleware, Json, Router};
+use chrono::{SecondsFormat, Utc};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+pub use error::{ApiError, ApiResult};
+
+use crate::backups::BackupHistory;
+use crate::config_store::{config_dir, LocalFieldCatalogStore, LocalProjectStore};
+use crate::doc_store::FsDocStore;
+use crate::error::{Error, Result};
+use crate::models::AppConfig;
+use crate::path_guard::Access;
+use crate::ports::{DocStore, FieldCatalogStore, ProjectStore};
+use crate::scope::ProjectScopes;
+
+/// Stores the API reads and writes through.
+///
+/// The desktop app implements this for its `AppHandle` so requests share
+/// the managed stores (and their locks, index and search hooks) with IPC.
+pub trait Stores: Send + Sync + 'static {
+    fn docs(&self) -> &dyn DocStore;
+    fn projects(&self) -> &dyn ProjectStore;
+    fn fields(&self) -> &dyn FieldCatalogStore;
+    fn scopes(&self) -> &ProjectScopes;
+
+    /// Called after a project is created, updated or deleted through the API
+    /// so the allowed roots follow the enabled projects.
+    fn projects_changed(&self) {
+        if let Err(error) = self.scopes().sync(self.projects()) {
+            log::error!("Failed to sync project scopes: {error}");
+        }
+    }
+}
+
+/// Standalone stores over a config directory (`None` = default).
+#[derive(Debug)]
+pub struct LocalStores {
+    pub docs: FsDocStore,
+    pub projects: LocalProjectStore,
+    pub fields: LocalFieldCatalogStore,
+    pub scopes: ProjectScopes,
+}
+
+impl LocalStores {
+    pub fn new(config_dir: Option<PathBuf>) -> Self {
+        let data_dir = config_dir
+            .clone()
+            .unwrap_or_else(crate::config_store::config_dir);
+        let history = BackupHistory::open(&data_dir);
+        let docs = FsDocStore::new().with_history(Arc::new(history));
+        #[cfg(feature = "git")]
+        let docs = docs.with_git(Arc::new(crate::git::GitAutoCommit::new(
+            LocalProjectStore::new(config_dir.clone()),
+        )));
+        let stores = LocalStores {
+            docs,
+            projects: LocalProjectStore::new(config_dir.clone()),
+            fields: LocalFieldCatalogStore::new(config_dir),
+            scopes: ProjectScopes::new(data_dir),
+        };
+        stores.projects_changed();
+        stores
+    }
+}
+
+impl Stores for LocalStores {
+    fn docs(&self) -> &dyn DocStore {
+        &self.docs
+    }
+
+    fn projects(&self) -> &dyn ProjectStore {
+        &self.projects
+    }
+
+    fn fields(&self) -> &dyn FieldCatalogStore {
+        &self.fields
+    }
+
+    fn scopes(&self) -> &ProjectScopes {
+        &self.scopes
+    }
+}
+
+/// Resolved server settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerConfig {
+    /// Localhost port to listen on
+    pub port: u16,
+    /// Required bearer token
+    pub token: String,
+}
+
+impl ServerConfig {
+    /// Resolves settings from `config.json`, or `None` when the server is off.
+    ///
+    /// A port without a token leaves the server off: any local process or
+    /// web page could otherwise read and write documents.
+    ///
+    /// Environment overrides:
+    /// - `MEATYCAPTURE_LOCAL_API_PORT`: Port (`0` disables)
+    /// - `MEATYCAPTURE_AUTH_TOKEN`: Bearer token, as for the Bun server
+    pub fn resolve(config: &AppConfig) -> Option<Self> {
+        let env = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+        let port = match env("MEATYCAPTURE_LOCAL_API_PORT") {
+            Some(port) => port.trim().parse().ok(),
+            None => config.local_api_port,
+        };
+        let port = port.filter(|port| *port != 0)?;
+        let token = env("MEATYCAPTURE_AUTH_TOKEN")
+            .or_else(|| config.local_api_token.clone())
+            .filter(|token| !token.trim().is_empty());
+        let Some(token) = token else {
+            log::warn!("Local API server disabled: set local_api_token or MEATYCAPTURE_AUTH_TOKEN");
+            return None;
+        };
+
+        Some(ServerConfig { port, token })
+    }
+}
+
+/// Shared router state.
+#[derive(Clone)]
+pub struct ApiState {
+    stores: Arc<dyn Stores>,
+    token: Arc<str>,
+    started: Instant,
+}
+
+impl ApiState {
+    pub fn new(stores: Arc<dyn Stores>, token: String) -> Self {
+        ApiState {
+            stores,
+            token: Arc::from(token),
+            started: Instant::now(),
+        }
+    }
+}
+
+/// Builds the router: `/health` plus the authenticated `/api` routes, all
+/// behind the localhost `Host` check.
+pub fn router(state: ApiState) -> Router {
+    let api = Router::new()
+        .route("/docs", get(docs::list))
+        .route(
+            "/docs/{doc_id}",
+            get(docs::read).post(docs::write).head(docs::check_writable),
+        )
+        .route("/docs/{doc_id}/items", patch(docs::append_item))
+        .route("/docs/{doc_id}/backup", post(docs::backup))
+        .route("/projects", get(projects::list).post(projects::create))
+        .route(
+            "/projects/{id}",
+            get(projects::get)
+                .patch(projects::update)
+                .delete(projects::delete),
+        )
+        .route("/fields", post(fields::add_option))
+        .route("/fields/global", get(fields::get_global))
+        .route("/fields/project/{id}", get(fields::get_for_project))
+        .route("/fields/by-field/{field}", get(fields::get_by_field))
+        .route("/fields/{id}", delete(fields::remove_option))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth::require_token,
+        ));
+
+    Router::new()
+        .route("/health", get(health))
+        .nest("/api", api)
+        .fallback(not_found)
+        .layer(middleware::from_fn(auth::require_local_host))
+        .with_state(state)
+}
+
+/// Binds `127.0.0.1:{port}` and serves the API on a background thread.
+///
+/// Binding happens before returning so a port conflict is reported to the
+/// caller instead of being lost on the server thread.
+pub fn start(config: ServerConfig, stores: Arc<dyn Stores>) -> Result<SocketAddr> {
+    let address = SocketAddr::from((Ipv4Addr::LOCALHOST, config.port));
+    let context = format!("Failed to start local API server on {address}");
+    let listener = TcpListener::bind(address).map_err(Error::io(context.clone()))?;
+    listener
+        .set_nonblocking(true)
+        .map_err(Error::io(context.clone()))?;
+    let address = listener.local_addr().map_err(Error::io(context))?;
+
+    let app = router(ApiState::new(stores, config.token));
+
+    std::thread::Builder::new()
+        .name("local-api".to_string())
+        .spawn(move || {
+            let served = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .and_then(|runtime| {
+                    runtime.block_on(async {
+                        let listener = tokio::net::TcpListener::from_std(listener)?;
+                        axum::serve(listener, app).await
+                    })
+                });
+            if let Err(error) = served {
+                log::error!("Local API server stopped: {error}");
+            }
+        })
+        .map_err(Error::io("Failed to spawn local API server thread"))?;
+
+    log::info!("Local API server listening on http://{address}");
+    Ok(address)
+}
+
+/// GET /health
+async fn health(State(state): State<ApiState>) -> Json<Value> {
+    Json(json!({
+        "status": "ok",
+        "timestamp": Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+        "environment": "desktop",
+        "uptime": state.started.elapsed().as_millis() as u64,
+        "dataDir": config_dir().display().to_string(),
+    }))
+}
+
+/// 404 for unmatched routes.
+async fn not_found(method: Method, uri: Uri) -> (axum::http::StatusCode, Json<Value>) {
+    (
+        axum::http::StatusCode::NOT_FOUND,
+        Json(json!({
+            "error": "Not Found",
+            "path": uri.path(),
+            "method": method.as_str(),
+        })),
+    )
+}
+
+// ============================================================================
+// Request Helpers
+// ============================================================================
+
+/// Returns a required, non-blank query parameter.
+fn required_param<'q>(query: &'q HashMap<String, String>, name: &str) -> ApiResult<&'q str> {
+    query
+        .get(name)
+        .map(String::as_str)
+        .filter(|value| !value.trim().is_empty())
+        .ok_or_else(|| ApiError::missing_param(name))
+}
+
+/// Returns a required path parameter resolved through the project scopes,
+/// so handlers only ever touch the checked path.
+fn guarded_param(
+    state: &ApiState,
+    query: &HashMap<String, String>,
+    name: &str,
+    access: Access,
+) -> ApiResult<String> {
+    let path = required_param(query, name)?;
+    Ok(state.stores.scopes().check_str(path, access)?)
+}
+
+/// True if a `Content-Type` value is `application/json`, optionally with
+/// parameters such as `charset`.
+fn is_json_content_type(value: &str) -> bool {
+    let essence = value.split(';').next().unwrap_or_default();
+    essence.trim().eq_ignore_ascii_case("application/json")
+}
+
+/// Parses a JSON request body, as `parseJsonBody` does.
+///
+/// The media type must be exactly `application/json`: form and `text/plain`
+/// bodies (which browsers send cross-origin without a preflight) are refused.
+fn parse_body<T: DeserializeOwned>(headers: &HeaderMap, body: &[u8]) -> ApiResult<T> {
+    let is_json = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(is_json_content_type);
+    if !is_json {
+        return Err(ApiError::validation(
+            "Invalid request",
+            Some(json!({ "message": "Content-Type must be application/json" })),
+        ));
+    }
+
+    let value: Value = serde_json::from_slice(body).map_err(|error| {
+        ApiError::validation(
+            "Invalid JSON",
+            Some(json!({ "message": error.to_string() })),
+        )
+    })?;
+    serde_json::from_value(value).map_err(|error| {
+        ApiError::validation(
+            "Validation failed",
+            Some(json!({ "message": error.to_string() })),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+    use tempfile::TempDir;
+
+    /// Sends a raw HTTP/1.1 request and returns (status, body).
+    fn send(address: SocketAddr, request: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let status = response[9..12].parse().unwrap();
+        let body = response
+            .split_once("\r\n\r\n")
+            .map(|(_, body)| body.to_string())
+            .unwrap_or_default();
+        (status, body)
+    }
+
+    #[test]
+    fn json_content_type_must_match_exactly() {
+        assert!(is_json_content_type("application/json"));
+        assert!(is_json_content_type("Application/JSON; charset=utf-8"));
+        assert!(!is_json_content_type("application/json-patch+json"));
+        assert!(!is_json_content_type("text/plain; x=application/json"));
+        assert!(!is_json_content_type("application/x-www-form-urlencoded"));
+    }
+
+    #[test]
+    fn port_without_token_leaves_server_off() {
+        let overridden = ["MEATYCAPTURE_LOCAL_API_PORT", "MEATYCAPTURE_AUTH_TOKEN"];
+        if overridden
+            .iter()
+            .any(|name| std::env::var_os(name).is_some())
+        {
+            return;
+        }
+        let config: AppConfig = serde_json::from_value(json!({
+            "version": "1.0.0",
+            "local_api_port": 3737,
+            "created_at": "2025-12-03T12:00:00Z",
+            "updated_at": "2025-12-03T12:00:00Z",
+        }))
+        .unwrap();
+        assert_eq!(ServerConfig::resolve(&config), None);
+
+        let config = AppConfig {
+            local_api_token: Some("s3cret".to_string()),
+            ..config
+        };
+        assert_eq!(
+            ServerConfig::resolve(&config).map(|settings| settings.port),
+            Some(3737)
+        );
+    }
+
+    fn request(method: &str, path: &str, token: Option<&str>, body: Option<&str>) -> String {
+        let mut request =
+            format!("{method} {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n");
+        if let Some(token) = token {
+            request.push_str(&format!("Authorization: Bearer {token}\r\n"));
+        }
+        if let Some(body) = body {
+            request.push_str(&format!(
+                "Content-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            ));
+        } else {
+            request.push_str("\r\n");
+        }
+        request
+    }
+
+    #[test]
+    fn serves_projects_behind_bearer_auth() {
+        let temp = TempDir::new().unwrap();
+        let stores = Arc::new(LocalStores::new(Some(temp.path().to_path_buf())));
+        let config = ServerConfig {
+            port: 0,
+            token: "s3cret".to_string(),
+        };
+        let address = start(config, stores).unwrap();
+
+        let (status, body) = send(address, &request("GET", "/health", None, None));
+        assert_eq!(status, 200);
+        assert!(body.contains("\"status\":\"ok\""));
+
+        let (status, body) = send(address, &request("GET", "/api/projects", None, None));
+        assert_eq!(status, 401);
+        assert!(body.contains("Missing Authorization header"));
+
+        let docs = temp.path().join("docs");
+        std::fs::create_dir_all(&docs).unwrap();
+        let root = format!(
+            r#"{{"name":"My App","default_path":"{}","enabled":true}}"#,
+            temp.path().display()
+        );
+        let (status, body) = send(
+            address,
+            &request("POST", "/api/projects", Some("s3cret"), Some(&root)),
+        );
+        assert_eq!(status, 400, "{body}");
+
+        let project = format!(
+            r#"{{"name":"My App","default_path":"{}","enabled":true}}"#,
+            docs.display()
+        );
+        let (status, body) = send(
+            address,
+            &request("POST", "/api/projects", Some("s3cret"), Some(&project)),
+        );
+        assert_eq!(status, 201, "{body}");
+        assert!(body.contains("\"id\":\"my-app\""));
+
+        let (status, _) = send(
+            address,
+            &request("POST", "/api/projects", Some("s3cret"), Some(&project)),
+        );
+        assert_eq!(status, 409);
+
+        let (status, _) = send(
+            address,
+            &request("GET", "/health", None, None).replace("localhost", "evil.example"),
+        );
+        assert_eq!(status, 403);
+
+        let text_body = request("POST", "/api/projects", Some("s3cret"), Some(&project))
+            .replace("application/json", "text/plain; x=application/json");
+        let (status, body) = send(address, &text_body);
+        assert_eq!(status, 400);
+        assert!(body.contains("Content-Type must be application/json"));
+
+        let (status, body) = send(address, &request("GET", "/api/docs", Some("s3cret"), None));
+        assert_eq!(status, 400);
+        assert!(body.contains("\"directory\":\"Required query parameter\""));
+
+        let (status, _) = send(
+            address,
+            &request(
+                "GET",
+                &format!("/api/docs?directory={}", docs.display()),
+                Some("s3cret"),
+                None,
+            ),
+        );
+        assert_eq!(status, 200);
+
+        let (status, body) = send(
+            address,
+            &request("GET", "/api/docs/x?path=/etc/passwd", Some("s3cret"), None),
+        );
+        assert_eq!(status, 403);
+        assert!(body.contains("\"error\":\"Forbidden\""));
+
+        let (status, body) = send(
+            address,
+            &request("GET", "/api/projects/nope", Some("s3cret"), None),
+        );
+        assert_eq!(status, 404);
+        assert!(body.contains("Project not found: nope"));
+
+        let (status, _) = send(address, &request("GET", "/nowhere", None, None));
+        assert_eq!(status, 404);
+    }
+}
@@ -0,0 +1,88 @@
+//! Project Routes
+//!
+//! `/api/projects` handlers (see `src/server/routes/projects.ts`):
+//! - GET    /api/projects      - List all projects
+//! - GET    /api/projects/{id} - Get project
+//! - POST   /api/projects      - Create project (201)
+//! - PATCH  /api/projects/{id} - Update project
+//! - DELETE /api/projects/{id} - Delete project (204)
+//!
+//! Mutations re-sync the project scopes that confine `/api/docs` paths, so
+//! a `default_path` that is a filesystem root or covers the home or data
+//! directory is rejected with 400.
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::Json;
+
+use crate::models::{NewProject, Project, ProjectUpdate};
+use crate::server::{parse_body, ApiError, ApiResult, ApiState};
+
+/// GET /api/projects
+pub async fn list(State(state): State<ApiState>) -> ApiResult<Json<Vec<Project>>> {
+    Ok(Json(state.stores.projects().list()?))
+}
+
+/// GET /api/projects/{id}
+pub async fn get(
+    State(state): State<ApiState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<Project>> {
+    state
+        .stores
+        .projects()
+        .get(&id)?
+        .map(Json)
+        .ok_or_else(|| ApiError::not_found(format!("Project not found: {id}")))
+}
+
+/// POST /api/projects
+pub async fn create(
+    State(state): State<ApiState>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> ApiResult<(StatusCode, Json<Project>)> {
+    let project: NewProject = parse_body(&headers, &body)?;
+    state
+        .stores
+        .scopes()
+        .check_project_root(&project.default_path)?;
+    let created = state.stores.projects().create(project)?;
+    state.stores.projects_changed();
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+/// PATCH /api/projects/{id}
+///
+/// At least one field must be present.
+pub async fn update(
+    State(state): State<ApiState>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> ApiResult<Json<Project>> {
+    let updates: ProjectUpdate = parse_body(&headers, &body)?;
+    if updates == ProjectUpdate::default() {
+        return Err(ApiError::validation(
+            "Update request must include at least one field to update",
+            None,
+        ));
+    }
+    if let Some(path) = &updates.default_path {
+        state.stores.scopes().check_project_root(path)?;
+    }
+    let updated = state.stores.projects().update(&id, updates)?;
+    state.stores.projects_changed();
+    Ok(Json(updated))
+}
+
+/// DELETE /api/projects/{id}
+pub async fn delete(
+    State(state): State<ApiState>,
+    Path(id): Path<String>,
+) -> ApiResult<StatusCode> {
+    state.stores.projects().delete(&id)?;
+    state.stores.projects_changed();
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,82 @@
+//! Global Shortcuts
+//!
+//! Quick-capture hotkey via the Tauri global-shortcut plugin:
+//! - Binding comes from `capture_shortcut` in `config.json`
+//!   (default `CommandOrControl+Shift+Space`, empty disables it)
+//! - Pressing it opens or focuses the quick-capture window
+//! - Rebinding releases the old combination and restores it if the new one
+//!   cannot be registered
+
+use tauri::plugin::TauriPlugin;
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+use crate::capture;
+use crate::error::{Error, Result};
+
+/// Global-shortcut plugin with the quick-capture handler installed.
+///
+/// Only the capture shortcut is ever registered, so every press opens it.
+pub fn plugin<R: Runtime>() -> TauriPlugin<R> {
+    tauri_plugin_global_shortcut::Builder::new()
+        .with_handler(|app, _shortcut, event| {
+            if event.state() == ShortcutState::Pressed {
+                capture::show(app);
+            }
+        })
+        .build()
+}
+
+/// Parses a binding such as `CommandOrControl+Shift+Space`.
+pub fn parse(binding: &str) -> Result<Shortcut> {
+    binding
+        .parse::<Shortcut>()
+        .map_err(|error| Error::InvalidShortcut {
+            shortcut: binding.to_string(),
+            reason: error.to_string(),
+        })
+}
+
+/// Registers `binding`; an empty binding is a no-op.
+pub fn register<R: Runtime>(app: &AppHandle<R>, binding: &str) -> Result<()> {
+    if binding.is_empty() {
+        return Ok(());
+    }
+
+    let shortcut = parse(binding)?;
+    let shortcuts = app.global_shortcut();
+    if shortcuts.is_registered(shortcut) {
+        return Ok(());
+    }
+    shortcuts
+        .register(shortcut)
+        .map_err(|error| Error::ShortcutUnavailable {
+            shortcut: binding.to_string(),
+            reason: error.to_string(),
+        })
+}
+
+/// Replaces `current` with `binding`, keeping `current` if that fails.
+pub fn rebind<R: Runtime>(app: &AppHandle<R>, current: &str, binding: &str) -> Result<()> {
+    let binding = binding.trim();
+    if !binding.is_empty() {
+        parse(binding)?;
+    }
+
+    // Release first: both may spell the same combination differently
+    unregister(app, current);
+    register(app, binding).inspect_err(|_| {
+        if let Err(error) = register(app, current) {
+            log::warn!("Failed to restore shortcut {current}: {error}");
+        }
+    })
+}
+
+fn unregister<R: Runtime>(app: &AppHandle<R>, binding: &str) {
+    let Ok(shortcut) = parse(binding) else {
+        return;
+    };
+    if let Err(error) = app.global_shortcut().unregister(shortcut) {
+        log::warn!("Failed to unregister shortcut {binding}: {error}");
+    }
+}
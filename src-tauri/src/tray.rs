@@ -0,0 +1,146 @@
+//! System Tray
+//!
+//! Tray icon that keeps MeatyCapture one click away:
+//! - Quick capture: Opens the capture wizard
+//! - Open viewer: Opens the document catalog in its own window
+//! - Manage projects: Opens the admin window
+//! - Recent projects: Capture straight into a recently updated project
+//! - Quit: Exits the app (the only exit when close-to-tray is enabled)
+//!
+//! Close-to-tray is read from `config.json` (`close_to_tray`) on every close
+//! request, so toggling it takes effect immediately.
+
+use tauri::menu::{Menu, MenuBuilder, MenuItemBuilder, SubmenuBuilder};
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, CloseRequestApi, Manager, Runtime, Window};
+
+use crate::config_store::{LocalConfigStore, LocalProjectStore};
+use crate::models::Project;
+use crate::navigation::{navigate, Navigate, View, MAIN_WINDOW};
+use crate::ports::{ConfigStore, ProjectStore};
+use crate::windows::{self, AppWindow};
+
+/// Tray icon identifier.
+pub const TRAY_ID: &str = "main";
+
+/// Number of projects listed under "Recent projects".
+const RECENT_PROJECTS: usize = 5;
+
+const QUICK_CAPTURE: &str = "quick-capture";
+const OPEN_VIEWER: &str = "open-viewer";
+const OPEN_ADMIN: &str = "open-admin";
+const QUIT: &str = "quit";
+const PROJECT_PREFIX: &str = "project:";
+
+/// Creates the tray icon and its menu.
+pub fn create<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<()> {
+    let mut builder = TrayIconBuilder::with_id(TRAY_ID)
+        .tooltip("MeatyCapture")
+        .menu(&build_menu(app)?)
+        .show_menu_on_left_click(true)
+        .on_menu_event(|app, event| handle_menu_event(app, event.id().as_ref()));
+
+    if let Some(icon) = app.default_window_icon() {
+        builder = builder.icon(icon.clone());
+    }
+
+    builder.build(app)?;
+    Ok(())
+}
+
+/// Rebuilds the menu so "Recent projects" reflects the project store.
+pub fn refresh<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<()> {
+    if let Some(tray) = app.tray_by_id(TRAY_ID) {
+        tray.set_menu(Some(build_menu(app)?))?;
+    }
+    Ok(())
+}
+
+/// Hides the main window instead of closing it when close-to-tray is on.
+pub fn handle_close_requested<R: Runtime>(window: &Window<R>, api: &CloseRequestApi) {
+    // Never hide the window if there is no tray icon to bring it back
+    if window.label() != MAIN_WINDOW || window.app_handle().tray_by_id(TRAY_ID).is_none() {
+        return;
+    }
+
+    let close_to_tray = window
+        .app_handle()
+        .state::<LocalConfigStore>()
+        .get()
+        .map(|config| config.close_to_tray())
+        .unwrap_or_else(|error| {
+            log::warn!("Failed to read config, closing normally: {error}");
+            false
+        });
+
+    if close_to_tray {
+        api.prevent_close();
+        if let Err(error) = window.hide() {
+            log::warn!("Failed to hide main window: {error}");
+        }
+    }
+}
+
+fn build_menu<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<Menu<R>> {
+    let projects = recent_projects(&*app.state::<LocalProjectStore>());
+
+    let mut recent = SubmenuBuilder::new(app, "Recent projects");
+    if projects.is_empty() {
+        recent = recent.item(
+            &MenuItemBuilder::with_id("no-projects", "No projects")
+                .enabled(false)
+                .build(app)?,
+        );
+    }
+    for project in projects {
+        recent = recent.text(format!("{PROJECT_PREFIX}{}", project.id), project.name);
+    }
+
+    MenuBuilder::new(app)
+        .text(QUICK_CAPTURE, "Quick capture")
+        .text(OPEN_VIEWER, "Open viewer")
+        .text(OPEN_ADMIN, "Manage projects")
+        .separator()
+        .item(&recent.build()?)
+        .separator()
+        .text(QUIT, "Quit")
+        .build()
+}
+
+/// Enabled projects, most recently updated first.
+fn recent_projects(store: &dyn ProjectStore) -> Vec<Project> {
+    let mut projects: Vec<_> = store
+        .list()
+        .unwrap_or_else(|error| {
+            log::warn!("Failed to load projects for tray menu: {error}");
+            Vec::new()
+        })
+        .into_iter()
+        .filter(|project| project.enabled)
+        .collect();
+    projects.sort_by_key(|project| std::cmp::Reverse(project.updated_at));
+    projects.truncate(RECENT_PROJECTS);
+    projects
+}
+
+fn handle_menu_event<R: Runtime>(app: &AppHandle<R>, id: &str) {
+    match id {
+        QUICK_CAPTURE => navigate(app, Navigate::to(View::Wizard)),
+        OPEN_VIEWER => windows::open(app, AppWindow::Viewer),
+        OPEN_ADMIN => windows::open(app, AppWindow::Admin),
+        QUIT => app.exit(0),
+        _ => {
+            if let Some(project_id) = id.strip_prefix(PROJECT_PREFIX) {
+                navigate(
+                    app,
+                    Navigate {
+                        view: View::Wizard,
+                        project_id: Some(project_id.to_string()),
+                        doc_path: None,
+                        draft: None,
+                    },
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,435 @@
+//! Document Watcher
+//!
+//! Pushes on-disk request-log changes to the viewer:
+//! - Watches the `default_path` of every enabled project (non-recursive)
+//! - Debounces bursts (editors and atomic writes emit several raw events)
+//! - Classifies each changed `.md` file as created, updated or deleted
+//! - Re-syncs watched directories whenever `projects.json` changes
+//!
+//! The watcher is transport-agnostic: events go to a sink callback, which
+//! the desktop app forwards as `doc-created` / `doc-updated` / `doc-deleted`
+//! Tauri events carrying the document's `DocMeta`.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, Weak};
+use std::time::Duration;
+
+use notify::{RecommendedWatcher, RecursiveMode};
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult, Debouncer};
+
+use crate::config_store::LocalProjectStore;
+use crate::doc_store::{enabled_project_dirs, FsDocStore};
+use crate::error::{Error, Result};
+use crate::models::DocMeta;
+use crate::ports::DocStore;
+
+/// Default quiet period before a burst of changes is reported.
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// A request-log change detected on disk.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DocEvent {
+    /// A new request-log appeared in a watched directory
+    Created(DocMeta),
+    /// An existing request-log was modified
+    Updated(DocMeta),
+    /// A request-log was removed; carries its last known metadata
+    Deleted(DocMeta),
+}
+
+impl DocEvent {
+    /// Tauri event name for this change.
+    pub fn name(&self) -> &'static str {
+        match self {
+            DocEvent::Created(_) => "doc-created",
+            DocEvent::Updated(_) => "doc-updated",
+            DocEvent::Deleted(_) => "doc-deleted",
+        }
+    }
+
+    /// Metadata of the affected document.
+    pub fn meta(&self) -> &DocMeta {
+        match self {
+            DocEvent::Created(meta) | DocEvent::Updated(meta) | DocEvent::Deleted(meta) => meta,
+        }
+    }
+}
+
+type Sink = Box<dyn Fn(DocEvent) + Send + Sync>;
+type Listener = Box<dyn Fn() + Send + Sync>;
+
+#[derive(Default)]
+struct WatchState {
+    /// Project directories currently registered with the OS watcher
+    dirs: HashSet<PathBuf>,
+    /// Last known metadata per document, used to classify changes
+    known: HashMap<PathBuf, DocMeta>,
+}
+
+struct Shared {
+    projects: LocalProjectStore,
+    docs: FsDocStore,
+    sink: Sink,
+    projects_listener: Mutex<Option<Listener>>,
+    state: Mutex<WatchState>,
+    debouncer: OnceLock<Mutex<Debouncer<RecommendedWatcher>>>,
+}
+
+/// Watches enabled project directories and reports document changes.
+///
+/// Dropping the watcher stops the background threads.
+pub struct DocWatcher {
+    shared: Arc<Shared>,
+}
+
+impl DocWatcher {
+    /// Starts watching the config directory and all enabled projects.
+    ///
+    /// `sink` is called from a background thread for every change.
+    pub fn start<F>(
+        projects: LocalProjectStore,
+        docs: FsDocStore,
+        debounce: Duration,
+        sink: F,
+    ) -> Result<DocWatcher>
+    where
+        F: Fn(DocEvent) + Send + Sync + 'static,
+    {
+        let shared = Arc::new(Shared {
+            projects,
+            docs,
+            sink: Box::new(sink),
+            projects_listener: Mutex::new(None),
+            state: Mutex::new(WatchState::default()),
+            debouncer: OnceLock::new(),
+        });
+
+        // The handler holds a weak reference so dropping DocWatcher tears
+        // down the debouncer (and its thread) instead of leaking a cycle
+        let weak: Weak<Shared> = Arc::downgrade(&shared);
+        let mut debouncer = new_debouncer(debounce, move |result: DebounceEventResult| {
+            let Some(shared) = weak.upgrade() else {
+                return;
+            };
+            match result {
+                Ok(events) => shared.handle(events.into_iter().map(|event| event.path)),
+                Err(error) => log::warn!("File watcher error: {error}"),
+            }
+        })
+        .map_err(Error::watch("Failed to start file watcher"))?;
+
+        let config_dir = shared
+            .projects
+            .projects_file()
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        std::fs::create_dir_all(&config_dir).map_err(Error::io(format!(
+            "Failed to create directory {}",
+            config_dir.display()
+        )))?;
+        debouncer
+            .watcher()
+            .watch(&config_dir, RecursiveMode::NonRecursive)
+            .map_err(Error::watch(format!(
+                "Failed to watch {}",
+                config_dir.display()
+            )))?;
+
+        let _ = shared.debouncer.set(Mutex::new(debouncer));
+        shared.sync()?;
+        Ok(DocWatcher { shared })
+    }
+
+    /// Reconciles watched directories with the current project list.
+    ///
+    /// Called automatically when `projects.json` changes; exposed for
+    /// callers that want the new set applied without waiting for debounce.
+    pub fn sync(&self) -> Result<()> {
+        self.shared.sync()
+    }
+
+    /// Registers a callback run (on the watcher thread) after `projects.json`
+    /// changes and the watched directories have been re-synced.
+    pub fn on_projects_changed<F>(&self, listener: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        *self
+            .shared
+            .projects_listener
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = Some(Box::new(listener));
+    }
+
+    /// Directories currently being watched, sorted.
+    pub fn watched_dirs(&self) -> Vec<PathBuf> {
+        let mut dirs: Vec<_> = self.shared.state().dirs.iter().cloned().collect();
+        dirs.sort();
+        dirs
+    }
+}
+
+impl Shared {
+    fn state(&self) -> MutexGuard<'_, WatchState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn sync(&self) -> Result<()> {
+        let wanted: HashSet<PathBuf> = enabled_project_dirs(&self.projects)?.into_iter().collect();
+
+        let Some(debouncer) = self.debouncer.get() else {
+            return Ok(());
+        };
+        let mut debouncer = debouncer.lock().unwrap_or_else(|e| e.into_inner());
+        let mut state = self.state();
+
+        let removed: Vec<_> = state.dirs.difference(&wanted).cloned().collect();
+        for dir in removed {
+            if let Err(error) = debouncer.watcher().unwatch(&dir) {
+                log::debug!("Failed to unwatch {}: {error}", dir.display());
+            }
+            state
+                .known
+                .retain(|path, _| path.parent() != Some(dir.as_path()));
+            state.dirs.remove(&dir);
+            log::info!("Stopped watching {}", dir.display());
+        }
+
+        for dir in wanted.difference(&state.dirs.clone()) {
+            // Missing directories are retried on the next sync
+            if !dir.is_dir() {
+                log::debug!("Project directory does not exist yet: {}", dir.display());
+                continue;
+            }
+            if let Err(error) = debouncer.watcher().watch(dir, RecursiveMode::NonRecursive) {
+                log::warn!("Failed to watch {}: {error}", dir.display());
+                continue;
+            }
+
+            let metas = dir
+                .to_str()
+                .map(|dir| self.docs.list(dir))
+                .transpose()?
+                .unwrap_or_default();
+            for meta in metas {
+                state.known.insert(PathBuf::from(&meta.path), meta);
+            }
+            state.dirs.insert(dir.clone());
+            log::info!("Watching {}", dir.display());
+        }
+
+        Ok(())
+    }
+
+    fn handle(&self, paths: impl IntoIterator<Item = PathBuf>) {
+        let mut projects_changed = false;
+        let mut events = Vec::new();
+
+        {
+            let mut state = self.state();
+            for path in paths {
+                if path == self.projects.projects_file() {
+                    projects_changed = true;
+                    continue;
+                }
+                let watched = path.parent().is_some_and(|dir| state.dirs.contains(dir));
+                if !watched || path.extension().is_none_or(|ext| ext != "md") {
+                    continue;
+                }
+                if let Some(event) = classify(&self.docs, &mut state.known, path) {
+                    events.push(event);
+                }
+            }
+        }
+
+        if projects_changed {
+            if let Err(error) = self.sync() {
+                log::warn!("Failed to update watched projects: {error}");
+            }
+            let listener = self
+                .projects_listener
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+            if let Some(listener) = listener.as_ref() {
+                listener();
+            }
+        }
+
+        // Emit outside the state lock so sinks may call back into the watcher
+        for event in events {
+            log::debug!("{} {}", event.name(), event.meta().path);
+            (self.sink)(event);
+        }
+    }
+}
+
+/// Decides what happened to `path` by comparing disk with the last known state.
+fn classify(
+    docs: &FsDocStore,
+    known: &mut HashMap<PathBuf, DocMeta>,
+    path: PathBuf,
+) -> Option<DocEvent> {
+    if !path.exists() {
+        return known.remove(&path).map(DocEvent::Deleted);
+    }
+
+    match docs.read_meta(&path) {
+        Ok(meta) => match known.insert(path, meta.clone()) {
+            Some(previous) if previous == meta => None,
+            Some(_) => Some(DocEvent::Updated(meta)),
+            None => Some(DocEvent::Created(meta)),
+        },
+        // Not a request-log (or mid-edit by a non-atomic editor); keep the
+        // last good metadata so a later valid save is reported as an update
+        Err(error) => {
+            log::debug!("Ignoring change - {error}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    use chrono::{TimeZone, Utc};
+
+    use crate::models::{NewProject, ProjectUpdate, RequestLogDoc, SCHEMA_VERSION};
+    use crate::ports::ProjectStore;
+
+    const WAIT: Duration = Duration::from_secs(5);
+
+    fn doc(doc_id: &str, hour: u32) -> RequestLogDoc {
+        let at = Utc.with_ymd_and_hms(2025, 12, 3, hour, 0, 0).unwrap();
+        RequestLogDoc {
+            schema_version: SCHEMA_VERSION,
+            doc_id: doc_id.to_string(),
+            title: "App Log".to_string(),
+            project_id: "app".to_string(),
+            items: vec![],
+            items_index: vec![],
+            tags: vec![],
+            item_count: 0,
+            created_at: at,
+            updated_at: at,
+            extra_frontmatter: vec![],
+            preamble: String::new(),
+        }
+    }
+
+    fn start(config: &Path) -> (DocWatcher, mpsc::Receiver<DocEvent>) {
+        let (tx, rx) = mpsc::channel();
+        let tx = Mutex::new(tx);
+        let watcher = DocWatcher::start(
+            LocalProjectStore::new(Some(config.to_path_buf())),
+            FsDocStore::new(),
+            Duration::from_millis(50),
+            move |event| {
+                let _ = tx.lock().unwrap().send(event);
+            },
+        )
+        .unwrap();
+        (watcher, rx)
+    }
+
+    fn wait_until(mut condition: impl FnMut() -> bool) {
+        let deadline = std::time::Instant::now() + WAIT;
+        while !condition() {
+            assert!(std::time::Instant::now() < deadline, "timed out waiting");
+            std::thread::sleep(Duration::from_millis(20));
+        }
+    }
+
+    #[test]
+    fn reports_created_updated_and_deleted_documents() {
+        let root = tempfile::tempdir().unwrap();
+        let config = root.path().join("config");
+        let docs_dir = root.path().join("app");
+        std::fs::create_dir_all(&docs_dir).unwrap();
+        LocalProjectStore::new(Some(config.clone()))
+            .create(NewProject {
+                id: None,
+                name: "App".to_string(),
+                default_path: docs_dir.display().to_string(),
+                repo_url: None,
+                enabled: true,
+                auto_commit: None,
+            })
+            .unwrap();
+
+        let (watcher, rx) = start(&config);
+        assert_eq!(watcher.watched_dirs(), vec![docs_dir.clone()]);
+
+        let path = docs_dir.join("REQ-20251203-app.md");
+        let path_str = path.to_str().unwrap();
+        let store = FsDocStore::new();
+
+        store.write(path_str, &doc("REQ-20251203-app", 10)).unwrap();
+        let created = rx.recv_timeout(WAIT).unwrap();
+        assert_eq!(created.name(), "doc-created");
+        assert_eq!(created.meta().doc_id, "REQ-20251203-app");
+
+        store.write(path_str, &doc("REQ-20251203-app", 11)).unwrap();
+        let updated = rx.recv_timeout(WAIT).unwrap();
+        assert_eq!(updated.name(), "doc-updated");
+        assert_eq!(updated.meta().updated_at.format("%H").to_string(), "11");
+
+        std::fs::remove_file(&path).unwrap();
+        let deleted = rx.recv_timeout(WAIT).unwrap();
+        assert_eq!(deleted.name(), "doc-deleted");
+        assert_eq!(deleted.meta().path, path_str);
+    }
+
+    #[test]
+    fn follows_project_changes() {
+        let root = tempfile::tempdir().unwrap();
+        let config = root.path().join("config");
+        let first = root.path().join("first");
+        let second = root.path().join("second");
+        std::fs::create_dir_all(&first).unwrap();
+        std::fs::create_dir_all(&second).unwrap();
+        let projects = LocalProjectStore::new(Some(config.clone()));
+        let project = projects
+            .create(NewProject {
+                id: None,
+                name: "App".to_string(),
+                default_path: first.display().to_string(),
+                repo_url: None,
+                enabled: true,
+                auto_commit: None,
+            })
+            .unwrap();
+
+        let (watcher, _rx) = start(&config);
+        assert_eq!(watcher.watched_dirs(), vec![first.clone()]);
+        let notified = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let flag = notified.clone();
+        watcher.on_projects_changed(move || flag.store(true, std::sync::atomic::Ordering::SeqCst));
+
+        projects
+            .update(
+                &project.id,
+                ProjectUpdate {
+                    default_path: Some(second.display().to_string()),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        wait_until(|| watcher.watched_dirs() == vec![second.clone()]);
+        wait_until(|| notified.load(std::sync::atomic::Ordering::SeqCst));
+
+        projects
+            .update(
+                &project.id,
+                ProjectUpdate {
+                    enabled: Some(false),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        wait_until(|| watcher.watched_dirs().is_empty());
+    }
+}
@@ -0,0 +1,287 @@
+//! Window State
+//!
+//! Last size, position, maximized state and monitor of each desktop window:
+//! - Keyed by window label (`main`, `capture`, `viewer`, `admin`)
+//! - Persisted as JSON at `~/.meatycapture/window-state.json` (atomic writes)
+//! - Restored onto the saved monitor when it is still connected, otherwise
+//!   the primary one, clamped so the window is fully visible
+//!
+//! Geometry is in physical pixels, as reported by the window system. A
+//! missing or corrupt file only means windows open at their default size.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use serde::{Deserialize, Serialize};
+
+use crate::atomic_write::write_atomic;
+use crate::error::{Error, Result};
+
+/// Bump when the on-disk layout changes; older files are discarded.
+const STATE_VERSION: u32 = 1;
+
+/// State file name inside the config directory.
+pub const STATE_FILE: &str = "window-state.json";
+
+/// Saved geometry of one window.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    /// Outer position (left edge)
+    pub x: i32,
+    /// Outer position (top edge)
+    pub y: i32,
+    /// Inner width
+    pub width: u32,
+    /// Inner height
+    pub height: u32,
+    /// Maximized when last seen; `x`/`y`/`width`/`height` are then the
+    /// geometry to return to when unmaximized
+    #[serde(default)]
+    pub maximized: bool,
+    /// Name of the monitor the window was on
+    #[serde(default)]
+    pub monitor: Option<String>,
+}
+
+/// Usable area of a connected monitor (excluding taskbars and docks).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonitorArea {
+    pub name: Option<String>,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl MonitorArea {
+    fn contains(&self, x: i64, y: i64) -> bool {
+        let (left, top) = (i64::from(self.x), i64::from(self.y));
+        (left..left + i64::from(self.width)).contains(&x)
+            && (top..top + i64::from(self.height)).contains(&y)
+    }
+}
+
+/// On-disk layout of the state file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct StateFile {
+    version: u32,
+    windows: BTreeMap<String, WindowGeometry>,
+}
+
+/// Window geometry by label, loaded once and saved on change.
+pub struct WindowStateStore {
+    file: PathBuf,
+    windows: Mutex<BTreeMap<String, WindowGeometry>>,
+}
+
+impl WindowStateStore {
+    /// Loads `window-state.json` from `config_dir`; starts empty if it is
+    /// missing, corrupt or from another version.
+    pub fn open(config_dir: &Path) -> Self {
+        let file = config_dir.join(STATE_FILE);
+        let windows = match fs::read_to_string(&file) {
+            Ok(content) => match serde_json::from_str::<StateFile>(&content) {
+                Ok(state) if state.version == STATE_VERSION => state.windows,
+                Ok(state) => {
+                    log::info!(
+                        "Discarding window state (version {} != {STATE_VERSION})",
+                        state.version
+                    );
+                    BTreeMap::new()
+                }
+                Err(error) => {
+                    log::warn!(
+                        "Discarding corrupt window state {}: {error}",
+                        file.display()
+                    );
+                    BTreeMap::new()
+                }
+            },
+            Err(_) => BTreeMap::new(),
+        };
+        Self {
+            file,
+            windows: Mutex::new(windows),
+        }
+    }
+
+    /// Saved geometry of the window with `label`.
+    pub fn get(&self, label: &str) -> Option<WindowGeometry> {
+        self.windows().get(label).cloned()
+    }
+
+    /// Records the geometry of `label` in memory; returns true if it changed.
+    pub fn update(&self, label: &str, geometry: WindowGeometry) -> bool {
+        let mut windows = self.windows();
+        if windows.get(label) == Some(&geometry) {
+            return false;
+        }
+        windows.insert(label.to_string(), geometry);
+        true
+    }
+
+    /// Writes all recorded windows to the state file.
+    pub fn save(&self) -> Result<()> {
+        let state = StateFile {
+            version: STATE_VERSION,
+            windows: self.windows().clone(),
+        };
+        let json = serde_json::to_vec_pretty(&state)
+            .map_err(Error::json("Failed to serialize window state"))?;
+        if let Some(dir) = self.file.parent() {
+            fs::create_dir_all(dir).map_err(Error::io(format!(
+                "Failed to create directory {}",
+                dir.display()
+            )))?;
+        }
+        write_atomic(&self.file, json).map_err(Error::io(format!(
+            "Failed to write window state {}",
+            self.file.display()
+        )))
+    }
+
+    fn windows(&self) -> MutexGuard<'_, BTreeMap<String, WindowGeometry>> {
+        self.windows.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Where to restore `saved` given the connected monitors (primary first).
+///
+/// Picks the saved monitor by name, else the monitor containing the
+/// window's center, else the primary one. The window is shrunk to fit and
+/// moved fully inside the chosen work area, so a window saved on a
+/// disconnected or resized display never opens off-screen. Returns `None`
+/// when no monitors are reported.
+pub fn place(saved: &WindowGeometry, monitors: &[MonitorArea]) -> Option<WindowGeometry> {
+    let center_x = i64::from(saved.x) + i64::from(saved.width / 2);
+    let center_y = i64::from(saved.y) + i64::from(saved.height / 2);
+    let area = saved
+        .monitor
+        .as_ref()
+        .and_then(|name| monitors.iter().find(|m| m.name.as_ref() == Some(name)))
+        .or_else(|| monitors.iter().find(|m| m.contains(center_x, center_y)))
+        .or_else(|| monitors.first())?;
+
+    let width = saved.width.min(area.width);
+    let height = saved.height.min(area.height);
+    Some(WindowGeometry {
+        x: clamp_axis(saved.x, area.x, area.width - width),
+        y: clamp_axis(saved.y, area.y, area.height - height),
+        width,
+        height,
+        maximized: saved.maximized,
+        monitor: area.name.clone(),
+    })
+}
+
+/// Clamps `position` to `[start, start + slack]`.
+fn clamp_axis(position: i32, start: i32, slack: u32) -> i32 {
+    let end = i64::from(start) + i64::from(slack);
+    i64::from(position).clamp(i64::from(start), end) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn geometry(x: i32, y: i32, width: u32, height: u32) -> WindowGeometry {
+        WindowGeometry {
+            x,
+            y,
+            width,
+            height,
+            maximized: false,
+            monitor: None,
+        }
+    }
+
+    fn monitor(name: &str, x: i32, y: i32, width: u32, height: u32) -> MonitorArea {
+        MonitorArea {
+            name: Some(name.to_string()),
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn persists_across_opens_and_survives_corruption() {
+        let temp = TempDir::new().unwrap();
+        let store = WindowStateStore::open(temp.path());
+        assert_eq!(store.get("viewer"), None);
+
+        let saved = WindowGeometry {
+            maximized: true,
+            monitor: Some("DP-1".to_string()),
+            ..geometry(100, 80, 1024, 768)
+        };
+        assert!(store.update("viewer", saved.clone()));
+        assert!(!store.update("viewer", saved.clone()));
+        store.save().unwrap();
+
+        let reopened = WindowStateStore::open(temp.path());
+        assert_eq!(reopened.get("viewer"), Some(saved));
+        assert_eq!(reopened.get("admin"), None);
+
+        fs::write(temp.path().join(STATE_FILE), "{ not json").unwrap();
+        assert_eq!(WindowStateStore::open(temp.path()).get("viewer"), None);
+        fs::write(
+            temp.path().join(STATE_FILE),
+            r#"{"version": 99, "windows": {}}"#,
+        )
+        .unwrap();
+        assert_eq!(WindowStateStore::open(temp.path()).get("viewer"), None);
+    }
+
+    #[test]
+    fn restores_onto_the_saved_monitor() {
+        let monitors = [
+            monitor("eDP-1", 0, 0, 1920, 1080),
+            monitor("DP-1", 1920, 0, 2560, 1440),
+        ];
+        let saved = WindowGeometry {
+            monitor: Some("DP-1".to_string()),
+            ..geometry(2200, 100, 1200, 900)
+        };
+        assert_eq!(place(&saved, &monitors), Some(saved.clone()));
+
+        // Matched by position when the name is unknown
+        let unnamed = geometry(2200, 100, 1200, 900);
+        assert_eq!(
+            place(&unnamed, &monitors).unwrap().monitor.as_deref(),
+            Some("DP-1")
+        );
+    }
+
+    #[test]
+    fn falls_back_to_primary_when_monitor_is_gone() {
+        let monitors = [monitor("eDP-1", 0, 0, 1920, 1080)];
+        let saved = WindowGeometry {
+            monitor: Some("DP-1".to_string()),
+            ..geometry(2200, 100, 1200, 900)
+        };
+        let placed = place(&saved, &monitors).unwrap();
+        assert_eq!(placed.monitor.as_deref(), Some("eDP-1"));
+        assert_eq!((placed.x, placed.y), (720, 100));
+        assert_eq!((placed.width, placed.height), (1200, 900));
+
+        assert_eq!(place(&saved, &[]), None);
+    }
+
+    #[test]
+    fn shrinks_and_moves_windows_into_view() {
+        let monitors = [MonitorArea {
+            name: None,
+            ..monitor("", 0, 25, 1280, 775)
+        }];
+        let placed = place(&geometry(-400, -50, 1600, 500), &monitors).unwrap();
+        assert_eq!(placed, geometry(0, 25, 1280, 500));
+
+        let placed = place(&geometry(1000, 700, 800, 600), &monitors).unwrap();
+        assert_eq!((placed.x, placed.y), (480, 200));
+    }
+}
@@ -0,0 +1,211 @@
+//! Desktop Windows
+//!
+//! Separate windows opened from the tray menu or `window_open`:
+//! - main: The full app (wizard, viewer and admin tabs), from `tauri.conf.json`
+//! - capture: Quick capture (see `capture`)
+//! - viewer / admin: Standalone document catalog and project/field management
+//!
+//! Every window restores its last size, position, maximized state and
+//! monitor from `window_state` when it is created, and records them as it
+//! moves, resizes or closes. Each label has its own capability file in
+//! `capabilities/`, so e.g. the capture window cannot delete projects.
+
+use serde::Deserialize;
+use tauri::{
+    AppHandle, Manager, PhysicalPosition, PhysicalSize, Runtime, WebviewUrl, WebviewWindowBuilder,
+    Window, WindowEvent,
+};
+
+use crate::capture;
+use crate::window_state::{place, MonitorArea, WindowGeometry, WindowStateStore};
+
+/// Label of the standalone viewer window.
+pub const VIEWER_WINDOW: &str = "viewer";
+
+/// Label of the standalone admin window.
+pub const ADMIN_WINDOW: &str = "admin";
+
+/// Windows the webview and tray can open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AppWindow {
+    Capture,
+    Viewer,
+    Admin,
+}
+
+impl AppWindow {
+    /// Window label (also the capability file's `windows` entry).
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Capture => capture::CAPTURE_WINDOW,
+            Self::Viewer => VIEWER_WINDOW,
+            Self::Admin => ADMIN_WINDOW,
+        }
+    }
+
+    /// Window title.
+    pub(crate) fn title(self) -> &'static str {
+        match self {
+            Self::Capture => "Quick Capture",
+            Self::Viewer => "MeatyCapture Viewer",
+            Self::Admin => "MeatyCapture Admin",
+        }
+    }
+
+    /// Size used until the user resizes the window.
+    pub(crate) fn default_size(self) -> (f64, f64) {
+        match self {
+            Self::Capture => (520.0, 400.0),
+            Self::Viewer => (1000.0, 700.0),
+            Self::Admin => (820.0, 640.0),
+        }
+    }
+}
+
+/// Opens (or focuses) `window`.
+pub fn open<R: Runtime>(app: &AppHandle<R>, window: AppWindow) {
+    if window == AppWindow::Capture {
+        capture::show(app);
+        return;
+    }
+    if let Err(error) = try_open(app, window) {
+        log::warn!("Failed to open {} window: {error}", window.label());
+    }
+}
+
+/// Moves and sizes a just-created window to its saved geometry, if any.
+pub fn restore<R: Runtime>(window: &Window<R>) {
+    let Some(states) = window.try_state::<WindowStateStore>() else {
+        return;
+    };
+    let Some(saved) = states.get(window.label()) else {
+        return;
+    };
+    let restored = monitor_areas(window).and_then(|monitors| {
+        let Some(placed) = place(&saved, &monitors) else {
+            return Ok(());
+        };
+        window.set_position(PhysicalPosition::new(placed.x, placed.y))?;
+        if window.is_resizable()? {
+            window.set_size(PhysicalSize::new(placed.width, placed.height))?;
+        }
+        if placed.maximized {
+            window.maximize()?;
+        }
+        Ok(())
+    });
+    if let Err(error) = restored {
+        log::warn!("Failed to restore {} window: {error}", window.label());
+    }
+}
+
+/// Records geometry as windows move or resize, and writes the state file
+/// when one closes (the capture window only hides, but still asks to close).
+pub fn handle_window_event<R: Runtime>(window: &Window<R>, event: &WindowEvent) {
+    match event {
+        WindowEvent::Moved(_) | WindowEvent::Resized(_) => record(window),
+        WindowEvent::CloseRequested { .. } => {
+            record(window);
+            save(window.app_handle());
+        }
+        _ => {}
+    }
+}
+
+/// Writes the recorded geometry of all windows (on close and on exit).
+pub fn save<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(states) = app.try_state::<WindowStateStore>() {
+        if let Err(error) = states.save() {
+            log::warn!("Failed to save window state: {error}");
+        }
+    }
+}
+
+fn try_open<R: Runtime>(app: &AppHandle<R>, window: AppWindow) -> tauri::Result<()> {
+    let webview = match app.get_webview_window(window.label()) {
+        Some(webview) => webview,
+        None => {
+            let (width, height) = window.default_size();
+            let created = WebviewWindowBuilder::new(
+                app,
+                window.label(),
+                WebviewUrl::App("index.html".into()),
+            )
+            .title(window.title())
+            .inner_size(width, height)
+            .min_inner_size(480.0, 360.0)
+            .center()
+            .visible(false)
+            .build()?;
+            restore(&created.as_ref().window());
+            created
+        }
+    };
+
+    webview.show()?;
+    webview.unminimize()?;
+    webview.set_focus()
+}
+
+fn record<R: Runtime>(window: &Window<R>) {
+    let Some(states) = window.try_state::<WindowStateStore>() else {
+        return;
+    };
+    // Hidden and minimized windows report placeholder geometry
+    if !window.is_visible().unwrap_or(false) || window.is_minimized().unwrap_or(true) {
+        return;
+    }
+    let monitor = window
+        .current_monitor()
+        .ok()
+        .flatten()
+        .and_then(|monitor| monitor.name().cloned());
+
+    let geometry = match (window.is_maximized(), states.get(window.label())) {
+        // Keep the unmaximized geometry to return to
+        (Ok(true), Some(previous)) => WindowGeometry {
+            maximized: true,
+            monitor,
+            ..previous
+        },
+        (maximized, _) => {
+            let (Ok(position), Ok(size)) = (window.outer_position(), window.inner_size()) else {
+                return;
+            };
+            WindowGeometry {
+                x: position.x,
+                y: position.y,
+                width: size.width,
+                height: size.height,
+                maximized: maximized.unwrap_or(false),
+                monitor,
+            }
+        }
+    };
+    states.update(window.label(), geometry);
+}
+
+/// Work areas of the connected monitors, primary first (the fallback for a
+/// disconnected display).
+fn monitor_areas<R: Runtime>(window: &Window<R>) -> tauri::Result<Vec<MonitorArea>> {
+    let primary = window
+        .primary_monitor()?
+        .and_then(|monitor| monitor.name().cloned());
+    let mut monitors = window.available_monitors()?;
+    monitors.sort_by_key(|monitor| monitor.name() != primary.as_ref());
+
+    Ok(monitors
+        .iter()
+        .map(|monitor| {
+            let area = monitor.work_area();
+            MonitorArea {
+                name: monitor.name().cloned(),
+                x: area.position.x,
+                y: area.position.y,
+                width: area.size.width,
+                height: area.size.height,
+            }
+        })
+        .collect())
+}
@@ -7,9 +7,17 @@
  * - Admin field management interface
  * - Store initialization and view routing
  */
-import { useState, useMemo } from 'react';
+import { useState, useMemo, useCallback } from 'react';
 import { Pencil2Icon, EyeOpenIcon, GearIcon, PersonIcon } from '@radix-ui/react-icons';
-import { ToastProvider, ToastContainer, useToast, useNavigationShortcuts } from './ui/shared';
+import {
+  ToastProvider,
+  ToastContainer,
+  useToast,
+  useNavigationShortcuts,
+  useNativeNavigation,
+  useNativeNotices,
+} from './ui/shared';
+import type { NativeNavigation, NativeDraft } from './ui/shared';
 import { WizardFlow } from './ui/wizard';
 import { AdminContainer } from './ui/admin';
 import { ViewerContainer } from './ui/viewer';
@@ -59,8 +67,20 @@ function initializeStores(): StoreResult {
 }
 
 function AppContent() {
-  const { toasts, dismissToast } = useToast();
+  const { toasts, addToast, dismissToast } = useToast();
   const [view, setView] = useState<View>('wizard');
+  // Project (and, from deep links, document and fields) preselected natively;
+  // bumping the key restarts the wizard
+  const [captureTarget, setCaptureTarget] = useState<{
+    projectId?: string;
+    docPath?: string;
+    draft?: NativeDraft;
+    key: number;
+  }>({
+    key: 0,
+  });
+  // Document opened from the command line (`meatycapture <file.md>`)
+  const [openDocPath, setOpenDocPath] = useState<string | undefined>(undefined);
 
   // Detect platform adapter mode
   const adapterMode = detectAdapterMode();
@@ -68,6 +88,27 @@ function AppContent() {
   // Enable keyboard shortcuts for navigation
   useNavigationShortcuts({ onNavigate: setView });
 
+  // Follow navigation requests from the system tray, forwarded launches and
+  // deep links
+  const handleNativeNavigation = useCallback((request: NativeNavigation) => {
+    if (request.project_id) {
+      const target = {
+        projectId: request.project_id,
+        ...(request.view === 'wizard' && request.doc_path ? { docPath: request.doc_path } : {}),
+        ...(request.draft ? { draft: request.draft } : {}),
+      };
+      setCaptureTarget((prev) => ({ ...target, key: prev.key + 1 }));
+    }
+    if (request.view === 'viewer' && request.doc_path) {
+      setOpenDocPath(request.doc_path);
+    }
+    setView(request.view);
+  }, []);
+  useNativeNavigation(handleNativeNavigation);
+
+  // Results of native actions, e.g. a deep link that saved an item
+  useNativeNotices(addToast);
+
   // Initialize stores once using useMemo to prevent recreation on re-renders
   // Error handling is done in initializeStores to avoid setState during render
   const { stores, error: initError } = useMemo(() => initializeStores(), []);
@@ -176,6 +217,10 @@ function AppContent() {
         <main id="main-content">
           {view === 'wizard' ? (
             <WizardFlow
+              key={captureTarget.key}
+              initialProjectId={captureTarget.projectId}
+              initialDocPath={captureTarget.docPath}
+              initialDraft={captureTarget.draft}
               projectStore={stores.projectStore}
               fieldCatalogStore={stores.fieldCatalogStore}
               docStore={stores.docStore}
@@ -185,6 +230,7 @@ function AppContent() {
             <ViewerContainer
               projectStore={stores.projectStore}
               docStore={stores.docStore}
+              openPath={openDocPath}
             />
           ) : (
             <AdminContainer
@@ -0,0 +1,33 @@
+/**
+ * Capture Window Root Component
+ *
+ * Root for the desktop quick-capture window (label `capture`), opened by the
+ * global shortcut. Uses the same stores as the main window.
+ */
+import { useMemo } from 'react';
+import { QuickCapture } from './ui/capture';
+import { createProjectStore, createFieldCatalogStore } from './adapters/config-local/platform-factory';
+import { createDocStore } from './adapters/fs-local/platform-factory';
+
+function CaptureApp() {
+  const stores = useMemo(
+    () => ({
+      projectStore: createProjectStore(),
+      fieldCatalogStore: createFieldCatalogStore(),
+      docStore: createDocStore(),
+    }),
+    []
+  );
+
+  return (
+    <main id="main-content">
+      <QuickCapture
+        projectStore={stores.projectStore}
+        fieldCatalogStore={stores.fieldCatalogStore}
+        docStore={stores.docStore}
+      />
+    </main>
+  );
+}
+
+export default CaptureApp;
@@ -0,0 +1,58 @@
+/**
+ * Standalone Window Root Component
+ *
+ * Root for the desktop `viewer` and `admin` windows, opened from the tray
+ * menu or `window_open`. Each shows a single view with the same stores as
+ * the main window; what it may change is limited by its capability file.
+ */
+import { useMemo } from 'react';
+import { ToastProvider, ToastContainer, useToast } from './ui/shared';
+import { ViewerContainer } from './ui/viewer';
+import { AdminContainer } from './ui/admin';
+import { createProjectStore, createFieldCatalogStore } from './adapters/config-local/platform-factory';
+import { createDocStore } from './adapters/fs-local/platform-factory';
+
+export type WindowView = 'viewer' | 'admin';
+
+interface WindowAppProps {
+  view: WindowView;
+}
+
+function WindowApp({ view }: WindowAppProps) {
+  return (
+    <ToastProvider>
+      <WindowContent view={view} />
+    </ToastProvider>
+  );
+}
+
+function WindowContent({ view }: WindowAppProps) {
+  const { toasts, dismissToast } = useToast();
+  const stores = useMemo(
+    () => ({
+      projectStore: createProjectStore(),
+      fieldCatalogStore: createFieldCatalogStore(),
+      docStore: createDocStore(),
+    }),
+    []
+  );
+
+  return (
+    <div className="app">
+      <main id="main-content">
+        {view === 'viewer' ? (
+          <ViewerContainer projectStore={stores.projectStore} docStore={stores.docStore} />
+        ) : (
+          <AdminContainer
+            projectStore={stores.projectStore}
+            fieldCatalogStore={stores.fieldCatalogStore}
+          />
+        )}
+      </main>
+
+      <ToastContainer toasts={toasts} onDismiss={dismissToast} />
+    </div>
+  );
+}
+
+export default WindowApp;
@@ -56,7 +56,7 @@ const DEFAULT_CONFIG = {
  * @param obj - Value to deserialize (can be primitive, object, or array)
  * @returns Deserialized value with Date objects
  */
-function deserializeDates(obj: unknown): unknown {
+export function deserializeDates(obj: unknown): unknown {
   // Convert ISO date strings to Date objects
   if (typeof obj === 'string' && /^\d{4}-\d{2}-\d{2}T/.test(obj)) {
     return new Date(obj);
@@ -8,7 +8,7 @@
  * @module adapters/api-client
  */
 
-export { HttpClient } from './http-client.js';
+export { HttpClient, deserializeDates } from './http-client.js';
 export type { HttpClientConfig } from './types.js';
 export {
   ApiError,
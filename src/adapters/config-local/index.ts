@@ -53,6 +53,9 @@ async function ensureConfigDir(configDir: string): Promise<void> {
 
 /**
  * Application configuration file structure.
+ *
+ * Keys owned by other writers (e.g. the desktop app's `close_to_tray`)
+ * are preserved on write.
  */
 interface ConfigFile {
   version: string;
@@ -60,6 +63,7 @@ interface ConfigFile {
   api_url?: string;
   created_at: string;
   updated_at: string;
+  [key: string]: unknown;
 }
 
 /**
@@ -143,6 +147,7 @@ export class LocalConfigStore implements ConfigStore {
     await ensureConfigDir(this.configDir);
 
     const data: ConfigFile = {
+      ...(await this.readUnknownKeys()),
       version: config.version,
       created_at: config.created_at.toISOString(),
       updated_at: config.updated_at.toISOString(),
@@ -167,6 +172,28 @@ export class LocalConfigStore implements ConfigStore {
     }
   }
 
+  /**
+   * Reads keys this store doesn't manage so writes don't drop them.
+   *
+   * @returns Unknown keys from the config file (empty if missing or invalid)
+   */
+  private async readUnknownKeys(): Promise<Record<string, unknown>> {
+    try {
+      const content = await fs.readFile(this.configFile, 'utf-8');
+      const {
+        version: _version,
+        default_project: _defaultProject,
+        api_url: _apiUrl,
+        created_at: _createdAt,
+        updated_at: _updatedAt,
+        ...rest
+      } = JSON.parse(content) as ConfigFile;
+      return rest;
+    } catch {
+      return {};
+    }
+  }
+
   /**
    * Gets the current application configuration.
    *
@@ -26,7 +26,7 @@ import { createBrowserProjectStore, createBrowserFieldCatalogStore } from '@adap
  *
  * Selection logic (by priority):
  * 1. API mode: MEATYCAPTURE_API_URL env var set → ApiProjectStore (HTTP client)
- * 2. Local mode: Tauri desktop → TauriProjectStore (@tauri-apps/plugin-fs)
+ * 2. Local mode: Tauri desktop → TauriProjectStore (native commands)
  * 3. Browser mode: Web browser → BrowserProjectStore (IndexedDB)
  *
  * API mode supports both browser and Node.js/Bun environments.
@@ -60,7 +60,7 @@ export function createProjectStore(): ProjectStore {
  *
  * Selection logic (by priority):
  * 1. API mode: MEATYCAPTURE_API_URL env var set → ApiFieldCatalogStore (HTTP client)
- * 2. Local mode: Tauri desktop → TauriFieldCatalogStore (@tauri-apps/plugin-fs)
+ * 2. Local mode: Tauri desktop → TauriFieldCatalogStore (native commands)
  * 3. Browser mode: Web browser → BrowserFieldCatalogStore (IndexedDB)
  *
  * API mode supports both browser and Node.js/Bun environments.
@@ -25,7 +25,7 @@ import { createBrowserDocStore } from '@adapters/browser-storage';
  *
  * Selection logic (by priority):
  * 1. API mode: MEATYCAPTURE_API_URL env var set → ApiDocStore (HTTP client)
- * 2. Local mode: Tauri desktop → TauriDocStore (@tauri-apps/plugin-fs)
+ * 2. Local mode: Tauri desktop → TauriDocStore (native commands)
  * 3. Browser mode: Web browser → BrowserDocStore (IndexedDB)
  *
  * API mode supports both browser and Node.js/Bun environments.
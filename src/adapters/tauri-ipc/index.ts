@@ -0,0 +1,10 @@
+/**
+ * Tauri IPC Adapter
+ *
+ * Helpers shared by the desktop adapters for calling native commands.
+ *
+ * @module adapters/tauri-ipc
+ */
+
+export { invokeCommand, mapCommandError } from './invoke-command.js';
+export type { CommandError } from './invoke-command.js';
@@ -0,0 +1,76 @@
+/**
+ * Tauri Command Invocation Tests
+ *
+ * Coverage:
+ * - Arguments are passed through to invoke()
+ * - ISO date strings in results become Date objects
+ * - Native { kind, message } errors map to typed errors
+ * - Unknown rejections become StorageError
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { invoke } from '@tauri-apps/api/core';
+import { invokeCommand, mapCommandError } from './invoke-command.js';
+import {
+  ConflictError,
+  NotFoundError,
+  PermissionDeniedError,
+  StorageError,
+  ValidationError,
+} from '@adapters/api-client';
+
+vi.mock('@tauri-apps/api/core', () => ({
+  invoke: vi.fn(),
+}));
+
+describe('invokeCommand', () => {
+  beforeEach(() => {
+    vi.mocked(invoke).mockReset();
+  });
+
+  it('passes arguments and revives dates', async () => {
+    vi.mocked(invoke).mockResolvedValue({
+      id: 'app',
+      created_at: '2025-12-03T12:00:00.000Z',
+    });
+
+    const result = await invokeCommand<{ id: string; created_at: Date }>('project_get', {
+      id: 'app',
+    });
+
+    expect(invoke).toHaveBeenCalledWith('project_get', { id: 'app' });
+    expect(result.created_at).toBeInstanceOf(Date);
+    expect(result.created_at.toISOString()).toBe('2025-12-03T12:00:00.000Z');
+  });
+
+  it('rethrows native errors as typed errors', async () => {
+    vi.mocked(invoke).mockRejectedValue({
+      kind: 'Locked',
+      message: 'Document is locked by another process: /docs/a.md',
+    });
+
+    await expect(invokeCommand('doc_append', { path: '/docs/a.md' })).rejects.toThrow(
+      ConflictError
+    );
+  });
+});
+
+describe('mapCommandError', () => {
+  it.each([
+    ['NotFound', NotFoundError],
+    ['Locked', ConflictError],
+    ['Validation', ValidationError],
+    ['PermissionDenied', PermissionDeniedError],
+    ['Storage', StorageError],
+  ])('maps %s', (kind, errorClass) => {
+    const error = mapCommandError({ kind, message: 'boom' });
+    expect(error).toBeInstanceOf(errorClass);
+    expect(error.message).toBe('boom');
+  });
+
+  it('wraps unknown rejections', () => {
+    const error = mapCommandError('command doc_read not allowed');
+    expect(error).toBeInstanceOf(StorageError);
+    expect(error.message).toBe('command doc_read not allowed');
+  });
+});
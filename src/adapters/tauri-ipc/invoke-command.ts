@@ -0,0 +1,90 @@
+/**
+ * Tauri Command Invocation
+ *
+ * Shared `invoke()` wrapper for the desktop adapters:
+ * - Calls a native command from `src-tauri/src/commands`
+ * - Converts ISO date strings in the result to Date objects
+ * - Rethrows native `{ kind, message }` errors as the API client's typed
+ *   errors, so callers handle desktop and API mode the same way
+ *
+ * Error mapping:
+ * - NotFound → NotFoundError
+ * - Locked → ConflictError (document locked by another process)
+ * - Validation → ValidationError
+ * - PermissionDenied → PermissionDeniedError (outside the allowed folders)
+ * - Storage / anything else → StorageError
+ */
+
+import {
+  ApiError,
+  ConflictError,
+  NotFoundError,
+  PermissionDeniedError,
+  StorageError,
+  ValidationError,
+  deserializeDates,
+} from '@adapters/api-client';
+
+/**
+ * Error payload returned by native commands (see `src-tauri/src/error.rs`).
+ */
+export interface CommandError {
+  kind: string;
+  message: string;
+}
+
+function isCommandError(value: unknown): value is CommandError {
+  return (
+    typeof value === 'object' &&
+    value !== null &&
+    typeof (value as CommandError).kind === 'string' &&
+    typeof (value as CommandError).message === 'string'
+  );
+}
+
+/**
+ * Maps a rejected `invoke()` value to a typed error.
+ *
+ * @param error - Value the command rejected with
+ * @returns Typed error instance
+ */
+export function mapCommandError(error: unknown): ApiError {
+  if (!isCommandError(error)) {
+    const message = error instanceof Error ? error.message : String(error);
+    return new StorageError(message);
+  }
+
+  switch (error.kind) {
+    case 'NotFound':
+      return new NotFoundError(error.message);
+    case 'Locked':
+      return new ConflictError(error.message);
+    case 'Validation':
+      return new ValidationError(error.message);
+    case 'PermissionDenied':
+      return new PermissionDeniedError(error.message);
+    default:
+      return new StorageError(error.message);
+  }
+}
+
+/**
+ * Invokes a native command and returns its result with dates revived.
+ *
+ * @param command - Command name (e.g. 'doc_read')
+ * @param args - Command arguments (camelCase keys)
+ * @returns Command result
+ * @throws ApiError subclass mapped from the native error
+ */
+export async function invokeCommand<T>(
+  command: string,
+  args?: Record<string, unknown>
+): Promise<T> {
+  const { invoke } = await import('@tauri-apps/api/core');
+
+  try {
+    return deserializeDates(await invoke<unknown>(command, args)) as T;
+  } catch (error) {
+    throw mapCommandError(error);
+  }
+}
@@ -0,0 +1,64 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Application configuration entity.
+ *
+ * Stored in `~/.meatycapture/config.json`, shared with the CLI. Desktop-only
+ * settings are optional so files written by older clients still load, and
+ * keys this build does not know are kept in `extra` and written back.
+ */
+export type AppConfig = { 
+/**
+ * Config file format version (semver)
+ */
+version: string, 
+/**
+ * Default project ID for new documents
+ */
+default_project?: string, 
+/**
+ * API server URL for remote mode (e.g., `http://localhost:3737`)
+ */
+api_url?: string, 
+/**
+ * Desktop: hide to the tray instead of quitting when the window closes
+ */
+close_to_tray?: boolean, 
+/**
+ * Desktop: global shortcut for the quick-capture window (empty disables)
+ */
+capture_shortcut?: string, 
+/**
+ * Desktop: document the quick-capture window last appended to
+ */
+last_document?: string, 
+/**
+ * Desktop: save `meatycapture://...&submit=1` links without asking
+ * (by default the capture window opens prefilled for confirmation)
+ */
+deep_link_auto_submit?: boolean, 
+/**
+ * Desktop: port of the embedded localhost API server (unset disables it)
+ */
+local_api_port?: number, 
+/**
+ * Desktop: bearer token required by the embedded API server (unset
+ * keeps the server off)
+ */
+local_api_token?: string, 
+/**
+ * Backup snapshots kept per document (`0` keeps all)
+ */
+backup_keep?: number, 
+/**
+ * Days after which backup snapshots are pruned (`0` never expires)
+ */
+backup_max_age_days?: number, 
+/**
+ * Timestamp when config was created
+ */
+created_at: string, 
+/**
+ * Timestamp of last modification
+ */
+updated_at: string, };
@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Document metadata for listing operations.
+ *
+ * Lightweight representation without full item details.
+ */
+export type DocMeta = { 
+/**
+ * Filesystem path to the document
+ */
+path: string, 
+/**
+ * Document identifier (e.g., `REQ-20251203-capture-app`)
+ */
+doc_id: string, 
+/**
+ * Document title
+ */
+title: string, 
+/**
+ * Total number of items in the document
+ */
+item_count: number, 
+/**
+ * Timestamp of last modification
+ */
+updated_at: string, };
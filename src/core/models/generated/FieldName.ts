@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Field names that support configurable options.
+ */
+export type FieldName = "type" | "domain" | "context" | "priority" | "status" | "tags";
@@ -0,0 +1,35 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FieldName } from "./FieldName";
+import type { FieldScope } from "./FieldScope";
+
+/**
+ * Field option entity.
+ *
+ * A configurable option for dropdown/select fields, scoped globally or
+ * to a specific project.
+ */
+export type FieldOption = { 
+/**
+ * Unique identifier
+ */
+id: string, 
+/**
+ * Which field this option belongs to
+ */
+field: FieldName, 
+/**
+ * The option value (e.g., `enhancement`, `bug`)
+ */
+value: string, 
+/**
+ * Whether this is a global or project-specific option
+ */
+scope: FieldScope, 
+/**
+ * Required when scope is `project`
+ */
+project_id?: string, 
+/**
+ * Timestamp when option was created
+ */
+created_at: string, };
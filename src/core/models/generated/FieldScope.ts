@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Scope for field options.
+ *
+ * Global options apply to all projects, project options to one project.
+ */
+export type FieldScope = "global" | "project";
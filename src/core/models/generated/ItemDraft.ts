@@ -0,0 +1,40 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Item draft entity.
+ *
+ * Form data for an item before it is appended to a document.
+ */
+export type ItemDraft = { 
+/**
+ * Item title/summary
+ */
+title: string, 
+/**
+ * Item type (enhancement, bug, idea, etc.)
+ */
+type: string, 
+/**
+ * Domain/area (web, api, mobile, etc.)
+ */
+domain: string, 
+/**
+ * Additional context information
+ */
+context: string, 
+/**
+ * Priority level (low, medium, high, critical)
+ */
+priority: string, 
+/**
+ * Current status (triage, backlog, in-progress, etc.)
+ */
+status: string, 
+/**
+ * Tag strings for categorization
+ */
+tags: Array<string>, 
+/**
+ * Freeform notes/description with problem/goal details
+ */
+notes: string, };
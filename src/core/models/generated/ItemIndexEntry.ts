@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Item index entry.
+ *
+ * Quick reference entry in frontmatter for fast lookup.
+ */
+export type ItemIndexEntry = { 
+/**
+ * Item ID reference
+ */
+id: string, 
+/**
+ * Item type for filtering
+ */
+type: string, 
+/**
+ * Item title for display
+ */
+title: string, };
@@ -0,0 +1,40 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Project configuration entity.
+ *
+ * Represents a project that can have request-log documents.
+ */
+export type Project = { 
+/**
+ * Unique identifier (slug format, e.g., `meatycapture`)
+ */
+id: string, 
+/**
+ * Human-readable project name
+ */
+name: string, 
+/**
+ * Default filesystem path for request-log files
+ */
+default_path: string, 
+/**
+ * Optional repository URL for context
+ */
+repo_url?: string, 
+/**
+ * Whether the project is active and available for selection
+ */
+enabled: boolean, 
+/**
+ * Commit each document write to the enclosing git repository
+ */
+auto_commit?: boolean, 
+/**
+ * Timestamp when project was created
+ */
+created_at: string, 
+/**
+ * Timestamp of last modification
+ */
+updated_at: string, };
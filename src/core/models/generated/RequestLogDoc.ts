@@ -0,0 +1,51 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ItemIndexEntry } from "./ItemIndexEntry";
+import type { RequestLogItem } from "./RequestLogItem";
+
+/**
+ * Request log document entity.
+ *
+ * Represents a complete request-log markdown document containing
+ * multiple items and aggregated metadata.
+ */
+export type RequestLogDoc = { 
+/**
+ * Request-log format version (defaults to the current version)
+ */
+schema_version?: number, 
+/**
+ * Document ID (e.g., `REQ-20251203-capture-app`)
+ */
+doc_id: string, 
+/**
+ * Document title
+ */
+title: string, 
+/**
+ * Associated project ID
+ */
+project_id: string, 
+/**
+ * All items in the document
+ */
+items: Array<RequestLogItem>, 
+/**
+ * Quick reference index for frontmatter
+ */
+items_index: Array<ItemIndexEntry>, 
+/**
+ * Aggregated unique tags from all items (sorted)
+ */
+tags: Array<string>, 
+/**
+ * Total number of items in document
+ */
+item_count: number, 
+/**
+ * Timestamp when document was created
+ */
+created_at: string, 
+/**
+ * Timestamp of last modification
+ */
+updated_at: string, };
@@ -0,0 +1,48 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request log item entity.
+ *
+ * Represents a persisted item within a request-log document.
+ */
+export type RequestLogItem = { 
+/**
+ * Unique item ID (e.g., `REQ-20251203-capture-app-01`)
+ */
+id: string, 
+/**
+ * Item title/summary
+ */
+title: string, 
+/**
+ * Item type (enhancement, bug, idea, etc.)
+ */
+type: string, 
+/**
+ * Domain/area (web, api, mobile, etc.)
+ */
+domain: string, 
+/**
+ * Additional context information
+ */
+context: string, 
+/**
+ * Priority level (low, medium, high, critical)
+ */
+priority: string, 
+/**
+ * Current status (triage, backlog, in-progress, etc.)
+ */
+status: string, 
+/**
+ * Tag strings for categorization
+ */
+tags: Array<string>, 
+/**
+ * Freeform notes/description with problem/goal details
+ */
+notes: string, 
+/**
+ * Timestamp when item was created
+ */
+created_at: string, };
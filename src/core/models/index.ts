@@ -8,172 +8,69 @@
  * - ItemDraft: Request log item being created
  * - RequestLogItem: Persisted item in request-log document
  * - RequestLogDoc: Complete request-log document structure
+ *
+ * The shapes are derived from the Rust models in `src-tauri/src/models.rs`
+ * (see `./generated`, exported by ts-rs). Generated types carry timestamps
+ * as ISO 8601 strings; the domain types below swap them for `Date`.
  */
 
-/**
- * Application configuration entity
- * Stores global application settings
- */
-export interface AppConfig {
-  /** Application version (semver format) */
-  version: string;
-  /** Default project ID for new documents */
-  default_project?: string;
-  /** API server URL for remote mode (e.g., 'http://localhost:3737') */
-  api_url?: string;
-  /** Timestamp when config was created */
-  created_at: Date;
-  /** Timestamp of last modification */
-  updated_at: Date;
-}
+import type { AppConfig as AppConfigJson } from './generated/AppConfig';
+import type { FieldOption as FieldOptionJson } from './generated/FieldOption';
+import type { Project as ProjectJson } from './generated/Project';
+import type { RequestLogDoc as RequestLogDocJson } from './generated/RequestLogDoc';
+import type { RequestLogItem as RequestLogItemJson } from './generated/RequestLogItem';
+import type { FieldName } from './generated/FieldName';
+import type { FieldScope } from './generated/FieldScope';
+import type { ItemDraft } from './generated/ItemDraft';
+import type { ItemIndexEntry } from './generated/ItemIndexEntry';
+
+export type { FieldName, FieldScope, ItemDraft, ItemIndexEntry };
 
 /**
- * Valid configuration keys that can be set
+ * Replaces the ISO timestamp fields K of a generated type with Date
  */
-export type ConfigKey = 'default_project' | 'api_url';
+export type WithDates<T, K extends keyof T> = Omit<T, K> & { [P in K]: Date };
 
 /**
- * Field names that support configurable options
+ * Application configuration entity
+ * Stores global application settings
  */
-export type FieldName = 'type' | 'domain' | 'context' | 'priority' | 'status' | 'tags';
+export type AppConfig = WithDates<AppConfigJson, 'created_at' | 'updated_at'>;
 
 /**
- * Scope for field options - global applies to all projects,
- * project applies only to a specific project
+ * Valid configuration keys that can be set
  */
-export type FieldScope = 'global' | 'project';
+export type ConfigKey = 'default_project' | 'api_url';
 
 /**
  * Project configuration entity
  * Represents a project that can have request-log documents
  */
-export interface Project {
-  /** Unique identifier (slug format, e.g., 'meatycapture') */
-  id: string;
-  /** Human-readable project name */
-  name: string;
-  /** Default filesystem path for request-log files */
-  default_path: string;
-  /** Optional repository URL for context */
-  repo_url?: string;
-  /** Whether the project is active and available for selection */
-  enabled: boolean;
-  /** Timestamp when project was created */
-  created_at: Date;
-  /** Timestamp of last modification */
-  updated_at: Date;
-}
+export type Project = WithDates<ProjectJson, 'created_at' | 'updated_at'>;
 
 /**
  * Field option entity
  * Represents a configurable option for dropdown/select fields
  * Can be scoped globally or to a specific project
  */
-export interface FieldOption {
-  /** Unique identifier */
-  id: string;
-  /** Which field this option belongs to */
-  field: FieldName;
-  /** The option value (e.g., 'enhancement', 'bug') */
-  value: string;
-  /** Whether this is a global or project-specific option */
-  scope: FieldScope;
-  /** Required when scope is 'project' */
-  project_id?: string;
-  /** Timestamp when option was created */
-  created_at: Date;
-}
-
-/**
- * Item draft entity
- * Represents an item being created in the wizard before persistence
- * This is the form data structure
- */
-export interface ItemDraft {
-  /** Item title/summary */
-  title: string;
-  /** Item type (enhancement, bug, idea, etc.) */
-  type: string;
-  /** Domain/area (web, api, mobile, etc.) */
-  domain: string;
-  /** Additional context information */
-  context: string;
-  /** Priority level (low, medium, high, critical) */
-  priority: string;
-  /** Current status (triage, backlog, in-progress, etc.) */
-  status: string;
-  /** Array of tag strings for categorization */
-  tags: string[];
-  /** Freeform notes/description with problem/goal details */
-  notes: string;
-}
+export type FieldOption = WithDates<FieldOptionJson, 'created_at'>;
 
 /**
  * Request log item entity
  * Represents a persisted item within a request-log document
  * Extends ItemDraft with ID and timestamp
  */
-export interface RequestLogItem {
-  /** Unique item ID (e.g., 'REQ-20251203-capture-app-01') */
-  id: string;
-  /** Item title/summary */
-  title: string;
-  /** Item type (enhancement, bug, idea, etc.) */
-  type: string;
-  /** Domain/area (web, api, mobile, etc.) */
-  domain: string;
-  /** Additional context information */
-  context: string;
-  /** Priority level (low, medium, high, critical) */
-  priority: string;
-  /** Current status (triage, backlog, in-progress, etc.) */
-  status: string;
-  /** Array of tag strings for categorization */
-  tags: string[];
-  /** Freeform notes/description with problem/goal details */
-  notes: string;
-  /** Timestamp when item was created */
-  created_at: Date;
-}
-
-/**
- * Item index entry
- * Quick reference entry in frontmatter for fast lookup
- */
-export interface ItemIndexEntry {
-  /** Item ID reference */
-  id: string;
-  /** Item type for filtering */
-  type: string;
-  /** Item title for display */
-  title: string;
-}
+export type RequestLogItem = WithDates<RequestLogItemJson, 'created_at'>;
 
 /**
  * Request log document entity
  * Represents a complete request-log markdown document
  * Contains multiple items and aggregated metadata
  */
-export interface RequestLogDoc {
-  /** Document ID (e.g., 'REQ-20251203-capture-app') */
-  doc_id: string;
-  /** Document title */
-  title: string;
-  /** Associated project ID */
-  project_id: string;
-  /** All items in the document */
-  items: RequestLogItem[];
-  /** Quick reference index for frontmatter */
-  items_index: ItemIndexEntry[];
-  /** Aggregated unique tags from all items (sorted) */
-  tags: string[];
-  /** Total number of items in document */
-  item_count: number;
-  /** Timestamp when document was created */
-  created_at: Date;
-  /** Timestamp of last modification */
-  updated_at: Date;
-}
+export type RequestLogDoc = WithDates<
+  Omit<RequestLogDocJson, 'items'> & { items: RequestLogItem[] },
+  'created_at' | 'updated_at'
+>;
 
 /**
  * Default field option values
@@ -198,7 +95,8 @@ export function isProject(obj: unknown): obj is Project {
     typeof p.enabled === 'boolean' &&
     p.created_at instanceof Date &&
     p.updated_at instanceof Date &&
-    (p.repo_url === undefined || typeof p.repo_url === 'string')
+    (p.repo_url === undefined || typeof p.repo_url === 'string') &&
+    (p.auto_commit === undefined || typeof p.auto_commit === 'boolean')
   );
 }
 
@@ -11,7 +11,17 @@
  * Implementations live in adapters/ (fs-local, config-local)
  */
 
-import type { AppConfig, ConfigKey, Project, FieldOption, FieldName, ItemDraft, RequestLogDoc } from '@core/models';
+import type {
+  AppConfig,
+  ConfigKey,
+  Project,
+  FieldOption,
+  FieldName,
+  ItemDraft,
+  RequestLogDoc,
+  WithDates,
+} from '@core/models';
+import type { DocMeta as DocMetaJson } from '@core/models/generated/DocMeta';
 
 /**
  * Clock abstraction for time-dependent operations
@@ -152,18 +162,7 @@ export interface FieldCatalogStore {
  * Document metadata for listing operations
  * Lightweight representation without full item details
  */
-export interface DocMeta {
-  /** Filesystem path to the document */
-  path: string;
-  /** Document identifier (e.g., 'REQ-20251203-capture-app') */
-  doc_id: string;
-  /** Document title */
-  title: string;
-  /** Total number of items in the document */
-  item_count: number;
-  /** Timestamp of last modification */
-  updated_at: Date;
-}
+export type DocMeta = WithDates<DocMetaJson, 'updated_at'>;
 
 /**
  * Request-log document store interface
@@ -7,10 +7,17 @@
  * - Tag aggregation (unique sorted list from all items)
  * - Item count auto-update
  * - Backup creation before writes
+ * - `schema_version` detection (files without it are version 1)
  */
 
 import type { RequestLogDoc, RequestLogItem, ItemIndexEntry } from '@core/models';
 
+/** Request-log format version written for new documents. */
+export const SCHEMA_VERSION = 2;
+
+/** Version of files written before `schema_version` existed. */
+const LEGACY_SCHEMA_VERSION = 1;
+
 /**
  * Serializes a RequestLogDoc to markdown format with YAML frontmatter.
  *
@@ -18,6 +25,7 @@ import type { RequestLogDoc, RequestLogItem, ItemIndexEntry } from '@core/models
  * ```yaml
  * ---
  * type: request-log
+ * schema_version: 2
  * doc_id: REQ-20251203-capture-app
  * title: Capture App Request Log
  * project_id: capture-app
@@ -71,6 +79,7 @@ export function parse(content: string): RequestLogDoc {
   const items_index = frontmatter.items_index || [];
   const created_at = parseDate(frontmatter.created_at);
   const updated_at = parseDate(frontmatter.updated_at);
+  const schema_version = frontmatter.schema_version ?? LEGACY_SCHEMA_VERSION;
 
   // Validate required fields
   if (!doc_id || typeof doc_id !== 'string') {
@@ -82,6 +91,9 @@ export function parse(content: string): RequestLogDoc {
   if (!project_id || typeof project_id !== 'string') {
     throw new Error('Missing or invalid required field: project_id');
   }
+  if (!Number.isInteger(schema_version) || schema_version < 1) {
+    throw new Error('Missing or invalid required field: schema_version');
+  }
   if (typeof item_count !== 'number') {
     throw new Error('Missing or invalid required field: item_count');
   }
@@ -102,6 +114,7 @@ export function parse(content: string): RequestLogDoc {
   const items = parseItems(body);
 
   return {
+    schema_version,
     doc_id,
     title,
     project_id,
@@ -164,9 +177,12 @@ export function updateItemsIndex(items: RequestLogItem[]): ItemIndexEntry[] {
  * @returns YAML frontmatter string wrapped in --- delimiters
  */
 function serializeFrontmatter(doc: RequestLogDoc): string {
+  // Version 1 files stay byte-identical when rewritten
+  const version = doc.schema_version ?? SCHEMA_VERSION;
   const lines = [
     '---',
     'type: request-log',
+    ...(version === LEGACY_SCHEMA_VERSION ? [] : [`schema_version: ${version}`]),
     `doc_id: ${doc.doc_id}`,
     `title: ${doc.title}`,
     `project_id: ${doc.project_id}`,
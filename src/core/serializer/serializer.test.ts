@@ -11,7 +11,7 @@
  */
 
 import { describe, it, expect } from 'vitest';
-import { serialize, parse, aggregateTags, updateItemsIndex } from './index';
+import { serialize, parse, aggregateTags, updateItemsIndex, SCHEMA_VERSION } from './index';
 import { createTestDoc, createTestItem } from '../test-helpers';
 
 describe('serialize', () => {
@@ -458,6 +458,31 @@ describe('serialize/parse roundtrip', () => {
     expect(parsed.created_at.getTime()).toBe(original.created_at.getTime());
     expect(parsed.updated_at.getTime()).toBe(original.updated_at.getTime());
   });
+
+  it('should write the current schema_version for new documents', () => {
+    const markdown = serialize(createTestDoc());
+
+    expect(markdown).toContain(`type: request-log\nschema_version: ${SCHEMA_VERSION}\n`);
+    expect(parse(markdown).schema_version).toBe(SCHEMA_VERSION);
+  });
+
+  it('should keep version 1 documents without a schema_version key', () => {
+    const legacy = serialize(createTestDoc({ schema_version: 1 }));
+    const parsed = parse(legacy);
+
+    expect(legacy).not.toContain('schema_version');
+    expect(parsed.schema_version).toBe(1);
+    expect(serialize(parsed)).toBe(legacy);
+  });
+
+  it('should reject an invalid schema_version', () => {
+    const markdown = serialize(createTestDoc()).replace(
+      `schema_version: ${SCHEMA_VERSION}`,
+      'schema_version: two'
+    );
+
+    expect(() => parse(markdown)).toThrow('schema_version');
+  });
 });
 
 describe('aggregateTags', () => {
@@ -0,0 +1,103 @@
+/**
+ * Shared ID Test Vectors
+ *
+ * Checks the ID helpers against `id-vectors.json`, which the Rust `ids`
+ * module tests read as well, so both implementations provably agree.
+ *
+ * Inputs are curated by hand; outputs are exported from this implementation:
+ *   UPDATE_ID_VECTORS=1 pnpm test src/core/validation/id-vectors
+ * Dates are `YYYY-MM-DD` in local time; `null` means "throws" or "invalid".
+ */
+
+import { writeFileSync } from 'node:fs';
+import { describe, it, expect, afterAll } from 'vitest';
+import {
+  generateDocId,
+  generateItemId,
+  parseDocId,
+  parseItemId,
+  slugify,
+  sanitizePathSegment,
+  getNextItemNumber,
+} from './index';
+import vectors from './id-vectors.json';
+
+const VECTORS_PATH = new URL('./id-vectors.json', import.meta.url);
+const update = Boolean(process.env['UPDATE_ID_VECTORS']);
+
+function formatDate(date: Date): string {
+  const year = String(date.getFullYear()).padStart(4, '0');
+  const month = String(date.getMonth() + 1).padStart(2, '0');
+  const day = String(date.getDate()).padStart(2, '0');
+  return `${year}-${month}-${day}`;
+}
+
+function localDate(value: string): Date {
+  const [year = 0, month = 1, day = 1] = value.split('-').map(Number);
+  return new Date(year, month - 1, day);
+}
+
+function orNull<T>(fn: () => T): T | null {
+  try {
+    return fn();
+  } catch {
+    return null;
+  }
+}
+
+const actual = {
+  slugify: vectors.slugify.map(({ input }) => ({ input, output: slugify(input) })),
+  sanitizePathSegment: vectors.sanitizePathSegment.map(({ input }) => ({
+    input,
+    output: sanitizePathSegment(input),
+  })),
+  generateDocId: vectors.generateDocId.map(({ slug, date }) => ({
+    slug,
+    date,
+    output: orNull(() => generateDocId(slug, localDate(date))),
+  })),
+  generateItemId: vectors.generateItemId.map(({ docId, itemNumber }) => ({
+    docId,
+    itemNumber,
+    output: orNull(() => generateItemId(docId, itemNumber)),
+  })),
+  parseDocId: vectors.parseDocId.map(({ input }) => {
+    const parsed = parseDocId(input);
+    return {
+      input,
+      output: parsed && { date: formatDate(parsed.date), projectSlug: parsed.projectSlug },
+    };
+  }),
+  parseItemId: vectors.parseItemId.map(({ input }) => {
+    const parsed = parseItemId(input);
+    return {
+      input,
+      output: parsed && {
+        docId: parsed.docId,
+        itemNumber: parsed.itemNumber,
+        date: formatDate(parsed.date),
+        projectSlug: parsed.projectSlug,
+      },
+    };
+  }),
+  getNextItemNumber: vectors.getNextItemNumber.map(({ ids }) => ({
+    ids,
+    output: getNextItemNumber((ids as string[]).map((id) => ({ id }))),
+  })),
+};
+
+describe('id-vectors.json', () => {
+  for (const key of Object.keys(actual) as Array<keyof typeof actual>) {
+    it(`should match ${key} vectors`, () => {
+      if (!update) {
+        expect(actual[key]).toEqual(vectors[key]);
+      }
+    });
+  }
+
+  afterAll(() => {
+    if (update) {
+      writeFileSync(VECTORS_PATH, JSON.stringify(actual, null, 2) + '\n');
+    }
+  });
+});
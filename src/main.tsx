@@ -1,6 +1,9 @@
 import React from 'react';
 import ReactDOM from 'react-dom/client';
 import App from './App';
+import CaptureApp from './CaptureApp';
+import WindowApp from './WindowApp';
+import { isTauri } from '@platform';
 import './index.css';
 
 const rootElement = document.getElementById('root');
@@ -9,8 +12,32 @@ if (!rootElement) {
   throw new Error('Failed to find root element');
 }
 
-ReactDOM.createRoot(rootElement).render(
-  <React.StrictMode>
-    <App />
-  </React.StrictMode>
-);
+/**
+ * Desktop windows load the same bundle; pick the root by window label
+ * (`main`, `capture`, `viewer` or `admin`).
+ */
+async function windowLabel(): Promise<string> {
+  if (!isTauri()) {
+    return 'main';
+  }
+  const { getCurrentWindow } = await import('@tauri-apps/api/window');
+  return getCurrentWindow().label;
+}
+
+function rootFor(label: string): React.JSX.Element {
+  switch (label) {
+    case 'capture':
+      return <CaptureApp />;
+    case 'viewer':
+    case 'admin':
+      return <WindowApp view={label} />;
+    default:
+      return <App />;
+  }
+}
+
+void windowLabel()
+  .catch(() => 'main')
+  .then((label) => {
+    ReactDOM.createRoot(rootElement).render(<React.StrictMode>{rootFor(label)}</React.StrictMode>);
+  });
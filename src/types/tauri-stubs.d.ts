@@ -5,17 +5,29 @@
  * The actual implementations are only available in Tauri desktop builds.
  */
 
-declare module '@tauri-apps/plugin-fs' {
-  export function readTextFile(path: string): Promise<string>;
-  export function writeTextFile(path: string, content: string): Promise<void>;
-  export function readDir(path: string): Promise<Array<{ name: string; isFile: boolean; isDirectory: boolean }>>;
-  export function exists(path: string): Promise<boolean>;
-  export function mkdir(path: string, options?: { recursive?: boolean }): Promise<void>;
-  export function copyFile(source: string, destination: string): Promise<void>;
-}
-
 declare module '@tauri-apps/api/path' {
   export function join(...paths: string[]): Promise<string>;
   export function dirname(path: string): Promise<string>;
   export function homeDir(): Promise<string>;
 }
+
+declare module '@tauri-apps/api/event' {
+  export interface Event<T> {
+    event: string;
+    id: number;
+    payload: T;
+  }
+  export type UnlistenFn = () => void;
+  export function listen<T>(event: string, handler: (event: Event<T>) => void): Promise<UnlistenFn>;
+}
+
+declare module '@tauri-apps/api/core' {
+  export function invoke<T>(command: string, args?: Record<string, unknown>): Promise<T>;
+}
+
+declare module '@tauri-apps/api/window' {
+  export interface Window {
+    label: string;
+  }
+  export function getCurrentWindow(): Window;
+}
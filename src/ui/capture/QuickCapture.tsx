@@ -0,0 +1,338 @@
+/**
+ * QuickCapture Component
+ *
+ * Single-screen capture form for the desktop quick-capture window,
+ * opened by the global shortcut.
+ *
+ * Features:
+ * - Preloads the default project and the last-used document from app config
+ * - Confirms `meatycapture://...&submit=1` links: opens with their project,
+ *   document and fields prefilled, and saves only on Enter / Save
+ * - Title, type and notes only; other fields use wizard defaults
+ * - Enter (Cmd/Ctrl+Enter in notes) submits, Escape hides the window
+ * - Saves through `capture_submit`: appends to the chosen document, or to
+ *   today's project document (created on first capture) when the project
+ *   has none or "Today's document" is chosen
+ *
+ * Desktop only: relies on the `capture_*` native commands.
+ */
+
+import React, { useCallback, useEffect, useRef, useState } from 'react';
+import type { ProjectStore, FieldCatalogStore, DocStore, DocMeta } from '@core/ports';
+import type { Project, FieldOption, ItemDraft } from '@core/models';
+import { invokeCommand } from '@adapters/tauri-ipc';
+import '../shared/shared.css';
+import './quick-capture.css';
+
+interface QuickCaptureProps {
+  /** Project store for the project selector */
+  projectStore: ProjectStore;
+  /** Field catalog store for item types */
+  fieldCatalogStore: FieldCatalogStore;
+  /** Document store for listing the project's documents */
+  docStore: DocStore;
+}
+
+/**
+ * Capture target sent with `capture-open` and returned by `capture_target`
+ */
+interface CaptureTarget {
+  project_id?: string | null;
+  doc_path?: string | null;
+  /** Preselect today's document (deep links without `doc`) */
+  today?: boolean;
+  /** Prefilled title (`meatycapture --capture "title"` or a deep link) */
+  title?: string | null;
+  /** Prefilled type, tags and notes (deep links) */
+  type?: string | null;
+  tags?: string[];
+  notes?: string | null;
+}
+
+/** Select value for today's project document */
+const NEW_DOC = '';
+
+const DEFAULT_DRAFT: Omit<ItemDraft, 'title' | 'type' | 'notes'> = {
+  domain: '',
+  context: '',
+  priority: 'medium',
+  status: 'triage',
+  tags: [],
+};
+
+async function hideWindow(): Promise<void> {
+  const { invoke } = await import('@tauri-apps/api/core');
+  await invoke('capture_hide');
+}
+
+export function QuickCapture({
+  projectStore,
+  fieldCatalogStore,
+  docStore,
+}: QuickCaptureProps): React.JSX.Element {
+  const [projects, setProjects] = useState<Project[]>([]);
+  const [projectId, setProjectId] = useState<string>('');
+  const [docs, setDocs] = useState<DocMeta[]>([]);
+  const [docPath, setDocPath] = useState<string>(NEW_DOC);
+  const [types, setTypes] = useState<FieldOption[]>([]);
+
+  const [title, setTitle] = useState('');
+  const [type, setType] = useState('');
+  const [notes, setNotes] = useState('');
+  const [tags, setTags] = useState<string[]>([]);
+
+  const [isSubmitting, setIsSubmitting] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  // Bumped on every open so documents are reloaded for the same project
+  const [openCount, setOpenCount] = useState(0);
+
+  // Document to preselect once the project's documents are loaded
+  const preferredDocRef = useRef<string | null>(null);
+  const titleRef = useRef<HTMLInputElement>(null);
+
+  const selectedProject = projects.find((p) => p.id === projectId) ?? null;
+
+  /**
+   * Resets the form to the configured project and document
+   */
+  const open = useCallback(
+    async (target: CaptureTarget) => {
+      setTitle(target.title ?? '');
+      setNotes(target.notes ?? '');
+      setTags(target.tags ?? []);
+      if (target.type) setType(target.type);
+      setError(null);
+      titleRef.current?.focus();
+
+      try {
+        const enabled = (await projectStore.list()).filter((p) => p.enabled);
+        const project = enabled.find((p) => p.id === target.project_id) ?? enabled[0];
+
+        preferredDocRef.current = target.today ? NEW_DOC : (target.doc_path ?? null);
+        setProjects(enabled);
+        setProjectId(project?.id ?? '');
+        setOpenCount((count) => count + 1);
+      } catch (err) {
+        setError(err instanceof Error ? err.message : 'Failed to load projects');
+      }
+    },
+    [projectStore]
+  );
+
+  /**
+   * Load config on mount and reset whenever the window is reopened
+   */
+  useEffect(() => {
+    let disposed = false;
+    let unlisten: (() => void) | undefined;
+
+    void (async () => {
+      const { listen } = await import('@tauri-apps/api/event');
+
+      const stop = await listen<CaptureTarget>('capture-open', (event) => {
+        void open(event.payload);
+      });
+      if (disposed) {
+        stop();
+        return;
+      }
+      unlisten = stop;
+
+      await open(await invokeCommand<CaptureTarget>('capture_target'));
+    })().catch((err) => {
+      console.error('[QuickCapture] Failed to initialize:', err);
+      setError(err instanceof Error ? err.message : 'Failed to load configuration');
+    });
+
+    return () => {
+      disposed = true;
+      unlisten?.();
+    };
+  }, [open]);
+
+  /**
+   * Load documents and item types for the selected project
+   */
+  useEffect(() => {
+    if (!selectedProject) {
+      setDocs([]);
+      setDocPath(NEW_DOC);
+      return;
+    }
+
+    let cancelled = false;
+
+    void (async () => {
+      const [docList, options] = await Promise.all([
+        docStore.list(selectedProject.default_path).catch(() => [] as DocMeta[]),
+        fieldCatalogStore.getForProject(selectedProject.id),
+      ]);
+      if (cancelled) return;
+
+      const preferred = docList.find((d) => d.path === preferredDocRef.current);
+      const typeOptions = options.filter((o) => o.field === 'type');
+
+      setDocs(docList);
+      setDocPath(
+        preferredDocRef.current === NEW_DOC
+          ? NEW_DOC
+          : (preferred?.path ?? docList[0]?.path ?? NEW_DOC)
+      );
+      setTypes(typeOptions);
+      setType((current) =>
+        typeOptions.some((o) => o.value === current) ? current : (typeOptions[0]?.value ?? '')
+      );
+    })().catch((err) => {
+      if (!cancelled) {
+        setError(err instanceof Error ? err.message : 'Failed to load project');
+      }
+    });
+
+    return () => {
+      cancelled = true;
+    };
+  }, [selectedProject, openCount, docStore, fieldCatalogStore]);
+
+  const handleSubmit = useCallback(async () => {
+    if (!selectedProject || !title.trim() || isSubmitting) return;
+
+    const draft: ItemDraft = { ...DEFAULT_DRAFT, title: title.trim(), type, notes, tags };
+
+    try {
+      setIsSubmitting(true);
+      setError(null);
+
+      const path = await invokeCommand<string>('capture_submit', {
+        projectId: selectedProject.id,
+        docPath: docPath === NEW_DOC ? null : docPath,
+        item: draft,
+      });
+      preferredDocRef.current = path;
+
+      setTitle('');
+      setNotes('');
+      setTags([]);
+      await hideWindow();
+    } catch (err) {
+      console.error('[QuickCapture] Failed to capture item:', err);
+      setError(err instanceof Error ? err.message : String(err));
+    } finally {
+      setIsSubmitting(false);
+    }
+  }, [selectedProject, title, type, notes, tags, docPath, isSubmitting]);
+
+  const handleKeyDown = useCallback(
+    (event: React.KeyboardEvent) => {
+      if (event.key === 'Escape') {
+        event.preventDefault();
+        void hideWindow();
+        return;
+      }
+
+      const inNotes = event.target instanceof HTMLTextAreaElement;
+      if (event.key === 'Enter' && (!inNotes || event.metaKey || event.ctrlKey)) {
+        event.preventDefault();
+        void handleSubmit();
+      }
+    },
+    [handleSubmit]
+  );
+
+  return (
+    <div className="quick-capture glass" onKeyDown={handleKeyDown}>
+      <div className="quick-capture-header" data-tauri-drag-region>
+        <h1 data-tauri-drag-region>Quick capture</h1>
+        <span className="quick-capture-hint">Enter to save · Esc to close</span>
+      </div>
+
+      <div className="quick-capture-target">
+        <select
+          className="input-base"
+          aria-label="Project"
+          value={projectId}
+          onChange={(e) => {
+            preferredDocRef.current = null;
+            setProjectId(e.target.value);
+          }}
+        >
+          {projects.length === 0 && <option value="">No projects</option>}
+          {projects.map((project) => (
+            <option key={project.id} value={project.id}>
+              {project.name}
+            </option>
+          ))}
+        </select>
+
+        <select
+          className="input-base"
+          aria-label="Document"
+          value={docPath}
+          onChange={(e) => setDocPath(e.target.value)}
+        >
+          <option value={NEW_DOC}>Today&apos;s document</option>
+          {docs.map((doc) => (
+            <option key={doc.path} value={doc.path}>
+              {doc.title || doc.doc_id}
+            </option>
+          ))}
+        </select>
+      </div>
+
+      <div className="quick-capture-row">
+        <input
+          ref={titleRef}
+          className="input-base"
+          type="text"
+          aria-label="Title"
+          placeholder="What's the idea, bug or request?"
+          value={title}
+          onChange={(e) => setTitle(e.target.value)}
+          autoFocus
+        />
+        <select
+          className="input-base quick-capture-type"
+          aria-label="Type"
+          value={type}
+          onChange={(e) => setType(e.target.value)}
+        >
+          {types.map((option) => (
+            <option key={option.id} value={option.value}>
+              {option.value}
+            </option>
+          ))}
+        </select>
+      </div>
+
+      <textarea
+        className="input-base quick-capture-notes"
+        aria-label="Notes"
+        placeholder="Notes (optional)"
+        value={notes}
+        onChange={(e) => setNotes(e.target.value)}
+      />
+
+      {tags.length > 0 && <span className="quick-capture-hint">Tags: {tags.join(', ')}</span>}
+
+      {error && (
+        <div className="error-message" role="alert">
+          {error}
+        </div>
+      )}
+
+      <div className="quick-capture-actions">
+        <button type="button" className="button secondary" onClick={() => void hideWindow()}>
+          Cancel
+        </button>
+        <button
+          type="button"
+          className={`button primary ${isSubmitting ? 'loading' : ''}`}
+          onClick={() => void handleSubmit()}
+          disabled={!selectedProject || !title.trim() || isSubmitting}
+        >
+          Save
+        </button>
+      </div>
+    </div>
+  );
+}
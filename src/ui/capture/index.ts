@@ -0,0 +1,7 @@
+/**
+ * Quick Capture Components
+ *
+ * Single-screen capture form for the desktop quick-capture window.
+ */
+
+export { QuickCapture } from './QuickCapture';
@@ -4,6 +4,7 @@
  * Component structure:
  * - wizard/: Multi-step capture flow (Project → Doc → Item → Review)
  * - admin/: Field management interface
+ * - capture/: Desktop quick-capture window form
  * - shared/: Reusable components (DropdownWithAdd, MultiSelectWithAdd, etc.)
  *
  * Design: Glass/x-morphism styling, accessibility-first
@@ -14,3 +15,6 @@ export * from './shared';
 
 // Export admin components
 export * from './admin';
+
+// Export quick-capture components
+export * from './capture';
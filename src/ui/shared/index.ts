@@ -14,6 +14,8 @@ export { Tooltip } from './Tooltip';
 export { FormField } from './FormField';
 export { useKeyboardShortcuts } from './useKeyboardShortcuts';
 export { useNavigationShortcuts } from './useNavigationShortcuts';
+export { useNativeNavigation } from './useNativeNavigation';
+export { useNativeNotices } from './useNativeNotices';
 export { useFocusTrap } from './useFocusTrap';
 export { Toast, ToastContainer } from './Toast';
 export { ToastProvider, useToast } from './useToast';
@@ -26,3 +28,5 @@ export type { default as StepProgressProps } from './StepProgress';
 export type { default as PathFieldProps } from './PathField';
 export type { ToastType, ToastData } from './Toast';
 export type { ValidationState } from './FormField';
+export type { NativeNavigation, NativeDraft } from './useNativeNavigation';
+export type { NativeNotice } from './useNativeNotices';
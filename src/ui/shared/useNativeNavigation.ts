@@ -0,0 +1,84 @@
+/**
+ * useNativeNavigation Hook
+ *
+ * Subscribes to `navigate` events emitted by the desktop app's native
+ * entry points (system tray menu, arguments forwarded from a second launch,
+ * `meatycapture://` deep links).
+ *
+ * Payload:
+ * - view: Target view ('wizard' | 'viewer' | 'admin')
+ * - project_id: Optional project to preselect in the capture wizard
+ * - doc_path: Optional document to expand in the viewer, or to append to
+ *   in the wizard
+ * - draft: Optional item fields to prefill in the wizard
+ *
+ * No-op outside Tauri.
+ */
+
+import { useEffect, useRef } from 'react';
+import { isTauri } from '@platform';
+import type { ItemDraft } from '@core/models';
+
+type View = 'wizard' | 'viewer' | 'admin';
+
+/**
+ * Item fields a deep link may prefill (validated natively)
+ */
+export type NativeDraft = Partial<Pick<ItemDraft, 'title' | 'type' | 'tags' | 'notes'>>;
+
+/**
+ * Navigation request sent by the native side
+ */
+export interface NativeNavigation {
+  view: View;
+  project_id?: string;
+  doc_path?: string;
+  draft?: NativeDraft;
+}
+
+/**
+ * useNativeNavigation Hook
+ *
+ * Listens for navigation requests for the lifetime of the component.
+ * The latest handler is always used without re-subscribing.
+ *
+ * @param onNavigate - Called with each navigation request
+ */
+export function useNativeNavigation(onNavigate: (request: NativeNavigation) => void): void {
+  const handlerRef = useRef(onNavigate);
+
+  // Update handler ref when handler changes
+  useEffect(() => {
+    handlerRef.current = onNavigate;
+  }, [onNavigate]);
+
+  useEffect(() => {
+    if (!isTauri()) {
+      return;
+    }
+
+    let disposed = false;
+    let unlisten: (() => void) | undefined;
+
+    void (async () => {
+      const { listen } = await import('@tauri-apps/api/event');
+      const stop = await listen<NativeNavigation>('navigate', (event) => {
+        handlerRef.current(event.payload);
+      });
+
+      // Component unmounted while subscribing
+      if (disposed) {
+        stop();
+      } else {
+        unlisten = stop;
+      }
+    })().catch((err) => {
+      console.error('[useNativeNavigation] Failed to subscribe to navigation events:', err);
+    });
+
+    return () => {
+      disposed = true;
+      unlisten?.();
+    };
+  }, []);
+}
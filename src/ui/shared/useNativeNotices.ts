@@ -0,0 +1,72 @@
+/**
+ * useNativeNotices Hook
+ *
+ * Subscribes to `notice` events emitted by the desktop app when it acts
+ * without the webview (e.g. a `meatycapture://` deep link saved an item or
+ * was rejected), so the result still shows up as a toast.
+ *
+ * Payload:
+ * - type: Toast level ('success' | 'error')
+ * - message: Text to show
+ *
+ * No-op outside Tauri.
+ */
+
+import { useEffect, useRef } from 'react';
+import { isTauri } from '@platform';
+import type { ToastType } from './Toast';
+
+/**
+ * Notice sent by the native side
+ */
+export interface NativeNotice {
+  type: ToastType;
+  message: string;
+}
+
+/**
+ * useNativeNotices Hook
+ *
+ * Listens for notices for the lifetime of the component.
+ * The latest handler is always used without re-subscribing.
+ *
+ * @param onNotice - Called with each notice
+ */
+export function useNativeNotices(onNotice: (notice: NativeNotice) => void): void {
+  const handlerRef = useRef(onNotice);
+
+  // Update handler ref when handler changes
+  useEffect(() => {
+    handlerRef.current = onNotice;
+  }, [onNotice]);
+
+  useEffect(() => {
+    if (!isTauri()) {
+      return;
+    }
+
+    let disposed = false;
+    let unlisten: (() => void) | undefined;
+
+    void (async () => {
+      const { listen } = await import('@tauri-apps/api/event');
+      const stop = await listen<NativeNotice>('notice', (event) => {
+        handlerRef.current(event.payload);
+      });
+
+      // Component unmounted while subscribing
+      if (disposed) {
+        stop();
+      } else {
+        unlisten = stop;
+      }
+    })().catch((err) => {
+      console.error('[useNativeNotices] Failed to subscribe to notice events:', err);
+    });
+
+    return () => {
+      disposed = true;
+      unlisten?.();
+    };
+  }, []);
+}
@@ -9,6 +9,7 @@
  * - Manages filter state with multi-faceted filtering
  * - Caches full documents on-demand for expansion
  * - Provides manual refresh to re-scan filesystem
+ * - Applies live watcher events in the desktop app (useDocumentEvents)
  *
  * Child Components (to be integrated):
  * - DocumentFilters (TASK-2.3) - Filter controls
@@ -30,6 +31,7 @@ import type { ViewerContainerProps } from './types';
 import { DocumentCatalog } from './DocumentCatalog';
 import { DocumentFilters } from './DocumentFilters';
 import { useDocumentCache } from './hooks/useDocumentCache';
+import { useDocumentEvents } from './hooks/useDocumentEvents';
 import './viewer.css';
 
 /**
@@ -53,6 +55,7 @@ import './viewer.css';
 export function ViewerContainer({
   projectStore,
   docStore,
+  openPath,
 }: ViewerContainerProps): React.JSX.Element {
   // ============================================================================
   // State Management
@@ -99,10 +102,14 @@ export function ViewerContainer({
    * Scans all enabled projects and aggregates documents into catalog.
    * Extracts available filter options from loaded data.
    * Called on mount and when user clicks refresh button.
+   *
+   * @param silent - Skip the loading skeleton (background refresh)
    */
-  const loadCatalog = useCallback(async () => {
+  const loadCatalog = useCallback(async (silent = false) => {
     try {
-      setLoading(true);
+      if (!silent) {
+        setLoading(true);
+      }
       setError(null);
 
       console.info('[ViewerContainer] Loading catalog...');
@@ -147,6 +154,28 @@ export function ViewerContainer({
     loadCatalog();
   }, [documentCache, loadCatalog]);
 
+  /**
+   * Apply filesystem watcher events (desktop only)
+   *
+   * Re-scans the catalog in the background. Expanded documents are
+   * re-read so their detail view stays current; others are evicted from
+   * the cache and re-loaded on next expansion.
+   */
+  useDocumentEvents((kind, meta) => {
+    if (kind !== 'deleted' && expandedPaths.has(meta.path)) {
+      docStore
+        .read(meta.path)
+        .then((doc) => documentCache.set(meta.path, doc))
+        .catch((err) => {
+          console.error(`[ViewerContainer] Failed to reload document: ${meta.path}`, err);
+          documentCache.remove(meta.path);
+        });
+    } else {
+      documentCache.remove(meta.path);
+    }
+    loadCatalog(true);
+  });
+
   // ============================================================================
   // Filter Management
   // ============================================================================
@@ -253,6 +282,15 @@ export function ViewerContainer({
     });
   }, []);
 
+  /**
+   * Expand a document requested from outside the viewer
+   */
+  useEffect(() => {
+    if (openPath) {
+      setExpandedPaths((prev) => new Set(prev).add(openPath));
+    }
+  }, [openPath]);
+
   // ============================================================================
   // Reserved Handler References (for child components in future tasks)
   // ============================================================================
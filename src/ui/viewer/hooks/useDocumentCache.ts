@@ -60,6 +60,15 @@ export interface DocumentCacheResult {
    */
   has: (path: string) => boolean;
 
+  /**
+   * Remove a single cached document
+   *
+   * Used when the document changed on disk so the next access re-loads it.
+   *
+   * @param path - Document file path
+   */
+  remove: (path: string) => void;
+
   /**
    * Clear all cached documents
    *
@@ -137,6 +146,23 @@ export function useDocumentCache(): DocumentCacheResult {
     [cache]
   );
 
+  /**
+   * Remove a single cached document
+   *
+   * Stable callback that creates new Map without the entry.
+   * No-op (no re-render) if the path is not cached.
+   */
+  const remove = useCallback((path: string): void => {
+    setCache((prev) => {
+      if (!prev.has(path)) {
+        return prev;
+      }
+      const next = new Map(prev);
+      next.delete(path);
+      return next;
+    });
+  }, []);
+
   /**
    * Clear all cached documents
    *
@@ -151,6 +177,7 @@ export function useDocumentCache(): DocumentCacheResult {
     get,
     set,
     has,
+    remove,
     invalidate,
     cache,
   };
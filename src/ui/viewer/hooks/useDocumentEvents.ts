@@ -0,0 +1,101 @@
+/**
+ * useDocumentEvents Hook
+ *
+ * Subscribes to document change events emitted by the desktop app's
+ * native filesystem watcher.
+ *
+ * Events:
+ * - doc-created: New request-log appeared in a watched project directory
+ * - doc-updated: Existing request-log changed on disk (CLI, editor, app)
+ * - doc-deleted: Request-log was removed (payload is last known metadata)
+ *
+ * No-op outside Tauri; the web build relies on manual refresh.
+ *
+ * Usage:
+ * ```typescript
+ * useDocumentEvents((kind, meta) => {
+ *   cache.remove(meta.path);
+ *   reloadCatalog();
+ * });
+ * ```
+ */
+
+import { useEffect, useRef } from 'react';
+import type { DocMeta } from '@core/ports';
+import { isTauri } from '@platform';
+
+/**
+ * Kind of change reported by the watcher
+ */
+export type DocumentEventKind = 'created' | 'updated' | 'deleted';
+
+/**
+ * Handler invoked for each document change
+ */
+export type DocumentEventHandler = (kind: DocumentEventKind, meta: DocMeta) => void;
+
+/**
+ * DocMeta as serialized over IPC (timestamps are ISO strings)
+ */
+interface DocMetaPayload extends Omit<DocMeta, 'updated_at'> {
+  updated_at: string;
+}
+
+const EVENT_KINDS: Record<string, DocumentEventKind> = {
+  'doc-created': 'created',
+  'doc-updated': 'updated',
+  'doc-deleted': 'deleted',
+};
+
+/**
+ * useDocumentEvents Hook
+ *
+ * Listens for watcher events for the lifetime of the component.
+ * The latest handler is always used without re-subscribing.
+ *
+ * @param onEvent - Called with the change kind and document metadata
+ */
+export function useDocumentEvents(onEvent: DocumentEventHandler): void {
+  const handlerRef = useRef(onEvent);
+
+  // Update handler ref when handler changes
+  useEffect(() => {
+    handlerRef.current = onEvent;
+  }, [onEvent]);
+
+  useEffect(() => {
+    if (!isTauri()) {
+      return;
+    }
+
+    let disposed = false;
+    const unlisteners: Array<() => void> = [];
+
+    void (async () => {
+      const { listen } = await import('@tauri-apps/api/event');
+
+      for (const [eventName, kind] of Object.entries(EVENT_KINDS)) {
+        const unlisten = await listen<DocMetaPayload>(eventName, (event) => {
+          handlerRef.current(kind, {
+            ...event.payload,
+            updated_at: new Date(event.payload.updated_at),
+          });
+        });
+
+        // Component unmounted while subscribing
+        if (disposed) {
+          unlisten();
+        } else {
+          unlisteners.push(unlisten);
+        }
+      }
+    })().catch((err) => {
+      console.error('[useDocumentEvents] Failed to subscribe to document events:', err);
+    });
+
+    return () => {
+      disposed = true;
+      unlisteners.forEach((unlisten) => unlisten());
+    };
+  }, []);
+}
@@ -60,4 +60,7 @@ export interface ViewerContainerProps {
 
   /** Document store for listing and reading documents */
   docStore: DocStore;
+
+  /** Document to expand, e.g. opened from the command line */
+  openPath?: string;
 }
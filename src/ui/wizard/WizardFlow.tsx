@@ -12,7 +12,7 @@
  * to the same document without re-selecting project/doc.
  */
 
-import React, { useState, useEffect, useCallback } from 'react';
+import React, { useState, useEffect, useCallback, useRef } from 'react';
 import type { Project, ItemDraft, RequestLogDoc, FieldOption, FieldName } from '@core/models';
 import type { ProjectStore, FieldCatalogStore, DocStore, Clock } from '@core/ports';
 import { generateDocId, slugify } from '@core/validation';
@@ -55,6 +55,12 @@ interface WizardFlowProps {
   clock: Clock;
   /** Called when wizard completes and user clicks Done */
   onComplete?: () => void;
+  /** Project to select once projects have loaded (e.g. from the tray menu) */
+  initialProjectId?: string | undefined;
+  /** Existing document to append to once the project is selected (deep links) */
+  initialDocPath?: string | undefined;
+  /** Item fields to prefill (deep links) */
+  initialDraft?: Partial<ItemDraft> | undefined;
 }
 
 /**
@@ -77,6 +83,9 @@ export function WizardFlow({
   docStore,
   clock,
   onComplete,
+  initialProjectId,
+  initialDocPath,
+  initialDraft,
 }: WizardFlowProps): React.JSX.Element {
   // ============================================================================
   // State Management
@@ -99,7 +108,7 @@ export function WizardFlow({
   const [isNewDoc, setIsNewDoc] = useState<boolean>(true);
 
   // Item step state
-  const [draft, setDraft] = useState<ItemDraft>(EMPTY_DRAFT);
+  const [draft, setDraft] = useState<ItemDraft>(() => ({ ...EMPTY_DRAFT, ...initialDraft }));
   const [fieldOptions, setFieldOptions] = useState<Record<FieldName, FieldOption[]>>({
     type: [],
     domain: [],
@@ -228,6 +237,16 @@ export function WizardFlow({
     [projects, clock]
   );
 
+  /**
+   * Preselect the requested project once projects are loaded
+   */
+  useEffect(() => {
+    if (!initialProjectId || selectedProject) return;
+    if (projects.some((p) => p.id === initialProjectId)) {
+      handleSelectProject(initialProjectId);
+    }
+  }, [initialProjectId, projects, selectedProject, handleSelectProject]);
+
   const handleCreateProject = useCallback(
     async (projectData: Omit<Project, 'id' | 'created_at' | 'updated_at'>) => {
       try {
@@ -268,6 +287,16 @@ export function WizardFlow({
     }
   }, []);
 
+  /**
+   * Preselect the requested document once, after its project is selected
+   */
+  const initialDocApplied = useRef(false);
+  useEffect(() => {
+    if (!initialDocPath || !selectedProject || initialDocApplied.current) return;
+    initialDocApplied.current = true;
+    handleSelectDoc(initialDocPath);
+  }, [initialDocPath, selectedProject, handleSelectDoc]);
+
   const handlePathOverride = useCallback((path: string) => {
     setDocPath(path);
   }, []);